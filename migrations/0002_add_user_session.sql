@@ -0,0 +1,27 @@
+-- 토큰을 user 테이블에서 기기별 세션으로 옮긴다.
+--
+-- 기존 토큰은 세션 ID(refresh token family)가 없어 새 방식으로 검증할 수 없으므로 옮기지 않는다.
+-- 업그레이드 후 모든 사용자는 다시 로그인해야 한다.
+
+-- 기기별 로그인 세션. session_id는 refresh token family ID로 사용된다.
+CREATE TABLE IF NOT EXISTS `user_session` (
+	`session_id` VARCHAR(36) NOT NULL,
+	`user_id` INT NOT NULL,
+	`session_device_id` VARCHAR(255) NULL,
+	`session_access_token` TEXT NULL,
+	`session_refresh_token` TEXT NULL,
+	`session_access_token_expires_at` DATETIME NULL,
+	`session_refresh_token_expires_at` DATETIME NULL,
+	`session_rotation_count` INT NOT NULL DEFAULT 0,
+	`session_revoked_at` DATETIME NULL,
+	`session_created_at` DATETIME NULL,
+	`session_last_used_at` DATETIME NULL,
+	PRIMARY KEY (`session_id`),
+	INDEX (`user_id`)
+);
+
+ALTER TABLE `user`
+	DROP COLUMN `user_access_token`,
+	DROP COLUMN `user_refresh_token`,
+	DROP COLUMN `user_access_token_expires_at`,
+	DROP COLUMN `user_refresh_token_expires_at`;
@@ -0,0 +1,7 @@
+-- 여러 서버 인스턴스가 공유하는 rate limit token bucket (RATE_LIMIT_BACKEND=mysql)
+CREATE TABLE IF NOT EXISTS `rate_limit_bucket` (
+	`bucket_key` VARCHAR(255) NOT NULL,
+	`bucket_tokens` DOUBLE NOT NULL,
+	`bucket_updated_at` DATETIME(3) NOT NULL,
+	PRIMARY KEY (`bucket_key`)
+);
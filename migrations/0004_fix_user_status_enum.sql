@@ -0,0 +1,14 @@
+-- user_status에 'suspended, deleted'가 하나의 값으로 들어가 있던 것을 'suspended'와 'deleted'로 나눈다.
+--
+-- 기존 'suspended, deleted' 값은 정지된 계정으로 보고 'suspended'로 바꾼다.
+-- 값을 옮기는 동안 두 형식을 모두 허용한 뒤 최종 ENUM으로 줄인다.
+
+ALTER TABLE `user`
+	MODIFY `user_status` ENUM('active', 'inactive', 'suspended, deleted', 'suspended', 'deleted') DEFAULT 'active';
+
+UPDATE `user`
+SET `user_status` = 'suspended'
+WHERE `user_status` = 'suspended, deleted';
+
+ALTER TABLE `user`
+	MODIFY `user_status` ENUM('active', 'inactive', 'suspended', 'deleted') DEFAULT 'active';
@@ -0,0 +1,11 @@
+-- 관리자가 피드백을 분류하고 처리할 수 있도록 앱 정보, 상태, 태그, 시각 컬럼을 추가한다.
+-- 기존 피드백은 모두 'open' 상태가 되고 작성 시각은 알 수 없으므로 NULL로 둔다.
+
+ALTER TABLE `feedback`
+	ADD COLUMN `feedback_app_version` VARCHAR(50) NULL,
+	ADD COLUMN `feedback_device_info` VARCHAR(255) NULL,
+	ADD COLUMN `feedback_status` ENUM('open', 'resolved') NOT NULL DEFAULT 'open',
+	ADD COLUMN `feedback_tags` VARCHAR(255) NULL, -- 쉼표로 구분
+	ADD COLUMN `feedback_created_at` DATETIME NULL,
+	ADD COLUMN `feedback_resolved_at` DATETIME NULL,
+	ADD INDEX (`feedback_status`);
@@ -0,0 +1,3 @@
+-- 탈퇴 요청 시각. 유예 기간이 지나면 완전히 삭제된다.
+ALTER TABLE `user`
+	ADD COLUMN `user_deleted_at` DATETIME NULL;
@@ -0,0 +1,11 @@
+-- 한 폴더에 같은 채널이 두 번 들어가지 않도록 유니크 키를 건다.
+-- 기존 중복 행은 먼저 추가된 것만 남긴다.
+
+DELETE dup FROM `channels_in_folder` AS dup
+	JOIN `channels_in_folder` AS keep
+		ON keep.`folder_id` = dup.`folder_id`
+		AND keep.`channel_id` = dup.`channel_id`
+		AND keep.`channels_in_folder_id` < dup.`channels_in_folder_id`;
+
+ALTER TABLE `channels_in_folder`
+	ADD UNIQUE KEY `uk_channels_in_folder` (`folder_id`, `channel_id`);
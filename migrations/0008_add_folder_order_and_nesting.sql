@@ -0,0 +1,13 @@
+-- 폴더 순서, 폴더 안 채널 순서, 한 단계 폴더 중첩을 추가한다.
+-- 기존 폴더와 채널은 모두 순서 0, 최상위 폴더가 된다.
+
+ALTER TABLE `rss_folder`
+	ADD COLUMN `parent_folder_id` INT NULL, -- 한 단계까지만 중첩
+	ADD COLUMN `folder_order` INT NULL DEFAULT 0;
+
+ALTER TABLE `channels_in_folder`
+	ADD COLUMN `channel_order` INT NULL DEFAULT 0;
+
+-- 폴더 타임라인을 채널별 최신순으로 페이지 조회할 때 사용한다.
+ALTER TABLE `rss_item`
+	ADD KEY `idx_rss_item_channel_pub_date` (`channel_id`, `rss_pub_date`, `rss_id`);
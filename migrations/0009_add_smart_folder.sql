@@ -0,0 +1,15 @@
+-- 저장된 검색 조건으로 아이템을 모아 보여주는 가상 폴더
+CREATE TABLE IF NOT EXISTS `smart_folder` (
+	`smart_folder_id` INT NOT NULL AUTO_INCREMENT,
+	`user_id` INT NULL DEFAULT 0,
+	`smart_folder_name` VARCHAR(50) NULL,
+	`smart_folder_query` VARCHAR(200) NULL, -- 의미 검색어
+	`smart_folder_keywords` VARCHAR(500) NULL, -- 쉼표로 구분된 키워드
+	`smart_folder_channel_ids` VARCHAR(500) NULL, -- 쉼표로 구분된 채널 ID
+	`smart_folder_language` VARCHAR(20) NULL,
+	`smart_folder_days` INT NULL, -- 최근 N일 이내 아이템만
+	`smart_folder_order` INT NULL DEFAULT 0,
+	`smart_folder_created_at` DATETIME NULL,
+	PRIMARY KEY (`smart_folder_id`),
+	KEY `idx_smart_folder_user_id` (`user_id`)
+);
@@ -0,0 +1,11 @@
+-- 구독 타임라인, 검색, 추천에서 숨길 사용자별 규칙
+CREATE TABLE IF NOT EXISTS `mute_rule` (
+	`mute_rule_id` INT NOT NULL AUTO_INCREMENT,
+	`user_id` INT NULL DEFAULT 0,
+	`mute_rule_type` ENUM('keyword', 'regex', 'author', 'channel', 'domain') NOT NULL,
+	`mute_rule_value` VARCHAR(200) NOT NULL,
+	`mute_rule_expires_at` DATETIME NULL, -- NULL이면 만료되지 않음
+	`mute_rule_created_at` DATETIME NULL,
+	PRIMARY KEY (`mute_rule_id`),
+	KEY `idx_mute_rule_user_id` (`user_id`)
+);
@@ -0,0 +1,24 @@
+-- 모든 DATETIME 컬럼을 UTC로 통일한다.
+--
+-- 이전 버전에서 각 컬럼이 저장되던 방식:
+--   * user, user_session, mute_rule, rate_limit_bucket, feedback, premium job 등 서버가 기록한 시각
+--     -> Utc::now().naive_utc()로 저장했으므로 이미 UTC다. 변환하지 않는다.
+--   * user_session 만료 시각
+--     -> 발급된 JWT exp의 UTC 값 그대로다. 이미 발급된 토큰과 맞아야 하므로 변환하지 않는다.
+--   * rss_item.rss_pub_date
+--     -> 피드에 적힌 오프셋의 로컬 시각을 그대로 저장했다. 국내 피드(+09:00)는 KST로 저장되어 있다.
+--   * News.news_pub_date
+--     -> 외부 수집기가 기록하는 값이므로 이 마이그레이션에서 다루지 않는다.
+--
+-- rss_pub_date는 원래 오프셋이 남아 있지 않으므로 채널 언어가 한국어인 아이템만 KST로 보고 9시간을 뺀다.
+-- 날짜가 없어 1970-01-01로 채워진 아이템과 정렬용 미래 날짜는 건드리지 않는다.
+
+ALTER TABLE `user`
+	ADD COLUMN `user_timezone` VARCHAR(64) DEFAULT 'Asia/Seoul' AFTER `user_theme`;
+
+UPDATE `rss_item` AS i
+	JOIN `rss_channel` AS c ON c.`channel_id` = i.`channel_id`
+SET i.`rss_pub_date` = DATE_SUB(i.`rss_pub_date`, INTERVAL 9 HOUR)
+WHERE c.`channel_language` LIKE 'ko%'
+	AND i.`rss_pub_date` > '1970-01-02 00:00:00'
+	AND i.`rss_pub_date` < '9999-01-01 00:00:00';
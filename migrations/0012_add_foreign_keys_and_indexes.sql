@@ -0,0 +1,131 @@
+-- 외래 키와 누락된 인덱스를 추가한다.
+--
+-- 외래 키를 걸기 전에 참조 대상이 없는 행(예: 채널이 삭제된 아이템, 기본값 0이 들어간 user_id)을 지운다.
+-- 외래 키 컬럼의 기본값 0은 제약을 어기므로 NULL로 바꾼다.
+
+-- 대소문자를 구분하는 서버에서 0001이 만든 `News` 테이블을 `news`로 바꾼다.
+SET @rename_news = (
+	SELECT IF(
+		SUM(BINARY table_name = 'News') = 1 AND SUM(BINARY table_name = 'news') = 0,
+		'RENAME TABLE `News` TO `news`',
+		'DO 0'
+	)
+	FROM information_schema.tables
+	WHERE table_schema = DATABASE() AND table_name IN ('News', 'news')
+);
+PREPARE stmt FROM @rename_news;
+EXECUTE stmt;
+DEALLOCATE PREPARE stmt;
+
+-- 참조 대상이 없는 행 정리
+DELETE i FROM `rss_item` AS i
+	LEFT JOIN `rss_channel` AS c ON c.`channel_id` = i.`channel_id`
+WHERE c.`channel_id` IS NULL;
+
+DELETE e FROM `embedding` AS e
+	LEFT JOIN `rss_channel` AS c ON c.`channel_id` = e.`channel_id`
+	LEFT JOIN `rss_item` AS i ON i.`rss_id` = e.`rss_id`
+	LEFT JOIN `news` AS n ON n.`news_id` = e.`news_id`
+WHERE (e.`channel_id` IS NOT NULL AND c.`channel_id` IS NULL)
+	OR (e.`rss_id` IS NOT NULL AND i.`rss_id` IS NULL)
+	OR (e.`news_id` IS NOT NULL AND n.`news_id` IS NULL);
+
+DELETE s FROM `user_subscription_channel` AS s
+	LEFT JOIN `user` AS u ON u.`user_id` = s.`user_id`
+	LEFT JOIN `rss_channel` AS c ON c.`channel_id` = s.`channel_id`
+WHERE u.`user_id` IS NULL OR c.`channel_id` IS NULL;
+
+DELETE f FROM `rss_folder` AS f
+	LEFT JOIN `user` AS u ON u.`user_id` = f.`user_id`
+WHERE u.`user_id` IS NULL;
+
+UPDATE `rss_folder` AS f
+	LEFT JOIN `rss_folder` AS p ON p.`folder_id` = f.`parent_folder_id`
+SET f.`parent_folder_id` = NULL
+WHERE f.`parent_folder_id` IS NOT NULL AND p.`folder_id` IS NULL;
+
+DELETE cif FROM `channels_in_folder` AS cif
+	LEFT JOIN `rss_folder` AS f ON f.`folder_id` = cif.`folder_id`
+	LEFT JOIN `rss_channel` AS c ON c.`channel_id` = cif.`channel_id`
+WHERE f.`folder_id` IS NULL OR c.`channel_id` IS NULL;
+
+DELETE sf FROM `smart_folder` AS sf
+	LEFT JOIN `user` AS u ON u.`user_id` = sf.`user_id`
+WHERE u.`user_id` IS NULL;
+
+DELETE m FROM `mute_rule` AS m
+	LEFT JOIN `user` AS u ON u.`user_id` = m.`user_id`
+WHERE u.`user_id` IS NULL;
+
+DELETE us FROM `user_session` AS us
+	LEFT JOIN `user` AS u ON u.`user_id` = us.`user_id`
+WHERE u.`user_id` IS NULL;
+
+-- rss_link 중복 아이템은 먼저 저장된 것만 남긴다.
+DELETE e FROM `embedding` AS e
+	JOIN `rss_item` AS dup ON dup.`rss_id` = e.`rss_id`
+	JOIN `rss_item` AS keep ON keep.`rss_link` = dup.`rss_link` AND keep.`rss_id` < dup.`rss_id`;
+
+DELETE dup FROM `rss_item` AS dup
+	JOIN `rss_item` AS keep ON keep.`rss_link` = dup.`rss_link` AND keep.`rss_id` < dup.`rss_id`;
+
+-- 외래 키
+ALTER TABLE `rss_item`
+	MODIFY `channel_id` INT NOT NULL,
+	ADD CONSTRAINT `fk_rss_item_channel` FOREIGN KEY (`channel_id`)
+		REFERENCES `rss_channel` (`channel_id`) ON DELETE CASCADE;
+
+ALTER TABLE `embedding`
+	ADD CONSTRAINT `fk_embedding_channel` FOREIGN KEY (`channel_id`)
+		REFERENCES `rss_channel` (`channel_id`) ON DELETE CASCADE,
+	ADD CONSTRAINT `fk_embedding_rss_item` FOREIGN KEY (`rss_id`)
+		REFERENCES `rss_item` (`rss_id`) ON DELETE CASCADE,
+	ADD CONSTRAINT `fk_embedding_news` FOREIGN KEY (`news_id`)
+		REFERENCES `news` (`news_id`) ON DELETE CASCADE;
+
+ALTER TABLE `user_subscription_channel`
+	MODIFY `user_id` INT NOT NULL,
+	MODIFY `channel_id` INT NOT NULL,
+	ADD CONSTRAINT `fk_user_subscription_channel_user` FOREIGN KEY (`user_id`)
+		REFERENCES `user` (`user_id`) ON DELETE CASCADE,
+	ADD CONSTRAINT `fk_user_subscription_channel_channel` FOREIGN KEY (`channel_id`)
+		REFERENCES `rss_channel` (`channel_id`) ON DELETE CASCADE;
+
+-- 상위 폴더가 삭제되면 하위 폴더는 최상위로 올라간다. (folder_service::delete_folder와 같은 동작)
+ALTER TABLE `rss_folder`
+	MODIFY `user_id` INT NOT NULL,
+	ADD CONSTRAINT `fk_rss_folder_user` FOREIGN KEY (`user_id`)
+		REFERENCES `user` (`user_id`) ON DELETE CASCADE,
+	ADD CONSTRAINT `fk_rss_folder_parent` FOREIGN KEY (`parent_folder_id`)
+		REFERENCES `rss_folder` (`folder_id`) ON DELETE SET NULL;
+
+ALTER TABLE `channels_in_folder`
+	MODIFY `folder_id` INT NOT NULL,
+	MODIFY `channel_id` INT NOT NULL,
+	ADD CONSTRAINT `fk_channels_in_folder_folder` FOREIGN KEY (`folder_id`)
+		REFERENCES `rss_folder` (`folder_id`) ON DELETE CASCADE,
+	ADD CONSTRAINT `fk_channels_in_folder_channel` FOREIGN KEY (`channel_id`)
+		REFERENCES `rss_channel` (`channel_id`) ON DELETE CASCADE;
+
+ALTER TABLE `smart_folder`
+	MODIFY `user_id` INT NOT NULL,
+	ADD CONSTRAINT `fk_smart_folder_user` FOREIGN KEY (`user_id`)
+		REFERENCES `user` (`user_id`) ON DELETE CASCADE;
+
+ALTER TABLE `mute_rule`
+	MODIFY `user_id` INT NOT NULL,
+	ADD CONSTRAINT `fk_mute_rule_user` FOREIGN KEY (`user_id`)
+		REFERENCES `user` (`user_id`) ON DELETE CASCADE;
+
+ALTER TABLE `user_session`
+	ADD CONSTRAINT `fk_user_session_user` FOREIGN KEY (`user_id`)
+		REFERENCES `user` (`user_id`) ON DELETE CASCADE;
+
+-- 인덱스
+-- rss_link는 VARCHAR(1000)이라 그대로는 유니크 인덱스 길이 제한(3072 bytes)을 넘으므로 해시 컬럼에 건다.
+ALTER TABLE `rss_item`
+	ADD COLUMN `rss_link_hash` BINARY(32) AS (UNHEX(SHA2(`rss_link`, 256))) STORED,
+	ADD UNIQUE KEY `uk_rss_item_rss_link` (`rss_link_hash`);
+
+CREATE INDEX `idx_rss_item_pub_date` ON `rss_item` (`rss_pub_date`);
+CREATE INDEX `idx_news_category_pub_date` ON `news` (`news_category`, `news_pub_date`);
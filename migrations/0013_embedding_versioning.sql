@@ -0,0 +1,37 @@
+-- 임베딩마다 어떤 모델과 문장 형식으로 만들었는지 기록한다.
+--
+-- 검색은 서버가 사용하는 모델/문장 형식과 같은 임베딩만 사용한다.
+-- 모델이나 문장 형식을 바꾸면 관리자 API로 재임베딩 작업을 돌려 기존 임베딩을 교체한다.
+
+ALTER TABLE `embedding`
+	ADD COLUMN `embedding_model_id` VARCHAR(200) NULL,
+	ADD COLUMN `embedding_dim` INT NULL,
+	ADD COLUMN `embedding_text_recipe_version` INT NULL,
+	ADD KEY `idx_embedding_version` (`embedding_model_id`, `embedding_text_recipe_version`);
+
+-- 기존 임베딩은 모두 rust-bert DistiluseBaseMultilingualCased로 만들었다.
+-- 채널과 아이템이 서로 다른 문장 형식을 쓰던 시기이므로 문장 형식 버전은 1로 둔다.
+UPDATE `embedding`
+SET `embedding_model_id` = 'distiluse-base-multilingual-cased',
+	`embedding_dim` = LENGTH(`embedding_value`) DIV 4,
+	`embedding_text_recipe_version` = 1
+WHERE `embedding_model_id` IS NULL;
+
+-- 모든 채널과 아이템을 새 모델/문장 형식으로 다시 임베딩하는 작업
+-- 채널(channel) 다음 아이템(item) 순서로 ID 순으로 진행하고, 마지막으로 처리한 ID를 저장해 중단된 곳부터 이어서 한다.
+CREATE TABLE IF NOT EXISTS `reembedding_job` (
+	`job_id` INT NOT NULL AUTO_INCREMENT,
+	`job_model_id` VARCHAR(200) NOT NULL,
+	`job_text_recipe_version` INT NOT NULL,
+	`job_status` ENUM('running', 'paused', 'completed', 'failed') NOT NULL DEFAULT 'running',
+	`job_phase` ENUM('channel', 'item') NOT NULL DEFAULT 'channel',
+	`job_cursor` INT NOT NULL DEFAULT 0,
+	`job_total` INT NOT NULL DEFAULT 0,
+	`job_processed` INT NOT NULL DEFAULT 0,
+	`job_error` VARCHAR(1000) NULL,
+	`job_created_at` DATETIME NULL,
+	`job_updated_at` DATETIME NULL,
+	`job_completed_at` DATETIME NULL,
+	PRIMARY KEY (`job_id`),
+	KEY `idx_reembedding_job_status` (`job_status`)
+);
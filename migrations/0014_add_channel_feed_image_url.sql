@@ -0,0 +1,7 @@
+-- 채널 메타데이터 갱신 때 마지막으로 본 피드 이미지 주소
+--
+-- channel_image_url은 피드 이미지가 깨진 경우 사이트 아이콘으로 대체되므로 피드 이미지와 다를 수 있다.
+-- 피드 이미지가 바뀌었는지는 이 값과 비교하고, 바뀌지 않았으면 아이콘을 다시 찾지 않는다.
+-- 기존 채널은 NULL로 두며, 다음 갱신 때 한 번 아이콘을 다시 찾고 피드 이미지 주소를 기록한다.
+ALTER TABLE `rss_channel`
+	ADD COLUMN `channel_feed_image_url` VARCHAR(1000) NULL;
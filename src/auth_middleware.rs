@@ -1,3 +1,4 @@
+use chrono_tz::Tz;
 use jsonwebtoken::{decode, DecodingKey, Validation};
 use okapi::openapi3::{Object, SecurityRequirement, SecurityScheme, SecuritySchemeData};
 use rocket::{
@@ -17,7 +18,7 @@ use std::{collections::HashMap, env, io::Cursor};
 use std::{collections::HashSet, sync::RwLock};
 use uuid::Uuid;
 
-use crate::{server_error, server_info, service::user_service};
+use crate::{handler::error_handler::MyError, server_error, server_info, service::user_service};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
@@ -25,12 +26,34 @@ pub struct Claims {
     pub sub: String,     // 사용자 이메일
     pub company: String, // 회사
     pub exp: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>, // 세션(token family) ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>, // 토큰 고유 ID
+}
+
+// 인증 실패 사유 (에러 코드와 메시지)
+pub struct AuthFailure {
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl AuthFailure {
+    fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
 }
 
 // 인증 정보를 저장할 구조체
 pub struct AuthCache {
-    pub auth_failures: RwLock<HashMap<String, String>>,
+    pub auth_failures: RwLock<HashMap<String, AuthFailure>>,
     pub user_emails: RwLock<HashMap<String, String>>,
+    pub user_sessions: RwLock<HashMap<String, String>>,
+    // 응답 시각을 변환할 사용자 시간대 (토큰 검증 쿼리에서 함께 읽는다)
+    pub user_timezones: RwLock<HashMap<String, Tz>>,
 }
 
 impl AuthCache {
@@ -38,6 +61,8 @@ impl AuthCache {
         Self {
             auth_failures: RwLock::new(HashMap::new()),
             user_emails: RwLock::new(HashMap::new()),
+            user_sessions: RwLock::new(HashMap::new()),
+            user_timezones: RwLock::new(HashMap::new()),
         }
     }
 }
@@ -64,13 +89,6 @@ impl AuthMiddleware {
     }
 }
 
-// 에러 응답을 JSON으로 직렬화하기 위한 구조체
-#[derive(Serialize)]
-struct ErrorResponse {
-    status: String,
-    message: String,
-}
-
 #[allow(clippy::upper_case_acronyms)]
 pub struct CORS;
 
@@ -150,7 +168,7 @@ impl Fairing for AuthMiddleware {
             _ => {
                 auth_cache.auth_failures.write().unwrap().insert(
                     request_id,
-                    "인증 토큰이 없거나 올바르지 않습니다.".to_string(),
+                    AuthFailure::new("UNAUTHORIZED", "인증 토큰이 없거나 올바르지 않습니다."),
                 );
                 return;
             }
@@ -162,7 +180,10 @@ impl Fairing for AuthMiddleware {
                 server_error!("JWT_SECRET_KEY environment variable not set");
                 auth_cache.auth_failures.write().unwrap().insert(
                     request_id,
-                    "서버 구성 오류: JWT_SECRET_KEY가 설정되지 않았습니다.".to_string(),
+                    AuthFailure::new(
+                        "UNAUTHORIZED",
+                        "서버 구성 오류: JWT_SECRET_KEY가 설정되지 않았습니다.",
+                    ),
                 );
                 return;
             }
@@ -187,40 +208,41 @@ impl Fairing for AuthMiddleware {
                 )
                 .await
                 {
-                    Ok(res) => {
-                        if res {
-                            // 토큰 검증 성공
-                            server_info!("Token validation successful for user: {}", user_email);
-                        } else {
-                            auth_cache.auth_failures.write().unwrap().insert(
-                                request_id.clone(),
-                                "사용자 이메일이 잘못되었습니다.".to_string(),
-                            );
+                    Ok(timezone) => {
+                        // 토큰 검증 성공: 폐기되지 않은 토큰만 사용자 정보를 남긴다.
+                        server_info!("Token validation successful for user: {}", user_email);
+                        if let Some(session_id) = token_data.claims.sid {
+                            auth_cache
+                                .user_sessions
+                                .write()
+                                .unwrap()
+                                .insert(request_id.clone(), session_id);
                         }
-                    }
-                    Err(_) => {
                         auth_cache
-                            .auth_failures
+                            .user_timezones
                             .write()
                             .unwrap()
-                            .insert(request_id.clone(), "유효하지 않은 토큰입니다.".to_string());
+                            .insert(request_id.clone(), timezone);
+                        auth_cache
+                            .user_emails
+                            .write()
+                            .unwrap()
+                            .insert(request_id, user_email);
+                    }
+                    Err(_) => {
+                        auth_cache.auth_failures.write().unwrap().insert(
+                            request_id,
+                            AuthFailure::new("TOKEN_INVALID", "유효하지 않은 토큰입니다."),
+                        );
                     }
                 };
-
-                // 인증 성공
-                auth_cache
-                    .user_emails
-                    .write()
-                    .unwrap()
-                    .insert(request_id, user_email);
             }
             Err(e) => {
                 server_error!("JWT decode error: {}", e);
-                auth_cache
-                    .auth_failures
-                    .write()
-                    .unwrap()
-                    .insert(request_id, format!("유효하지 않은 토큰입니다: {}", e));
+                auth_cache.auth_failures.write().unwrap().insert(
+                    request_id,
+                    AuthFailure::new("TOKEN_INVALID", format!("유효하지 않은 토큰입니다: {}", e)),
+                );
             }
         };
     }
@@ -236,25 +258,22 @@ impl Fairing for AuthMiddleware {
         }
 
         if let Some(auth_cache) = req.rocket().state::<AuthCache>() {
-            // 인증 실패 메시지 가져오기
-            let error_message = {
+            // 인증 실패 사유 가져오기
+            let error_response = {
                 let failures = auth_cache.auth_failures.read().unwrap();
-                failures.get(request_id).cloned()
+                failures.get(request_id).map(|failure| {
+                    MyError::new(failure.code, Status::Unauthorized, &failure.message)
+                })
             };
 
-            // 인증 실패가 있으면 401 응답으로 변경하고 에러 메시지 추가
-            if let Some(message) = error_message {
+            // 인증 실패가 있으면 다른 에러와 같은 형식의 401 응답으로 변경
+            if let Some(error_response) = error_response {
                 res.set_status(Status::Unauthorized);
 
-                // JSON 형식의 에러 메시지 생성
-                let error_response = ErrorResponse {
-                    status: "권한 없음".to_string(),
-                    message,
-                };
-
                 // JSON으로 직렬화
                 let json = serde_json::to_string(&error_response).unwrap_or_else(|_| {
-                    r#"{"status":"권한 없음","message":"인증에 실패했습니다."}"#.to_string()
+                    r#"{"code":"UNAUTHORIZED","err":"Unauthorized","msg":"인증에 실패했습니다."}"#
+                        .to_string()
                 });
 
                 // 응답 본문 설정
@@ -265,6 +284,12 @@ impl Fairing for AuthMiddleware {
             // 요청 처리가 끝났으므로 캐시에서 정보 정리
             auth_cache.auth_failures.write().unwrap().remove(request_id);
             auth_cache.user_emails.write().unwrap().remove(request_id);
+            auth_cache.user_sessions.write().unwrap().remove(request_id);
+            auth_cache
+                .user_timezones
+                .write()
+                .unwrap()
+                .remove(request_id);
         }
     }
 }
@@ -273,6 +298,10 @@ impl Fairing for AuthMiddleware {
 #[derive(JsonSchema)]
 pub struct AuthenticatedUser {
     pub user_email: String,
+    pub session_id: Option<String>,
+    /// 응답 시각을 변환할 사용자 시간대
+    #[schemars(skip)]
+    pub timezone: Tz,
 }
 
 #[rocket::async_trait]
@@ -293,10 +322,37 @@ impl<'r> FromRequest<'r> for AuthenticatedUser {
         }
 
         if let Some(auth_cache) = req.rocket().state::<AuthCache>() {
+            // 인증에 실패한 요청은 사용자 정보가 남아 있더라도 통과시키지 않는다.
+            if auth_cache
+                .auth_failures
+                .read()
+                .unwrap()
+                .contains_key(request_id)
+            {
+                return Outcome::Error((Status::Unauthorized, "인증되지 않은 요청입니다."));
+            }
+
             // 인증된 사용자 이메일 확인
             let user_emails = auth_cache.user_emails.read().unwrap();
             if let Some(user_email) = user_emails.get(request_id).cloned() {
-                return Outcome::Success(AuthenticatedUser { user_email });
+                let session_id = auth_cache
+                    .user_sessions
+                    .read()
+                    .unwrap()
+                    .get(request_id)
+                    .cloned();
+                let timezone = auth_cache
+                    .user_timezones
+                    .read()
+                    .unwrap()
+                    .get(request_id)
+                    .copied()
+                    .unwrap_or(Tz::UTC);
+                return Outcome::Success(AuthenticatedUser {
+                    user_email,
+                    session_id,
+                    timezone,
+                });
             }
         }
 
@@ -305,7 +361,70 @@ impl<'r> FromRequest<'r> for AuthenticatedUser {
     }
 }
 
+// 관리자(user_role = 'admin')만 통과하는 Request Guard
+#[derive(JsonSchema)]
+pub struct AdminUser {
+    pub user_email: String,
+    #[schemars(skip)]
+    pub timezone: Tz,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for AdminUser {
+    type Error = &'static str;
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let user = match req.guard::<AuthenticatedUser>().await {
+            Outcome::Success(user) => user,
+            Outcome::Error(e) => return Outcome::Error(e),
+            Outcome::Forward(status) => return Outcome::Forward(status),
+        };
+
+        let pool = match req.rocket().state::<MySqlPool>() {
+            Some(pool) => pool,
+            None => {
+                server_error!("Error: MySqlPool not found");
+                return Outcome::Error((Status::InternalServerError, "서버 구성 오류입니다."));
+            }
+        };
+
+        match user_service::find_user_role(pool, user.user_email.clone()).await {
+            Ok(role) if role == "admin" => Outcome::Success(AdminUser {
+                user_email: user.user_email,
+                timezone: user.timezone,
+            }),
+            _ => Outcome::Error((Status::Forbidden, "관리자 권한이 필요합니다.")),
+        }
+    }
+}
+
 // rapidoc, swagger-ui 전용
+fn bearer_security_input() -> rocket_okapi::Result<RequestHeaderInput> {
+    // Setup global requirement for Security scheme
+    let security_scheme = SecurityScheme {
+        description: Some("Requires an Bearer token to access.".to_owned()),
+        // Setup data requirements.
+        // In this case the header `Authorization: mytoken` needs to be set.
+        data: SecuritySchemeData::Http {
+            scheme: "bearer".to_owned(), // `basic`, `digest`, ...
+            // Just gives use a hint to the format used
+            bearer_format: Some("bearer".to_owned()),
+        },
+        extensions: Object::default(),
+    };
+    // Add the requirement for this route/endpoint
+    // This can change between routes.
+    let mut security_req = SecurityRequirement::new();
+    // Each security requirement needs to be met before access is allowed.
+    security_req.insert("HttpAuth".to_owned(), Vec::new());
+    // These vvvvvvv-----^^^^^^^^ values need to match exactly!
+    Ok(RequestHeaderInput::Security(
+        "HttpAuth".to_owned(),
+        security_scheme,
+        security_req,
+    ))
+}
+
 #[allow(clippy::needless_lifetimes)]
 impl<'a> OpenApiFromRequest<'a> for AuthenticatedUser {
     fn from_request_input(
@@ -313,28 +432,17 @@ impl<'a> OpenApiFromRequest<'a> for AuthenticatedUser {
         _name: String,
         _required: bool,
     ) -> rocket_okapi::Result<RequestHeaderInput> {
-        // Setup global requirement for Security scheme
-        let security_scheme = SecurityScheme {
-            description: Some("Requires an Bearer token to access.".to_owned()),
-            // Setup data requirements.
-            // In this case the header `Authorization: mytoken` needs to be set.
-            data: SecuritySchemeData::Http {
-                scheme: "bearer".to_owned(), // `basic`, `digest`, ...
-                // Just gives use a hint to the format used
-                bearer_format: Some("bearer".to_owned()),
-            },
-            extensions: Object::default(),
-        };
-        // Add the requirement for this route/endpoint
-        // This can change between routes.
-        let mut security_req = SecurityRequirement::new();
-        // Each security requirement needs to be met before access is allowed.
-        security_req.insert("HttpAuth".to_owned(), Vec::new());
-        // These vvvvvvv-----^^^^^^^^ values need to match exactly!
-        Ok(RequestHeaderInput::Security(
-            "HttpAuth".to_owned(),
-            security_scheme,
-            security_req,
-        ))
+        bearer_security_input()
+    }
+}
+
+#[allow(clippy::needless_lifetimes)]
+impl<'a> OpenApiFromRequest<'a> for AdminUser {
+    fn from_request_input(
+        _gen: &mut OpenApiGenerator,
+        _name: String,
+        _required: bool,
+    ) -> rocket_okapi::Result<RequestHeaderInput> {
+        bearer_security_input()
     }
 }
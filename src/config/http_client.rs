@@ -0,0 +1,257 @@
+use std::{
+    collections::HashMap,
+    env,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use reqwest::{
+    header::{HeaderMap, CONTENT_TYPE, RETRY_AFTER},
+    Client, ClientBuilder, Proxy, RequestBuilder, Response, StatusCode, Url,
+};
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+
+use crate::{
+    model::error::OmniNewsError,
+    server_warn,
+    utils::{
+        charset_util,
+        url_policy_util::{self, MAX_RESPONSE_BYTES},
+    },
+};
+
+#[derive(Clone)]
+pub struct HttpClientConfig {
+    pub connect_timeout: Duration,
+    pub timeout: Duration,
+    pub max_retries: u32,
+    pub base_backoff: Duration,
+    pub max_backoff: Duration,
+    pub per_host_concurrency: usize,
+    pub user_agent: String,
+    pub proxy_url: Option<String>,
+}
+
+impl Default for HttpClientConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(5),
+            timeout: Duration::from_secs(20),
+            max_retries: 2,
+            base_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            per_host_concurrency: 4,
+            user_agent: default_user_agent(None),
+            proxy_url: None,
+        }
+    }
+}
+
+impl HttpClientConfig {
+    /// 환경 변수로 기본값을 덮어씁니다.
+    ///
+    /// - `HTTP_CONNECT_TIMEOUT_SECS`, `HTTP_TIMEOUT_SECS` : 연결/전체 요청 제한 시간 (초)
+    /// - `HTTP_MAX_RETRIES` : 5xx, 429 응답과 타임아웃에 대한 재시도 횟수
+    /// - `HTTP_PER_HOST_CONCURRENCY` : 같은 호스트로 동시에 보내는 최대 요청 수
+    /// - `HTTP_USER_AGENT_CONTACT` : User-Agent에 붙일 연락처 (예: 봇 안내 페이지)
+    /// - `HTTP_PROXY_URL` : 서버가 정한 API 주소로 보내는 요청에 사용할 프록시
+    ///   (사용자가 입력한 주소는 프록시가 DNS 검사를 건너뛰게 되므로 사용하지 않음)
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            connect_timeout: env_secs("HTTP_CONNECT_TIMEOUT_SECS")
+                .unwrap_or(default.connect_timeout),
+            timeout: env_secs("HTTP_TIMEOUT_SECS").unwrap_or(default.timeout),
+            max_retries: env::var("HTTP_MAX_RETRIES")
+                .ok()
+                .and_then(|v| v.parse::<u32>().ok())
+                .unwrap_or(default.max_retries),
+            per_host_concurrency: env::var("HTTP_PER_HOST_CONCURRENCY")
+                .ok()
+                .and_then(|v| v.parse::<usize>().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(default.per_host_concurrency),
+            user_agent: default_user_agent(
+                env::var("HTTP_USER_AGENT_CONTACT")
+                    .ok()
+                    .filter(|v| !v.is_empty()),
+            ),
+            proxy_url: env::var("HTTP_PROXY_URL").ok().filter(|v| !v.is_empty()),
+            ..default
+        }
+    }
+}
+
+/// 서버 전체에서 공유하는 외부 요청 클라이언트
+///
+/// 호스트별 동시 요청 수를 제한하고, 5xx와 429 응답은 `Retry-After` 또는 지수 백오프로 재시도합니다.
+/// 사용자가 입력한 주소는 `fetch_text`, `outbound_get`처럼 외부 요청 정책이 적용된 클라이언트로 요청해야 합니다.
+#[derive(Clone)]
+pub struct HttpClient {
+    cfg: HttpClientConfig,
+    client: Client,
+    outbound: Client,
+    host_limits: Arc<Mutex<HashMap<String, Arc<Semaphore>>>>,
+}
+
+impl HttpClient {
+    pub fn new(cfg: HttpClientConfig) -> Self {
+        let client = apply_proxy(apply_config(Client::builder(), &cfg), &cfg)
+            .build()
+            .expect("Failed to build http client");
+        let outbound = apply_config(url_policy_util::outbound_client_builder(), &cfg)
+            .build()
+            .expect("Failed to build outbound http client");
+
+        Self {
+            cfg,
+            client,
+            outbound,
+            host_limits: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// 서버가 정한 API 주소(뉴스 API, App Store 등)로 보내는 요청
+    pub fn get(&self, url: &str) -> RequestBuilder {
+        self.client.get(url)
+    }
+
+    /// 서버가 정한 API 주소(임베딩 API 등)로 보내는 POST 요청
+    pub fn post(&self, url: &str) -> RequestBuilder {
+        self.client.post(url)
+    }
+
+    /// 사용자가 입력한 주소로 보내는 요청
+    pub fn outbound_get(&self, url: &str) -> Result<RequestBuilder, OmniNewsError> {
+        let url: Url = url_policy_util::validate_outbound_url(url)?;
+        Ok(self.outbound.get(url))
+    }
+
+    /// 사용자가 입력한 주소의 본문을 크기 제한 안에서 받아 UTF-8 문자열로 변환합니다.
+    pub async fn fetch_text(&self, url: &str) -> Result<String, OmniNewsError> {
+        let response = self.send(self.outbound_get(url)?).await?;
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string());
+        let body = url_policy_util::read_body_limited(response, MAX_RESPONSE_BYTES).await?;
+        Ok(charset_util::decode_to_utf8(&body, content_type.as_deref()))
+    }
+
+    /// 호스트별 동시 요청 제한 안에서 요청을 보내고, 일시적인 실패는 재시도합니다.
+    ///
+    /// 본문을 스트리밍하는 요청처럼 복제할 수 없는 요청은 재시도하지 않습니다.
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, OmniNewsError> {
+        let (client, request) = request.build_split();
+        let request = request?;
+        let host = request.url().host_str().unwrap_or_default().to_string();
+        let _permit = self.acquire_host(&host).await;
+
+        let mut attempt = 0;
+        loop {
+            let Some(current) = request.try_clone() else {
+                return Ok(client.execute(request).await?);
+            };
+
+            let delay = match client.execute(current).await {
+                Ok(response) if is_retryable_status(response.status()) => {
+                    if attempt >= self.cfg.max_retries {
+                        return Ok(response);
+                    }
+                    retry_after(response.headers()).unwrap_or_else(|| self.backoff(attempt))
+                }
+                Ok(response) => return Ok(response),
+                Err(e) if e.is_timeout() && attempt < self.cfg.max_retries => self.backoff(attempt),
+                Err(e) => return Err(OmniNewsError::Request(e)),
+            };
+
+            attempt += 1;
+            server_warn!(
+                "[Http] Retry {} {} after {:?} (attempt {}/{})",
+                request.method(),
+                request.url(),
+                delay,
+                attempt,
+                self.cfg.max_retries
+            );
+            tokio::time::sleep(delay.min(self.cfg.max_backoff)).await;
+        }
+    }
+
+    async fn acquire_host(&self, host: &str) -> Option<OwnedSemaphorePermit> {
+        let semaphore = self
+            .host_limits
+            .lock()
+            .await
+            .entry(host.to_string())
+            .or_insert_with(|| Arc::new(Semaphore::new(self.cfg.per_host_concurrency)))
+            .clone();
+        semaphore.acquire_owned().await.ok()
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .cfg
+            .base_backoff
+            .saturating_mul(2u32.saturating_pow(attempt));
+        // 여러 요청이 동시에 다시 몰리지 않도록 최대 25%의 지연을 더한다.
+        let jitter = backoff.mul_f64(rand::random::<f64>() * 0.25);
+        (backoff + jitter).min(self.cfg.max_backoff)
+    }
+}
+
+fn apply_config(builder: ClientBuilder, cfg: &HttpClientConfig) -> ClientBuilder {
+    builder
+        .user_agent(cfg.user_agent.clone())
+        .connect_timeout(cfg.connect_timeout)
+        .timeout(cfg.timeout)
+        .gzip(true)
+        .brotli(true)
+}
+
+fn apply_proxy(builder: ClientBuilder, cfg: &HttpClientConfig) -> ClientBuilder {
+    match cfg.proxy_url.as_deref().map(Proxy::all) {
+        Some(Ok(proxy)) => builder.proxy(proxy),
+        Some(Err(e)) => {
+            server_warn!("[Http] Ignore invalid HTTP_PROXY_URL: {}", e);
+            builder
+        }
+        None => builder,
+    }
+}
+
+fn default_user_agent(contact: Option<String>) -> String {
+    match contact {
+        Some(contact) => format!(
+            "OmniNews/{} (RSS feed reader; +{})",
+            env!("CARGO_PKG_VERSION"),
+            contact
+        ),
+        None => format!("OmniNews/{} (RSS feed reader)", env!("CARGO_PKG_VERSION")),
+    }
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// `Retry-After` 헤더의 초 또는 HTTP 날짜를 대기 시간으로 바꿉니다.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    SystemTime::from(date)
+        .duration_since(SystemTime::now())
+        .ok()
+}
+
+fn env_secs(key: &str) -> Option<Duration> {
+    env::var(key)
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
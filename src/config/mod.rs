@@ -1,5 +1,6 @@
 pub mod env;
 pub mod http_auth;
+pub mod http_client;
 pub mod logging;
 pub mod openapi;
 pub mod rapidoc;
@@ -311,7 +311,10 @@ impl DriverPool {
         }
     }
 
-    #[allow(dead_code)]
+    pub fn max_sessions(&self) -> usize {
+        self.cfg.max_sessions
+    }
+
     pub async fn stats(&self) -> (usize, usize) {
         let guard = self.inner.lock().await;
         (guard.idle.len(), guard.total)
@@ -0,0 +1,67 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct AdminUserSearchRequestDto {
+    #[schemars(example = "example_search_value")]
+    pub search_value: Option<String>,
+    #[schemars(example = "example_page")]
+    pub page: Option<i64>,
+    #[schemars(example = "example_page_size")]
+    pub page_size: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AdminUserStatusRequestDto {
+    #[schemars(example = "example_user_id")]
+    pub user_id: Option<i32>,
+    #[schemars(example = "example_user_status")]
+    pub user_status: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AdminDeleteChannelRequestDto {
+    #[schemars(example = "example_channel_id")]
+    pub channel_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AdminDeleteItemRequestDto {
+    #[schemars(example = "example_rss_id")]
+    pub rss_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AdminRebuildEmbeddingRequestDto {
+    // 없으면 전체 채널
+    #[schemars(example = "example_channel_id")]
+    pub channel_id: Option<i32>,
+}
+
+fn example_search_value() -> &'static str {
+    "hong"
+}
+
+fn example_page() -> i64 {
+    1
+}
+
+fn example_page_size() -> i64 {
+    20
+}
+
+fn example_user_id() -> i32 {
+    1
+}
+
+fn example_user_status() -> &'static str {
+    "suspended"
+}
+
+fn example_channel_id() -> i32 {
+    1
+}
+
+fn example_rss_id() -> i32 {
+    1
+}
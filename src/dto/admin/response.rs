@@ -0,0 +1,227 @@
+use chrono::{DateTime, FixedOffset};
+use chrono_tz::Tz;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    model::{
+        embedding::{ReembeddingJob, ReembeddingJobPhase, ReembeddingJobStatus},
+        user::User,
+    },
+    utils::date_util::LocalizeTimestamps,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AdminUserResponseDto {
+    #[schemars(example = "example_user_id")]
+    pub user_id: Option<i32>,
+    #[schemars(example = "example_user_email")]
+    pub user_email: Option<String>,
+    #[schemars(example = "example_user_display_name")]
+    pub user_display_name: Option<String>,
+    #[schemars(example = "example_user_social_login_provider")]
+    pub user_social_login_provider: Option<String>,
+    #[schemars(example = "example_user_status")]
+    pub user_status: Option<String>,
+    #[schemars(example = "example_user_role")]
+    pub user_role: Option<String>,
+    #[schemars(example = "example_user_subscription_plan")]
+    pub user_subscription_plan: Option<bool>,
+    pub user_last_active_at: Option<DateTime<FixedOffset>>,
+    pub user_created_at: Option<DateTime<FixedOffset>>,
+}
+
+impl AdminUserResponseDto {
+    pub fn from_model(user: User) -> Self {
+        Self {
+            user_id: user.user_id,
+            user_email: user.user_email,
+            user_display_name: user.user_display_name,
+            user_social_login_provider: user.user_social_login_provider,
+            user_status: user.user_status,
+            user_role: user.user_role,
+            user_subscription_plan: user.user_subscription_plan.map(|v| v != 0),
+            user_last_active_at: user.user_last_active_at.map(|dt| dt.fixed_offset()),
+            user_created_at: user.user_created_at.map(|dt| dt.fixed_offset()),
+        }
+    }
+
+    pub fn from_model_list(users: Vec<User>) -> Vec<Self> {
+        users.into_iter().map(Self::from_model).collect()
+    }
+}
+
+impl LocalizeTimestamps for AdminUserResponseDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.user_last_active_at.localize(tz);
+        self.user_created_at.localize(tz);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DriverPoolStatsResponseDto {
+    #[schemars(example = "example_idle")]
+    pub idle: usize,
+    #[schemars(example = "example_total")]
+    pub total: usize,
+    #[schemars(example = "example_max_sessions")]
+    pub max_sessions: usize,
+}
+
+/// 임베딩 큐 상태
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EmbeddingQueueStatsResponseDto {
+    /// 워커가 아직 가져가지 않은 문장 수
+    #[schemars(example = "example_queued")]
+    pub queued: usize,
+    #[schemars(example = "example_queue_capacity")]
+    pub queue_capacity: usize,
+    #[schemars(example = "example_workers")]
+    pub workers: usize,
+    #[schemars(example = "example_max_batch_size")]
+    pub max_batch_size: usize,
+}
+
+/// 재임베딩 작업 진행 상황
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReembeddingJobResponseDto {
+    #[schemars(example = "example_job_id")]
+    pub job_id: Option<i32>,
+    /// 작업이 만드는 임베딩의 모델
+    #[schemars(example = "example_job_model_id")]
+    pub job_model_id: Option<String>,
+    #[schemars(example = "example_job_text_recipe_version")]
+    pub job_text_recipe_version: Option<i32>,
+    pub job_status: Option<ReembeddingJobStatus>,
+    /// 채널(channel)을 모두 처리한 뒤 아이템(item)을 처리한다.
+    pub job_phase: Option<ReembeddingJobPhase>,
+    /// 작업을 시작할 때 다시 임베딩해야 했던 채널과 아이템 수
+    #[schemars(example = "example_job_total")]
+    pub job_total: Option<i32>,
+    #[schemars(example = "example_job_processed")]
+    pub job_processed: Option<i32>,
+    /// 진행률 (0 ~ 100)
+    #[schemars(example = "example_job_progress")]
+    pub job_progress: f64,
+    pub job_error: Option<String>,
+    pub job_created_at: Option<DateTime<FixedOffset>>,
+    pub job_updated_at: Option<DateTime<FixedOffset>>,
+    pub job_completed_at: Option<DateTime<FixedOffset>>,
+}
+
+impl ReembeddingJobResponseDto {
+    pub fn from_model(job: ReembeddingJob) -> Self {
+        let total = job.job_total.unwrap_or_default();
+        let processed = job.job_processed.unwrap_or_default();
+        let job_progress = if job.status() == Some(ReembeddingJobStatus::Completed) {
+            100.0
+        } else if total > 0 {
+            (processed as f64 / total as f64 * 100.0).min(100.0)
+        } else {
+            0.0
+        };
+
+        Self {
+            job_status: job.status(),
+            job_phase: Some(job.phase()),
+            job_id: job.job_id,
+            job_model_id: job.job_model_id,
+            job_text_recipe_version: job.job_text_recipe_version,
+            job_total: job.job_total,
+            job_processed: job.job_processed,
+            job_progress,
+            job_error: job.job_error,
+            job_created_at: job.job_created_at.map(|dt| dt.fixed_offset()),
+            job_updated_at: job.job_updated_at.map(|dt| dt.fixed_offset()),
+            job_completed_at: job.job_completed_at.map(|dt| dt.fixed_offset()),
+        }
+    }
+}
+
+impl LocalizeTimestamps for ReembeddingJobResponseDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.job_created_at.localize(tz);
+        self.job_updated_at.localize(tz);
+        self.job_completed_at.localize(tz);
+    }
+}
+
+fn example_user_id() -> i32 {
+    1
+}
+
+fn example_user_email() -> &'static str {
+    "hong11@gil.com"
+}
+
+fn example_user_display_name() -> &'static str {
+    "홍길동"
+}
+
+fn example_user_social_login_provider() -> &'static str {
+    "google"
+}
+
+fn example_user_status() -> &'static str {
+    "active"
+}
+
+fn example_user_role() -> &'static str {
+    "user"
+}
+
+fn example_user_subscription_plan() -> bool {
+    false
+}
+
+fn example_idle() -> usize {
+    1
+}
+
+fn example_total() -> usize {
+    2
+}
+
+fn example_max_sessions() -> usize {
+    3
+}
+
+fn example_queued() -> usize {
+    12
+}
+
+fn example_queue_capacity() -> usize {
+    1024
+}
+
+fn example_workers() -> usize {
+    2
+}
+
+fn example_max_batch_size() -> usize {
+    32
+}
+
+fn example_job_id() -> i32 {
+    1
+}
+
+fn example_job_model_id() -> &'static str {
+    "text-embedding-3-small"
+}
+
+fn example_job_text_recipe_version() -> i32 {
+    2
+}
+
+fn example_job_total() -> i32 {
+    120000
+}
+
+fn example_job_processed() -> i32 {
+    30000
+}
+
+fn example_job_progress() -> f64 {
+    25.0
+}
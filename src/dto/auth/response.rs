@@ -1,8 +1,8 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Utc};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::model::auth::{AccessToken, JwtToken};
+use crate::model::auth::JwtToken;
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct JwtTokenResponseDto {
@@ -11,17 +11,9 @@ pub struct JwtTokenResponseDto {
     #[schemars(example = "example_refresh_token")]
     pub refresh_token: Option<String>,
     #[schemars(example = "example_access_token_expires")]
-    pub access_token_expires_at: Option<NaiveDateTime>,
+    pub access_token_expires_at: Option<DateTime<Utc>>,
     #[schemars(example = "example_refresh_token_expires")]
-    pub refresh_token_expires_at: Option<NaiveDateTime>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
-pub struct AccessTokenResponseDto {
-    #[schemars(example = "example_access_token")]
-    pub access_token: Option<String>,
-    #[schemars(example = "example_access_token_expires")]
-    pub access_token_expires_at: Option<NaiveDateTime>,
+    pub refresh_token_expires_at: Option<DateTime<Utc>>,
 }
 
 impl JwtTokenResponseDto {
@@ -35,15 +27,6 @@ impl JwtTokenResponseDto {
     }
 }
 
-impl AccessTokenResponseDto {
-    pub fn from_model(access_token: AccessToken) -> Self {
-        AccessTokenResponseDto {
-            access_token: access_token.access_token,
-            access_token_expires_at: access_token.access_token_expires_at,
-        }
-    }
-}
-
 fn example_access_token() -> &'static str {
     "eyJ0eXAiOiJKV1QiLCJhbGciOiJIUz..."
 }
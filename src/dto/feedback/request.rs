@@ -0,0 +1,68 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CreateFeedbackRequestDto {
+    #[schemars(example = "example_feedback_content")]
+    pub feedback_content: Option<String>,
+    #[schemars(example = "example_app_version")]
+    pub app_version: Option<String>,
+    #[schemars(example = "example_device_info")]
+    pub device_info: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct FeedbackSearchRequestDto {
+    #[schemars(example = "example_feedback_status")]
+    pub feedback_status: Option<String>,
+    #[schemars(example = "example_page")]
+    pub page: Option<i64>,
+    #[schemars(example = "example_page_size")]
+    pub page_size: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FeedbackTagRequestDto {
+    #[schemars(example = "example_feedback_id")]
+    pub feedback_id: Option<i32>,
+    #[schemars(example = "example_feedback_tags")]
+    pub feedback_tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FeedbackResolveRequestDto {
+    #[schemars(example = "example_feedback_id")]
+    pub feedback_id: Option<i32>,
+}
+
+fn example_feedback_content() -> &'static str {
+    "구독한 채널의 새 글 알림이 오지 않아요."
+}
+
+fn example_app_version() -> &'static str {
+    "1.4.2"
+}
+
+fn example_device_info() -> &'static str {
+    "iPhone15,2 / iOS 17.5"
+}
+
+fn example_feedback_status() -> &'static str {
+    "open"
+}
+
+fn example_page() -> i64 {
+    1
+}
+
+fn example_page_size() -> i64 {
+    20
+}
+
+fn example_feedback_id() -> i32 {
+    1
+}
+
+fn example_feedback_tags() -> Vec<String> {
+    vec!["bug".to_string(), "notification".to_string()]
+}
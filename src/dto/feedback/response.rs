@@ -0,0 +1,89 @@
+use chrono::{DateTime, FixedOffset};
+use chrono_tz::Tz;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{model::feedback::Feedback, utils::date_util::LocalizeTimestamps};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FeedbackResponseDto {
+    #[schemars(example = "example_feedback_id")]
+    pub feedback_id: Option<i32>,
+    #[schemars(example = "example_feedback_email")]
+    pub feedback_email: Option<String>,
+    #[schemars(example = "example_feedback_content")]
+    pub feedback_content: Option<String>,
+    #[schemars(example = "example_app_version")]
+    pub app_version: Option<String>,
+    #[schemars(example = "example_device_info")]
+    pub device_info: Option<String>,
+    #[schemars(example = "example_feedback_status")]
+    pub feedback_status: Option<String>,
+    #[schemars(example = "example_feedback_tags")]
+    pub feedback_tags: Vec<String>,
+    pub feedback_created_at: Option<DateTime<FixedOffset>>,
+    pub feedback_resolved_at: Option<DateTime<FixedOffset>>,
+}
+
+impl FeedbackResponseDto {
+    pub fn from_model(feedback: Feedback) -> Self {
+        Self {
+            feedback_id: feedback.feedback_id,
+            feedback_email: feedback.feedback_email,
+            feedback_content: feedback.feedback_content,
+            app_version: feedback.feedback_app_version,
+            device_info: feedback.feedback_device_info,
+            feedback_status: feedback.feedback_status,
+            feedback_tags: feedback
+                .feedback_tags
+                .map(|tags| {
+                    tags.split(',')
+                        .filter(|tag| !tag.is_empty())
+                        .map(|tag| tag.to_string())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            feedback_created_at: feedback.feedback_created_at.map(|dt| dt.fixed_offset()),
+            feedback_resolved_at: feedback.feedback_resolved_at.map(|dt| dt.fixed_offset()),
+        }
+    }
+
+    pub fn from_model_list(feedbacks: Vec<Feedback>) -> Vec<Self> {
+        feedbacks.into_iter().map(Self::from_model).collect()
+    }
+}
+
+impl LocalizeTimestamps for FeedbackResponseDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.feedback_created_at.localize(tz);
+        self.feedback_resolved_at.localize(tz);
+    }
+}
+
+fn example_feedback_id() -> i32 {
+    1
+}
+
+fn example_feedback_email() -> &'static str {
+    "hong11@gil.com"
+}
+
+fn example_feedback_content() -> &'static str {
+    "구독한 채널의 새 글 알림이 오지 않아요."
+}
+
+fn example_app_version() -> &'static str {
+    "1.4.2"
+}
+
+fn example_device_info() -> &'static str {
+    "iPhone15,2 / iOS 17.5"
+}
+
+fn example_feedback_status() -> &'static str {
+    "open"
+}
+
+fn example_feedback_tags() -> Vec<String> {
+    vec!["bug".to_string(), "notification".to_string()]
+}
@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 pub struct CreateFolderRequestDto {
     #[schemars(example = "example_folder_name")]
     pub folder_name: Option<String>,
+    #[schemars(example = "example_parent_folder_id")]
+    pub parent_folder_id: Option<i32>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
@@ -21,6 +23,10 @@ pub struct UpdateFolderRequestDto {
     pub folder_id: Option<i32>,
     #[schemars(example = "example_folder_name")]
     pub folder_name: Option<String>,
+    #[schemars(example = "example_parent_folder_id")]
+    pub parent_folder_id: Option<i32>,
+    #[schemars(example = "example_move_to_top_level")]
+    pub move_to_top_level: Option<bool>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
@@ -29,6 +35,58 @@ pub struct DeleteFolderRequestDto {
     pub folder_id: Option<i32>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct FolderItemsRequestDto {
+    #[schemars(example = "example_cursor")]
+    pub cursor: Option<String>,
+    #[schemars(example = "example_limit")]
+    pub limit: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FolderOrderRequestDto {
+    #[schemars(example = "example_folder_ids")]
+    pub folder_ids: Option<Vec<i32>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FolderChannelOrderRequestDto {
+    #[schemars(example = "example_folder_id")]
+    pub folder_id: Option<i32>,
+    #[schemars(example = "example_channel_ids")]
+    pub channel_ids: Option<Vec<i32>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CreateSmartFolderRequestDto {
+    #[schemars(example = "example_smart_folder_name")]
+    pub smart_folder_name: Option<String>,
+    #[schemars(example = "example_smart_folder_query")]
+    pub smart_folder_query: Option<String>,
+    #[schemars(example = "example_keywords")]
+    pub keywords: Option<Vec<String>>,
+    #[schemars(example = "example_channel_ids")]
+    pub channel_ids: Option<Vec<i32>>,
+    #[schemars(example = "example_language")]
+    pub language: Option<String>,
+    #[schemars(example = "example_days")]
+    pub days: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateSmartFolderRequestDto {
+    #[schemars(example = "example_smart_folder_id")]
+    pub smart_folder_id: Option<i32>,
+    #[serde(flatten)]
+    pub smart_folder: CreateSmartFolderRequestDto,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DeleteSmartFolderRequestDto {
+    #[schemars(example = "example_smart_folder_id")]
+    pub smart_folder_id: Option<i32>,
+}
+
 fn example_folder_name() -> &'static str {
     "Development"
 }
@@ -39,3 +97,51 @@ fn example_channel_id() -> i32 {
 fn example_folder_id() -> i32 {
     2
 }
+
+fn example_parent_folder_id() -> i32 {
+    1
+}
+
+fn example_move_to_top_level() -> bool {
+    false
+}
+
+fn example_cursor() -> &'static str {
+    "1718000000_1523"
+}
+
+fn example_limit() -> i64 {
+    20
+}
+
+fn example_folder_ids() -> Vec<i32> {
+    vec![2, 1, 3]
+}
+
+fn example_channel_ids() -> Vec<i32> {
+    vec![3, 7, 5]
+}
+
+fn example_smart_folder_id() -> i32 {
+    4
+}
+
+fn example_smart_folder_name() -> &'static str {
+    "AI 뉴스"
+}
+
+fn example_smart_folder_query() -> &'static str {
+    "인공지능 신기술"
+}
+
+fn example_keywords() -> Vec<&'static str> {
+    vec!["AI", "LLM"]
+}
+
+fn example_language() -> &'static str {
+    "ko"
+}
+
+fn example_days() -> i32 {
+    7
+}
@@ -1,7 +1,15 @@
+use chrono_tz::Tz;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{dto::rss::response::RssChannelResponseDto, model::rss::RssChannel};
+use crate::{
+    dto::rss::response::{RssChannelResponseDto, RssItemResponseDto},
+    model::{
+        folder::{ItemCursor, RssFolder, SmartFolder},
+        rss::{RssChannel, RssItem},
+    },
+    utils::date_util::LocalizeTimestamps,
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct RssFolderResponseDto {
@@ -9,30 +17,145 @@ pub struct RssFolderResponseDto {
     pub folder_id: Option<i32>,
     #[schemars(example = "example_folder_name")]
     pub folder_name: Option<String>,
+    #[schemars(example = "example_parent_folder_id")]
+    pub parent_folder_id: Option<i32>,
+    #[schemars(example = "example_folder_order")]
+    pub folder_order: Option<i32>,
     #[schemars(example = "example_channel_id")]
     pub folder_channels: Option<Vec<RssChannelResponseDto>>,
+    /// "folder" 또는 "smart"
+    #[schemars(example = "example_folder_type")]
+    pub folder_type: Option<String>,
+    /// 스마트 폴더의 검색 조건 (일반 폴더는 null)
+    pub smart_folder: Option<SmartFolderResponseDto>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SmartFolderResponseDto {
+    #[schemars(example = "example_smart_folder_query")]
+    pub smart_folder_query: Option<String>,
+    #[schemars(example = "example_keywords")]
+    pub keywords: Vec<String>,
+    #[schemars(example = "example_channel_ids")]
+    pub channel_ids: Vec<i32>,
+    #[schemars(example = "example_language")]
+    pub language: Option<String>,
+    #[schemars(example = "example_days")]
+    pub days: Option<i32>,
 }
 
 impl RssFolderResponseDto {
-    pub fn new(
-        folder_id: Option<i32>,
-        folder_name: Option<String>,
-        channels: Vec<RssChannel>,
-    ) -> Self {
+    pub fn new(folder: RssFolder, channels: Vec<RssChannel>) -> Self {
         Self {
-            folder_id,
-            folder_name,
+            folder_id: folder.folder_id,
+            folder_name: folder.folder_name,
+            parent_folder_id: folder.parent_folder_id,
+            folder_order: folder.folder_order,
             folder_channels: Some(RssChannelResponseDto::from_model_list(channels)),
+            folder_type: Some("folder".to_string()),
+            smart_folder: None,
+        }
+    }
+
+    /// 스마트 폴더의 `folder_id`는 `smart_folder_id` 입니다.
+    pub fn from_smart_folder(folder: SmartFolder) -> Self {
+        Self {
+            folder_id: folder.smart_folder_id,
+            folder_name: folder.smart_folder_name.clone(),
+            parent_folder_id: None,
+            folder_order: folder.smart_folder_order,
+            folder_channels: None,
+            folder_type: Some("smart".to_string()),
+            smart_folder: Some(SmartFolderResponseDto::from_model(folder)),
         }
     }
 }
 
+impl SmartFolderResponseDto {
+    pub fn from_model(folder: SmartFolder) -> Self {
+        Self {
+            keywords: folder.keywords(),
+            channel_ids: folder.channel_ids(),
+            smart_folder_query: folder.smart_folder_query,
+            language: folder.smart_folder_language,
+            days: folder.smart_folder_days,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FolderItemsResponseDto {
+    pub items: Vec<RssItemResponseDto>,
+    /// 다음 페이지 요청에 사용할 커서. 마지막 페이지면 null
+    #[schemars(example = "example_next_cursor")]
+    pub next_cursor: Option<String>,
+}
+
+impl FolderItemsResponseDto {
+    /// `limit`보다 하나 더 조회한 결과로 다음 페이지 커서를 만든다.
+    pub fn new(items: Vec<RssItem>, next_cursor: Option<ItemCursor>) -> Self {
+        Self {
+            items: RssItemResponseDto::from_model_list(items),
+            next_cursor: next_cursor.map(|cursor| cursor.encode()),
+        }
+    }
+
+    pub fn from_page(mut items: Vec<RssItem>, limit: usize) -> Self {
+        let next_cursor = if items.len() > limit {
+            items.truncate(limit);
+            items
+                .last()
+                .map(|item| ItemCursor::from_item(item).encode())
+        } else {
+            None
+        };
+
+        Self {
+            items: RssItemResponseDto::from_model_list(items),
+            next_cursor,
+        }
+    }
+}
+
+impl LocalizeTimestamps for FolderItemsResponseDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.items.localize(tz);
+    }
+}
+
 fn example_folder_id() -> i32 {
     1
 }
 fn example_folder_name() -> &'static str {
     "Tech News"
 }
+fn example_parent_folder_id() -> i32 {
+    2
+}
+fn example_folder_order() -> i32 {
+    0
+}
+fn example_folder_type() -> &'static str {
+    "folder"
+}
+fn example_smart_folder_query() -> &'static str {
+    "인공지능 신기술"
+}
+fn example_keywords() -> Vec<&'static str> {
+    vec!["AI", "LLM"]
+}
+fn example_channel_ids() -> Vec<i32> {
+    vec![3, 7]
+}
+fn example_language() -> &'static str {
+    "ko"
+}
+fn example_days() -> i32 {
+    7
+}
+fn example_next_cursor() -> &'static str {
+    "1718000000_1523"
+}
 fn example_channel_id() -> i32 {
     3
 }
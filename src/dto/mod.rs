@@ -1,6 +1,9 @@
+pub mod admin;
 pub mod auth;
+pub mod feedback;
 pub mod folder;
 pub mod health;
+pub mod mute;
 pub mod news;
 pub mod omninews_subscription;
 pub mod premium;
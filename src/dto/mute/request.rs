@@ -0,0 +1,34 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CreateMuteRuleRequestDto {
+    #[schemars(example = "example_mute_rule_type")]
+    pub mute_rule_type: Option<String>,
+    #[schemars(example = "example_mute_rule_value")]
+    pub mute_rule_value: Option<String>,
+    #[schemars(example = "example_days")]
+    pub days: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DeleteMuteRuleRequestDto {
+    #[schemars(example = "example_mute_rule_id")]
+    pub mute_rule_id: Option<i32>,
+}
+
+fn example_mute_rule_type() -> &'static str {
+    "keyword"
+}
+
+fn example_mute_rule_value() -> &'static str {
+    "광고"
+}
+
+fn example_days() -> i32 {
+    30
+}
+
+fn example_mute_rule_id() -> i32 {
+    1
+}
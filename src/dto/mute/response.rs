@@ -0,0 +1,54 @@
+use chrono::{DateTime, FixedOffset};
+use chrono_tz::Tz;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{model::mute::MuteRule, utils::date_util::LocalizeTimestamps};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MuteRuleResponseDto {
+    #[schemars(example = "example_mute_rule_id")]
+    pub mute_rule_id: Option<i32>,
+    #[schemars(example = "example_mute_rule_type")]
+    pub mute_rule_type: Option<String>,
+    #[schemars(example = "example_mute_rule_value")]
+    pub mute_rule_value: Option<String>,
+    /// 만료 시각 (null이면 만료되지 않음)
+    pub mute_rule_expires_at: Option<DateTime<FixedOffset>>,
+    pub mute_rule_created_at: Option<DateTime<FixedOffset>>,
+}
+
+impl MuteRuleResponseDto {
+    pub fn from_model(rule: MuteRule) -> Self {
+        Self {
+            mute_rule_id: rule.mute_rule_id,
+            mute_rule_type: rule.mute_rule_type,
+            mute_rule_value: rule.mute_rule_value,
+            mute_rule_expires_at: rule.mute_rule_expires_at.map(|dt| dt.fixed_offset()),
+            mute_rule_created_at: rule.mute_rule_created_at.map(|dt| dt.fixed_offset()),
+        }
+    }
+
+    pub fn from_model_list(rules: Vec<MuteRule>) -> Vec<Self> {
+        rules.into_iter().map(Self::from_model).collect()
+    }
+}
+
+impl LocalizeTimestamps for MuteRuleResponseDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.mute_rule_expires_at.localize(tz);
+        self.mute_rule_created_at.localize(tz);
+    }
+}
+
+fn example_mute_rule_id() -> i32 {
+    1
+}
+
+fn example_mute_rule_type() -> &'static str {
+    "domain"
+}
+
+fn example_mute_rule_value() -> &'static str {
+    "example.com"
+}
@@ -1,8 +1,9 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, FixedOffset, Utc};
+use chrono_tz::Tz;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::model::news::News;
+use crate::{model::news::News, utils::date_util::LocalizeTimestamps};
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct NewsResponseDto {
@@ -19,7 +20,7 @@ pub struct NewsResponseDto {
     #[schemars(example = "example_news_source")]
     pub news_source: Option<String>,
     #[schemars(example = "example_news_pub_date")]
-    pub news_pub_date: Option<NaiveDateTime>,
+    pub news_pub_date: Option<DateTime<FixedOffset>>,
     #[schemars(example = "example_news_image_link")]
     pub news_image_link: Option<String>,
     #[schemars(example = "example_news_category")]
@@ -37,7 +38,7 @@ pub struct NewsApiResponseDto {
     #[schemars(example = "example_news_description")]
     pub news_description: Option<String>,
     #[schemars(example = "example_news_pub_date")]
-    pub news_pub_date: Option<NaiveDateTime>,
+    pub news_pub_date: Option<DateTime<FixedOffset>>,
 }
 
 impl NewsResponseDto {
@@ -49,7 +50,7 @@ impl NewsResponseDto {
             news_summary: news.news_summary,
             news_link: news.news_link,
             news_source: news.news_source,
-            news_pub_date: news.news_pub_date,
+            news_pub_date: news.news_pub_date.map(|dt| dt.fixed_offset()),
             news_image_link: news.news_image_link,
             news_category: news.news_category,
         }
@@ -60,24 +61,36 @@ impl NewsResponseDto {
     }
 }
 
+impl LocalizeTimestamps for NewsResponseDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.news_pub_date.localize(tz);
+    }
+}
+
 impl NewsApiResponseDto {
     pub fn new(
         news_title: String,
         news_original_link: String,
         news_link: String,
         news_description: String,
-        news_pub_date: NaiveDateTime,
+        news_pub_date: DateTime<Utc>,
     ) -> Self {
         NewsApiResponseDto {
             news_title: Some(news_title),
             news_original_link: Some(news_original_link),
             news_link: Some(news_link),
             news_description: Some(news_description),
-            news_pub_date: Some(news_pub_date),
+            news_pub_date: Some(news_pub_date.fixed_offset()),
         }
     }
 }
 
+impl LocalizeTimestamps for NewsApiResponseDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.news_pub_date.localize(tz);
+    }
+}
+
 /*
 *
     pub news_id: Option<i32>,
@@ -86,7 +99,7 @@ impl NewsApiResponseDto {
     pub news_summary: Option<String>,
     pub news_link: Option<String>,
     pub news_source: Option<String>,
-    pub news_pub_date: Option<NaiveDateTime>,
+    pub news_pub_date: Option<DateTime<Utc>>,
     pub news_image_link: Option<String>,
     pub news_category: Option<String>,
 */
@@ -108,8 +121,10 @@ fn example_news_link() -> &'static str {
 fn example_news_source() -> &'static str {
     "Example News Source"
 }
-fn example_news_pub_date() -> NaiveDateTime {
-    NaiveDateTime::parse_from_str("2023-10-01 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+fn example_news_pub_date() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2023-10-01T03:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
 }
 fn example_news_image_link() -> &'static str {
     "https://example.com/news/1/image.jpg"
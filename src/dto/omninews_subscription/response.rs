@@ -1,14 +1,15 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, FixedOffset};
+use chrono_tz::Tz;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::model::user::User;
+use crate::{model::user::User, utils::date_util::LocalizeTimestamps};
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct OmninewsSubscriptionResponseDto {
     pub is_active: bool,
     pub product_id: String,
-    pub expires_date: NaiveDateTime,
+    pub expires_date: DateTime<FixedOffset>,
 }
 
 impl OmninewsSubscriptionResponseDto {
@@ -17,7 +18,16 @@ impl OmninewsSubscriptionResponseDto {
             is_active: user.user_subscription_plan.is_some()
                 && user.user_subscription_plan.unwrap() > 0,
             product_id: user.user_subscription_product_id.unwrap_or_default(),
-            expires_date: user.user_subscription_end_date.unwrap_or_default(),
+            expires_date: user
+                .user_subscription_end_date
+                .unwrap_or_default()
+                .fixed_offset(),
         }
     }
 }
+
+impl LocalizeTimestamps for OmninewsSubscriptionResponseDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.expires_date.localize(tz);
+    }
+}
@@ -1,10 +1,64 @@
+use chrono::{DateTime, FixedOffset};
+use chrono_tz::Tz;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::dto::rss::response::RssChannelResponseDto;
+use crate::{
+    dto::rss::response::RssChannelResponseDto,
+    model::premium::rss_generate_job::{RssGenerateJob, RssGenerateJobStatus},
+    utils::date_util::LocalizeTimestamps,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RssGenerateResponseDto {
     pub is_exist: bool,
     pub channel: RssChannelResponseDto,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RssGenerateJobResponseDto {
+    #[schemars(example = "example_job_id")]
+    pub job_id: String,
+    pub status: RssGenerateJobStatus,
+    // 대기 중일 때 앞에 남은 작업 수
+    #[schemars(example = "example_queue_position")]
+    pub queue_position: Option<usize>,
+    pub result: Option<RssGenerateResponseDto>,
+    #[schemars(example = "example_error")]
+    pub error: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl RssGenerateJobResponseDto {
+    pub fn from_model(job: RssGenerateJob, queue_position: Option<usize>) -> Self {
+        Self {
+            job_id: job.job_id,
+            status: job.status,
+            queue_position,
+            result: job.result,
+            error: job.error,
+            created_at: job.created_at.fixed_offset(),
+            updated_at: job.updated_at.fixed_offset(),
+        }
+    }
+}
+
+impl LocalizeTimestamps for RssGenerateJobResponseDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.created_at.localize(tz);
+        self.updated_at.localize(tz);
+    }
+}
+
+fn example_job_id() -> &'static str {
+    "9b2f3c1e-6a8d-4f0b-9c3e-2d7a1b5e8f40"
+}
+
+fn example_queue_position() -> usize {
+    0
+}
+
+fn example_error() -> &'static str {
+    "Failed to fetch URL"
+}
@@ -1,8 +1,12 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, FixedOffset, Utc};
+use chrono_tz::Tz;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::model::rss::{RssChannel, RssItem};
+use crate::{
+    model::rss::{RssChannel, RssItem},
+    utils::{date_util::LocalizeTimestamps, image_proxy_util::proxied_image_url},
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RssChannelResponseDto {
@@ -41,7 +45,7 @@ pub struct RssItemResponseDto {
     #[schemars(example = "example_rss_author")]
     pub rss_author: Option<String>,
     #[schemars(example = "example_rss_pub_date")]
-    pub rss_pub_date: Option<NaiveDateTime>,
+    pub rss_pub_date: Option<DateTime<FixedOffset>>,
     #[schemars(example = "example_rss_rank")]
     pub rss_rank: Option<i32>,
     #[schemars(example = "example_rss_image_link")]
@@ -55,7 +59,7 @@ impl RssChannelResponseDto {
             channel_title: channel.channel_title,
             channel_link: channel.channel_link,
             channel_description: channel.channel_description,
-            channel_image_url: channel.channel_image_url,
+            channel_image_url: proxied_image_url(channel.channel_image_url),
             channel_language: channel.channel_language,
             rss_generator: channel.rss_generator,
             channel_rank: channel.channel_rank,
@@ -77,9 +81,9 @@ impl RssItemResponseDto {
             rss_description: item.rss_description,
             rss_link: item.rss_link,
             rss_author: item.rss_author,
-            rss_pub_date: item.rss_pub_date,
+            rss_pub_date: item.rss_pub_date.map(|dt| dt.fixed_offset()),
             rss_rank: item.rss_rank,
-            rss_image_link: item.rss_image_link,
+            rss_image_link: proxied_image_url(item.rss_image_link),
         }
     }
 
@@ -88,6 +92,12 @@ impl RssItemResponseDto {
     }
 }
 
+impl LocalizeTimestamps for RssItemResponseDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.rss_pub_date.localize(tz);
+    }
+}
+
 // channel
 fn example_channel_id() -> i32 {
     12345
@@ -133,10 +143,10 @@ fn example_rss_link() -> &'static str {
 fn example_rss_author() -> &'static str {
     "John Doe"
 }
-fn example_rss_pub_date() -> NaiveDateTime {
-    NaiveDateTime::parse_from_str("1970-01-01T00:00:00", "%Y-%m-%dT%H:%M:%S")
-        .ok()
+fn example_rss_pub_date() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2024-05-01T06:20:00Z")
         .unwrap()
+        .with_timezone(&Utc)
 }
 fn example_rss_rank() -> i32 {
     10
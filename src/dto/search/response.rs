@@ -1,7 +1,11 @@
+use chrono_tz::Tz;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::dto::rss::response::{RssChannelResponseDto, RssItemResponseDto};
+use crate::{
+    dto::rss::response::{RssChannelResponseDto, RssItemResponseDto},
+    utils::date_util::LocalizeTimestamps,
+};
 
 #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
 pub struct SearchResponseDto {
@@ -35,6 +39,12 @@ impl SearchResponseDto {
     }
 }
 
+impl LocalizeTimestamps for SearchResponseDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.items.localize(tz);
+    }
+}
+
 fn example_channels() -> Vec<RssChannelResponseDto> {
     vec![
         RssChannelResponseDto {
@@ -13,12 +13,20 @@ pub struct LoginUserRequestDto {
     pub user_social_login_provider: Option<String>,
     #[schemars(example = "example_user_social_provider_id")]
     pub user_social_provider_id: Option<String>,
+    #[schemars(example = "example_device_id")]
+    pub device_id: Option<String>,
+    #[schemars(example = "example_id_token")]
+    pub id_token: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AppleLoginRequestDto {
     #[schemars(example = "example_user_social_provider_id")]
     pub user_social_provider_id: Option<String>,
+    #[schemars(example = "example_device_id")]
+    pub device_id: Option<String>,
+    #[schemars(example = "example_id_token")]
+    pub id_token: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -35,6 +43,12 @@ pub struct UserThemeRequestDto {
     pub theme: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UserTimezoneRequestDto {
+    #[schemars(example = "example_user_timezone")]
+    pub user_timezone: Option<String>,
+}
+
 fn example_user_email() -> &'static str {
     "hong11@gil.com"
 }
@@ -55,6 +69,14 @@ fn example_user_social_provider_id() -> &'static str {
     "1234567890"
 }
 
+fn example_device_id() -> &'static str {
+    "0f8fad5b-d9cb-469f-a165-70867728950e"
+}
+
+fn example_id_token() -> &'static str {
+    "eyJhbGciOiJSUzI1NiIsImtpZCI6IjEyMzQ1Njc4OTAifQ.eyJzdWIiOiIxMjM0NTY3ODkwIn0.signature"
+}
+
 fn example_user_notification_push() -> bool {
     true
 }
@@ -66,3 +88,7 @@ fn example_user_fcm_token() -> &'static str {
 fn example_theme() -> &'static str {
     "paper"
 }
+
+fn example_user_timezone() -> &'static str {
+    "Asia/Seoul"
+}
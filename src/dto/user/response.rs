@@ -1,6 +1,17 @@
+use chrono::{DateTime, FixedOffset};
+use chrono_tz::Tz;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    dto::{
+        feedback::response::FeedbackResponseDto, folder::response::RssFolderResponseDto,
+        mute::response::MuteRuleResponseDto, rss::response::RssChannelResponseDto,
+    },
+    model::{session::UserSession, user::User},
+    utils::date_util::LocalizeTimestamps,
+};
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UserThemeResponseDto {
     #[schemars(example = "example_theme")]
@@ -16,3 +27,161 @@ impl UserThemeResponseDto {
 fn example_theme() -> &'static str {
     "paper"
 }
+
+/// 인증된 요청의 응답 시각은 이 시간대의 RFC 3339 값으로 변환되어 내려갑니다.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UserTimezoneResponseDto {
+    #[schemars(example = "example_user_timezone")]
+    user_timezone: Option<String>,
+}
+
+impl UserTimezoneResponseDto {
+    pub fn new(user_timezone: String) -> Self {
+        UserTimezoneResponseDto {
+            user_timezone: Some(user_timezone),
+        }
+    }
+}
+
+fn example_user_timezone() -> &'static str {
+    "Asia/Seoul"
+}
+
+/// 개인정보 내보내기 묶음
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UserExportResponseDto {
+    pub exported_at: DateTime<FixedOffset>,
+    pub profile: UserExportProfileDto,
+    pub subscriptions: Vec<RssChannelResponseDto>,
+    pub folders: Vec<RssFolderResponseDto>,
+    pub smart_folders: Vec<RssFolderResponseDto>,
+    pub mute_rules: Vec<MuteRuleResponseDto>,
+    pub reading_history: UserReadingHistoryDto,
+    pub devices: Vec<UserDeviceDto>,
+    pub feedback: Vec<FeedbackResponseDto>,
+}
+
+impl LocalizeTimestamps for UserExportResponseDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.exported_at.localize(tz);
+        self.profile.localize(tz);
+        self.mute_rules.localize(tz);
+        self.reading_history.localize(tz);
+        self.devices.localize(tz);
+        self.feedback.localize(tz);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UserExportProfileDto {
+    #[schemars(example = "example_user_email")]
+    pub user_email: Option<String>,
+    #[schemars(example = "example_user_display_name")]
+    pub user_display_name: Option<String>,
+    pub user_photo_url: Option<String>,
+    #[schemars(example = "example_user_social_login_provider")]
+    pub user_social_login_provider: Option<String>,
+    #[schemars(example = "example_theme")]
+    pub user_theme: Option<String>,
+    #[schemars(example = "example_user_timezone")]
+    pub user_timezone: Option<String>,
+    pub user_notification_push: Option<bool>,
+    pub user_subscription_plan: Option<bool>,
+    pub user_subscription_start_date: Option<DateTime<FixedOffset>>,
+    pub user_subscription_end_date: Option<DateTime<FixedOffset>>,
+    pub user_created_at: Option<DateTime<FixedOffset>>,
+}
+
+/// 아이템별 읽음 기록은 저장하지 않으므로 누적 값만 제공합니다.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UserReadingHistoryDto {
+    #[schemars(example = "example_user_articles_read")]
+    pub user_articles_read: Option<i32>,
+    pub user_last_active_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UserDeviceDto {
+    pub device_id: Option<String>,
+    pub created_at: Option<DateTime<FixedOffset>>,
+    pub last_used_at: Option<DateTime<FixedOffset>>,
+    pub revoked_at: Option<DateTime<FixedOffset>>,
+}
+
+impl UserExportProfileDto {
+    pub fn from_model(user: &User) -> Self {
+        Self {
+            user_email: user.user_email.clone(),
+            user_display_name: user.user_display_name.clone(),
+            user_photo_url: user.user_photo_url.clone(),
+            user_social_login_provider: user.user_social_login_provider.clone(),
+            user_theme: user.user_theme.clone(),
+            user_timezone: user.user_timezone.clone(),
+            user_notification_push: user.user_notification_push.map(|v| v != 0),
+            user_subscription_plan: user.user_subscription_plan.map(|v| v != 0),
+            user_subscription_start_date: user
+                .user_subscription_start_date
+                .map(|dt| dt.fixed_offset()),
+            user_subscription_end_date: user.user_subscription_end_date.map(|dt| dt.fixed_offset()),
+            user_created_at: user.user_created_at.map(|dt| dt.fixed_offset()),
+        }
+    }
+}
+
+impl LocalizeTimestamps for UserExportProfileDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.user_subscription_start_date.localize(tz);
+        self.user_subscription_end_date.localize(tz);
+        self.user_created_at.localize(tz);
+    }
+}
+
+impl UserReadingHistoryDto {
+    pub fn from_model(user: &User) -> Self {
+        Self {
+            user_articles_read: user.user_articles_read,
+            user_last_active_at: user.user_last_active_at.map(|dt| dt.fixed_offset()),
+        }
+    }
+}
+
+impl LocalizeTimestamps for UserReadingHistoryDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.user_last_active_at.localize(tz);
+    }
+}
+
+impl UserDeviceDto {
+    pub fn from_model(session: UserSession) -> Self {
+        Self {
+            device_id: session.session_device_id,
+            created_at: session.session_created_at.map(|dt| dt.fixed_offset()),
+            last_used_at: session.session_last_used_at.map(|dt| dt.fixed_offset()),
+            revoked_at: session.session_revoked_at.map(|dt| dt.fixed_offset()),
+        }
+    }
+}
+
+impl LocalizeTimestamps for UserDeviceDto {
+    fn localize(&mut self, tz: &Tz) {
+        self.created_at.localize(tz);
+        self.last_used_at.localize(tz);
+        self.revoked_at.localize(tz);
+    }
+}
+
+fn example_user_email() -> &'static str {
+    "hong11@gil.com"
+}
+
+fn example_user_display_name() -> &'static str {
+    "홍길동"
+}
+
+fn example_user_social_login_provider() -> &'static str {
+    "google"
+}
+
+fn example_user_articles_read() -> i32 {
+    42
+}
@@ -0,0 +1,287 @@
+use okapi::openapi3::OpenApi;
+use rocket::{serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AdminUser,
+    config::webdriver::DriverPool,
+    dto::{
+        admin::{
+            request::{
+                AdminDeleteChannelRequestDto, AdminDeleteItemRequestDto,
+                AdminRebuildEmbeddingRequestDto, AdminUserSearchRequestDto,
+                AdminUserStatusRequestDto,
+            },
+            response::{
+                AdminUserResponseDto, DriverPoolStatsResponseDto, EmbeddingQueueStatsResponseDto,
+                ReembeddingJobResponseDto,
+            },
+        },
+        feedback::{
+            request::{FeedbackResolveRequestDto, FeedbackSearchRequestDto, FeedbackTagRequestDto},
+            response::FeedbackResponseDto,
+        },
+    },
+    model::error::OmniNewsError,
+    service::{
+        admin_service, channel_service, feedback_service, item_service,
+        reembedding_job_service::ReembeddingJobManager,
+    },
+    utils::{date_util::LocalizeTimestamps, embedding_util::EmbeddingService},
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings:
+        find_users,
+        update_user_status,
+        delete_channel,
+        delete_item,
+        rebuild_embeddings,
+        driver_pool_stats,
+        embedding_queue_stats,
+        start_reembedding,
+        pause_reembedding,
+        reembedding_status,
+        find_feedbacks,
+        tag_feedback,
+        resolve_feedback
+    ]
+}
+
+/// # 사용자 목록 조회 API
+///
+/// 관리자 전용. 사용자 목록을 최신 가입 순으로 반환합니다.
+///
+/// ### `search_value`: 이메일 또는 이름 검색어 (선택)
+///
+/// ### `page`: 페이지 번호 (1부터, 기본값 1)
+///
+/// ### `page_size`: 페이지 크기 (기본값 20, 최대 100)
+///
+#[openapi(tag = "관리자 API")]
+#[get("/admin/users?<request..>")]
+pub async fn find_users(
+    pool: &State<MySqlPool>,
+    request: AdminUserSearchRequestDto,
+    admin: AdminUser,
+) -> Result<Json<Vec<AdminUserResponseDto>>, OmniNewsError> {
+    admin_service::find_users(pool, request)
+        .await
+        .map(|dto| Json(dto.localized(&admin.timezone)))
+}
+
+/// # 사용자 상태 변경 API
+///
+/// 관리자 전용. 사용자를 정지하거나 다시 활성화합니다. 정지된 사용자의 세션은 모두 폐기됩니다.
+///
+/// ### `user_id`: 사용자 ID
+///
+/// ### `user_status`: "suspended" 또는 "active"
+///
+#[openapi(tag = "관리자 API")]
+#[put("/admin/user/status", data = "<request>")]
+pub async fn update_user_status(
+    pool: &State<MySqlPool>,
+    request: Json<AdminUserStatusRequestDto>,
+    _admin: AdminUser,
+) -> Result<Json<i32>, OmniNewsError> {
+    admin_service::update_user_status(pool, request.into_inner())
+        .await
+        .map(Json)
+}
+
+/// # 채널 삭제 API
+///
+/// 관리자 전용. 채널과 채널의 아이템, 임베딩, 구독 정보를 함께 삭제합니다.
+///
+/// ### `channel_id`: 삭제할 채널 ID
+///
+#[openapi(tag = "관리자 API")]
+#[delete("/admin/channel", data = "<request>")]
+pub async fn delete_channel(
+    pool: &State<MySqlPool>,
+    request: Json<AdminDeleteChannelRequestDto>,
+    _admin: AdminUser,
+) -> Result<&str, OmniNewsError> {
+    channel_service::delete_rss_channel(pool, request.channel_id.unwrap_or_default()).await?;
+    Ok("Success")
+}
+
+/// # 아이템 삭제 API
+///
+/// 관리자 전용. 아이템과 아이템의 임베딩을 삭제합니다.
+///
+/// ### `rss_id`: 삭제할 아이템 ID
+///
+#[openapi(tag = "관리자 API")]
+#[delete("/admin/item", data = "<request>")]
+pub async fn delete_item(
+    pool: &State<MySqlPool>,
+    request: Json<AdminDeleteItemRequestDto>,
+    _admin: AdminUser,
+) -> Result<&str, OmniNewsError> {
+    item_service::delete_rss_item(pool, request.rss_id.unwrap_or_default()).await?;
+    Ok("Success")
+}
+
+/// # 임베딩 재생성 API
+///
+/// 관리자 전용. 채널과 아이템의 임베딩을 백그라운드에서 다시 계산합니다.
+/// 대상 채널 수를 바로 반환합니다.
+///
+/// ### `channel_id`: 대상 채널 ID (없으면 전체 채널)
+///
+#[openapi(tag = "관리자 API")]
+#[post("/admin/embedding/rebuild", data = "<request>")]
+pub async fn rebuild_embeddings(
+    pool: &State<MySqlPool>,
+    embedding_service: &State<EmbeddingService>,
+    request: Json<AdminRebuildEmbeddingRequestDto>,
+    _admin: AdminUser,
+) -> Result<Json<usize>, OmniNewsError> {
+    admin_service::rebuild_embeddings(pool, embedding_service, request.channel_id)
+        .await
+        .map(Json)
+}
+
+/// # WebDriver Pool 상태 API
+///
+/// 관리자 전용. 대기 중인 드라이버 수와 생성된 드라이버 수를 반환합니다.
+///
+#[openapi(tag = "관리자 API")]
+#[get("/admin/driver-pool")]
+pub async fn driver_pool_stats(
+    driver_pool: &State<DriverPool>,
+    _admin: AdminUser,
+) -> Json<DriverPoolStatsResponseDto> {
+    Json(admin_service::driver_pool_stats(driver_pool).await)
+}
+
+/// # 임베딩 큐 상태 API
+///
+/// 관리자 전용. 대기 중인 문장 수와 큐 설정을 반환합니다.
+///
+/// 대기 문장 수가 용량에 닿으면 임베딩이 필요한 요청은 503(EMBEDDING_BUSY)으로 거절됩니다.
+///
+#[openapi(tag = "관리자 API")]
+#[get("/admin/embedding-queue")]
+pub async fn embedding_queue_stats(
+    embedding_service: &State<EmbeddingService>,
+    _admin: AdminUser,
+) -> Json<EmbeddingQueueStatsResponseDto> {
+    Json(admin_service::embedding_queue_stats(embedding_service))
+}
+
+/// # 재임베딩 시작 API
+///
+/// 관리자 전용. 모든 채널과 아이템을 서버의 현재 임베딩 모델과 문장 형식으로 백그라운드에서 다시 임베딩합니다.
+///
+/// 같은 버전의 일시 정지되었거나 실패한 작업이 있으면 멈춘 곳부터 이어서 진행합니다.
+/// 이미 실행 중이면 실행 중인 작업을 반환합니다.
+///
+/// 작업이 끝나면 검색 인덱스를 새 임베딩으로 다시 만듭니다. 그 전까지 검색은 기존 인덱스를 사용합니다.
+///
+#[openapi(tag = "관리자 API")]
+#[post("/admin/embedding/reembed")]
+pub async fn start_reembedding(
+    reembedding_job_manager: &State<ReembeddingJobManager>,
+    admin: AdminUser,
+) -> Result<Json<ReembeddingJobResponseDto>, OmniNewsError> {
+    reembedding_job_manager
+        .start()
+        .await
+        .map(|dto| Json(dto.localized(&admin.timezone)))
+}
+
+/// # 재임베딩 일시 정지 API
+///
+/// 관리자 전용. 실행 중인 재임베딩 작업을 일시 정지합니다. 처리 중인 배치가 끝나면 멈춥니다.
+///
+#[openapi(tag = "관리자 API")]
+#[post("/admin/embedding/reembed/pause")]
+pub async fn pause_reembedding(
+    reembedding_job_manager: &State<ReembeddingJobManager>,
+    admin: AdminUser,
+) -> Result<Json<ReembeddingJobResponseDto>, OmniNewsError> {
+    reembedding_job_manager
+        .pause()
+        .await
+        .map(|dto| Json(dto.localized(&admin.timezone)))
+}
+
+/// # 재임베딩 진행 상황 API
+///
+/// 관리자 전용. 가장 최근 재임베딩 작업의 상태와 진행률을 반환합니다.
+///
+#[openapi(tag = "관리자 API")]
+#[get("/admin/embedding/reembed")]
+pub async fn reembedding_status(
+    reembedding_job_manager: &State<ReembeddingJobManager>,
+    admin: AdminUser,
+) -> Result<Json<ReembeddingJobResponseDto>, OmniNewsError> {
+    reembedding_job_manager
+        .status()
+        .await
+        .map(|dto| Json(dto.localized(&admin.timezone)))
+}
+
+/// # 피드백 조회 API
+///
+/// 관리자 전용. 사용자가 남긴 피드백을 최신 순으로 반환합니다.
+///
+/// ### `feedback_status`: "open" 또는 "resolved" (없으면 전체)
+///
+/// ### `page`: 페이지 번호 (1부터, 기본값 1)
+///
+/// ### `page_size`: 페이지 크기 (기본값 20, 최대 100)
+///
+#[openapi(tag = "관리자 API")]
+#[get("/admin/feedback?<request..>")]
+pub async fn find_feedbacks(
+    pool: &State<MySqlPool>,
+    request: FeedbackSearchRequestDto,
+    admin: AdminUser,
+) -> Result<Json<Vec<FeedbackResponseDto>>, OmniNewsError> {
+    feedback_service::find_feedbacks(pool, request)
+        .await
+        .map(|dto| Json(dto.localized(&admin.timezone)))
+}
+
+/// # 피드백 태그 API
+///
+/// 관리자 전용. 피드백의 태그를 요청한 목록으로 교체합니다.
+///
+/// ### `feedback_id`: 피드백 ID
+///
+/// ### `feedback_tags`: 태그 목록 (예: ["bug", "notification"])
+///
+#[openapi(tag = "관리자 API")]
+#[put("/admin/feedback/tags", data = "<request>")]
+pub async fn tag_feedback(
+    pool: &State<MySqlPool>,
+    request: Json<FeedbackTagRequestDto>,
+    _admin: AdminUser,
+) -> Result<Json<i32>, OmniNewsError> {
+    feedback_service::tag_feedback(pool, request.into_inner())
+        .await
+        .map(Json)
+}
+
+/// # 피드백 해결 API
+///
+/// 관리자 전용. 피드백을 해결(resolved) 상태로 변경합니다.
+///
+/// ### `feedback_id`: 피드백 ID
+///
+#[openapi(tag = "관리자 API")]
+#[put("/admin/feedback/resolve", data = "<request>")]
+pub async fn resolve_feedback(
+    pool: &State<MySqlPool>,
+    request: Json<FeedbackResolveRequestDto>,
+    _admin: AdminUser,
+) -> Result<Json<i32>, OmniNewsError> {
+    feedback_service::resolve_feedback(pool, request.into_inner())
+        .await
+        .map(Json)
+}
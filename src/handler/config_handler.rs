@@ -1,9 +1,21 @@
 use rocket::http::Status;
 use std::path::PathBuf;
 
+use super::error_handler::MyError;
+
 // catch-all handler
 #[options("/<path..>")]
 pub fn options_handler(path: PathBuf) -> Status {
     info!("path: {:?}", path);
     Status::NoContent
 }
+
+// RateLimiter가 제한된 요청을 이 경로로 보낸다. Retry-After 헤더는 RateLimiter에서 추가.
+#[get("/rate-limited")]
+pub fn rate_limited_handler() -> MyError {
+    MyError::new(
+        "RATE_LIMITED",
+        Status::TooManyRequests,
+        "요청이 너무 많습니다. 잠시 후 다시 시도해주세요.",
+    )
+}
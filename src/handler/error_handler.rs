@@ -1,3 +1,4 @@
+use rocket::http::Status;
 use rocket::{catch, catchers, response, response::Responder, Response};
 use rocket::{Catcher, Request};
 use rocket_okapi::gen::OpenApiGenerator;
@@ -7,11 +8,16 @@ use rocket_okapi::okapi::schemars;
 use rocket_okapi::response::OpenApiResponderInner;
 use rocket_okapi::OpenApiError;
 
+use crate::model::error::OmniNewsError;
+use crate::server_error;
+
 // ----- Catchers -------
 
 /// Error messages returned to user
 #[derive(Debug, serde::Serialize, schemars::JsonSchema)]
 pub struct MyError {
+    /// Machine-readable error code (e.g. "NOT_FOUND", "TOKEN_INVALID")
+    pub code: String,
     /// The title of the error message
     pub err: String,
     /// The description of the error
@@ -21,50 +27,97 @@ pub struct MyError {
     pub http_status_code: u16,
 }
 
+impl MyError {
+    pub fn new(code: &str, status: Status, msg: &str) -> Self {
+        MyError {
+            code: code.to_owned(),
+            err: status.reason_lossy().to_owned(),
+            msg: Some(msg.to_owned()),
+            http_status_code: status.code,
+        }
+    }
+}
+
 #[catch(400)]
 fn bad_request() -> MyError {
-    MyError {
-        err: "Badsadfsd Request".to_owned(),
-        msg: Some("The request given is wrongly formatted or data was missing.".to_owned()),
-        http_status_code: 400,
-    }
+    MyError::new(
+        "BAD_REQUEST",
+        Status::BadRequest,
+        "The request given is wrongly formatted or data was missing.",
+    )
 }
 
 #[catch(401)]
 fn unauthorized() -> MyError {
-    MyError {
-        err: "Unauthorized".to_owned(),
-        msg: Some("The authentication given was incorrect or insufficient.".to_owned()),
-        http_status_code: 401,
-    }
+    MyError::new(
+        "UNAUTHORIZED",
+        Status::Unauthorized,
+        "The authentication given was incorrect or insufficient.",
+    )
+}
+
+#[catch(403)]
+fn forbidden() -> MyError {
+    MyError::new(
+        "FORBIDDEN",
+        Status::Forbidden,
+        "You do not have permission to access this resource.",
+    )
 }
 
 #[catch(404)]
 fn not_found() -> MyError {
-    MyError {
-        err: "Not Found".to_owned(),
-        msg: Some("The requested resource was not found.".to_owned()),
-        http_status_code: 404,
-    }
+    MyError::new(
+        "NOT_FOUND",
+        Status::NotFound,
+        "The requested resource was not found.",
+    )
+}
+
+#[catch(422)]
+fn unprocessable_entity() -> MyError {
+    MyError::new(
+        "UNPROCESSABLE_ENTITY",
+        Status::UnprocessableEntity,
+        "The request body could not be parsed.",
+    )
+}
+
+#[catch(429)]
+fn too_many_requests() -> MyError {
+    MyError::new(
+        "RATE_LIMITED",
+        Status::TooManyRequests,
+        "Too many requests. Please retry after the time given in the Retry-After header.",
+    )
 }
 
 #[catch(500)]
 fn internal_error() -> MyError {
-    MyError {
-        err: "Internal Server Error".to_owned(),
-        msg: Some("An unexpected error occurred on the server.".to_owned()),
-        http_status_code: 500,
-    }
+    MyError::new(
+        "INTERNAL_SERVER_ERROR",
+        Status::InternalServerError,
+        "An unexpected error occurred on the server.",
+    )
 }
 
-pub fn bad_request_response(gen: &mut OpenApiGenerator) -> okapi::openapi3::Response {
+#[catch(503)]
+fn service_unavailable() -> MyError {
+    MyError::new(
+        "SERVICE_UNAVAILABLE",
+        Status::ServiceUnavailable,
+        "The server is temporarily busy. Please try again later.",
+    )
+}
+
+fn error_response(
+    gen: &mut OpenApiGenerator,
+    status: Status,
+    description: &str,
+) -> okapi::openapi3::Response {
     let schema = gen.json_schema::<MyError>();
     okapi::openapi3::Response {
-        description: "\
-        # 400 Bad Request\n\
-        The request given is wrongly formatted or data was missing. \
-        "
-        .to_owned(),
+        description: format!("# {}\n{}", status, description),
         content: okapi::map! {
             "application/json".to_owned() => MediaType {
                 schema: Some(schema),
@@ -75,58 +128,44 @@ pub fn bad_request_response(gen: &mut OpenApiGenerator) -> okapi::openapi3::Resp
     }
 }
 
+pub fn bad_request_response(gen: &mut OpenApiGenerator) -> okapi::openapi3::Response {
+    error_response(
+        gen,
+        Status::BadRequest,
+        "The request given is wrongly formatted or data was missing.",
+    )
+}
+
 pub fn unauthorized_response(gen: &mut OpenApiGenerator) -> okapi::openapi3::Response {
-    let schema = gen.json_schema::<MyError>();
-    okapi::openapi3::Response {
-        description: "\
-        # 401 Unauthorized\n\
-        The authentication given was incorrect or insufficient. \
-        "
-        .to_owned(),
-        content: okapi::map! {
-            "application/json".to_owned() => MediaType {
-                schema: Some(schema),
-                ..Default::default()
-            }
-        },
-        ..Default::default()
-    }
+    error_response(
+        gen,
+        Status::Unauthorized,
+        "The authentication given was incorrect or insufficient.",
+    )
+}
+
+pub fn forbidden_response(gen: &mut OpenApiGenerator) -> okapi::openapi3::Response {
+    error_response(
+        gen,
+        Status::Forbidden,
+        "You do not have permission to access this resource.",
+    )
 }
 
 pub fn not_found_response(gen: &mut OpenApiGenerator) -> okapi::openapi3::Response {
-    let schema = gen.json_schema::<MyError>();
-    okapi::openapi3::Response {
-        description: "\
-        # 404 Not Found\n\
-        The requested resource was not found. \
-        "
-        .to_owned(),
-        content: okapi::map! {
-            "application/json".to_owned() => MediaType {
-                schema: Some(schema),
-                ..Default::default()
-            }
-        },
-        ..Default::default()
-    }
+    error_response(
+        gen,
+        Status::NotFound,
+        "The requested resource was not found.",
+    )
 }
 
 pub fn internal_error_response(gen: &mut OpenApiGenerator) -> okapi::openapi3::Response {
-    let schema = gen.json_schema::<MyError>();
-    okapi::openapi3::Response {
-        description: "\
-        # 500 Internal Server Error\n\
-        An unexpected error occurred on the server. \
-        "
-        .to_owned(),
-        content: okapi::map! {
-            "application/json".to_owned() => MediaType {
-                schema: Some(schema),
-                ..Default::default()
-            }
-        },
-        ..Default::default()
-    }
+    error_response(
+        gen,
+        Status::InternalServerError,
+        "An unexpected error occurred on the server.",
+    )
 }
 
 impl<'r> Responder<'r, 'static> for MyError {
@@ -136,7 +175,7 @@ impl<'r> Responder<'r, 'static> for MyError {
         Response::build()
             .sized_body(body.len(), std::io::Cursor::new(body))
             .header(rocket::http::ContentType::JSON)
-            .status(rocket::http::Status::new(self.http_status_code))
+            .status(Status::new(self.http_status_code))
             .ok()
     }
 }
@@ -155,6 +194,79 @@ impl OpenApiResponderInner for MyError {
         })
     }
 }
+/// 서비스에서 올라온 에러를 `MyError`와 같은 JSON 형태로 응답한다.
+///
+/// 5xx 에러는 내부 정보가 노출되지 않도록 상세 메시지 대신 기본 문구를 내려준다.
+impl<'r> Responder<'r, 'static> for OmniNewsError {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        let status = self.status();
+        let msg = if status.code >= 500 {
+            server_error!("[Response] {} {} failed: {}", req.method(), req.uri(), self);
+            "An unexpected error occurred on the server.".to_owned()
+        } else {
+            self.to_string()
+        };
+
+        MyError {
+            code: self.code().to_owned(),
+            err: status.reason_lossy().to_owned(),
+            msg: Some(msg),
+            http_status_code: status.code,
+        }
+        .respond_to(req)
+    }
+}
+
+impl OpenApiResponderInner for OmniNewsError {
+    fn responses(gen: &mut OpenApiGenerator) -> Result<Responses, OpenApiError> {
+        use okapi::openapi3::RefOr;
+        Ok(Responses {
+            responses: okapi::map! {
+                "400".to_owned() => RefOr::Object(bad_request_response(gen)),
+                "401".to_owned() => RefOr::Object(unauthorized_response(gen)),
+                "403".to_owned() => RefOr::Object(forbidden_response(gen)),
+                "404".to_owned() => RefOr::Object(not_found_response(gen)),
+                "409".to_owned() => RefOr::Object(error_response(
+                    gen,
+                    Status::Conflict,
+                    "The resource already exists, or the account is linked to another login provider.",
+                )),
+                "422".to_owned() => RefOr::Object(error_response(
+                    gen,
+                    Status::UnprocessableEntity,
+                    "The request body, the given feed or the given image could not be parsed.",
+                )),
+                "429".to_owned() => RefOr::Object(error_response(
+                    gen,
+                    Status::TooManyRequests,
+                    "Too many requests. Please retry after the time given in the Retry-After header.",
+                )),
+                "500".to_owned() => RefOr::Object(internal_error_response(gen)),
+                "502".to_owned() => RefOr::Object(error_response(
+                    gen,
+                    Status::BadGateway,
+                    "Fetching from an upstream service failed.",
+                )),
+                "503".to_owned() => RefOr::Object(error_response(
+                    gen,
+                    Status::ServiceUnavailable,
+                    "The server is temporarily busy. Please try again later.",
+                )),
+            },
+            ..Default::default()
+        })
+    }
+}
+
 pub fn error_catchers() -> Vec<Catcher> {
-    catchers![bad_request, unauthorized, not_found, internal_error]
+    catchers![
+        bad_request,
+        unauthorized,
+        forbidden,
+        not_found,
+        unprocessable_entity,
+        too_many_requests,
+        internal_error,
+        service_unavailable
+    ]
 }
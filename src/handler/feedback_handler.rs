@@ -0,0 +1,35 @@
+use okapi::openapi3::OpenApi;
+use rocket::{serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthenticatedUser, dto::feedback::request::CreateFeedbackRequestDto,
+    model::error::OmniNewsError, service::feedback_service,
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings: create_feedback]
+}
+
+/// # 피드백 등록 API
+///
+/// 사용자의 피드백을 등록합니다. 이메일은 로그인한 사용자 정보로 채워집니다.
+///
+/// ### `feedback_content`: 피드백 내용 (최대 2000자)
+///
+/// ### `app_version`: 앱 버전 (선택)
+///
+/// ### `device_info`: 기기 정보 (선택)
+///
+#[openapi(tag = "피드백 API")]
+#[post("/feedback", data = "<feedback>")]
+pub async fn create_feedback(
+    pool: &State<MySqlPool>,
+    feedback: Json<CreateFeedbackRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<i32>, OmniNewsError> {
+    feedback_service::create_feedback(pool, user.user_email, feedback.into_inner())
+        .await
+        .map(Json)
+}
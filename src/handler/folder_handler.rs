@@ -7,12 +7,16 @@ use crate::{
     auth_middleware::AuthenticatedUser,
     dto::folder::{
         request::{
-            ChannelFolderRequestDto, CreateFolderRequestDto, DeleteFolderRequestDto,
-            UpdateFolderRequestDto,
+            ChannelFolderRequestDto, CreateFolderRequestDto, CreateSmartFolderRequestDto,
+            DeleteFolderRequestDto, DeleteSmartFolderRequestDto, FolderChannelOrderRequestDto,
+            FolderItemsRequestDto, FolderOrderRequestDto, UpdateFolderRequestDto,
+            UpdateSmartFolderRequestDto,
         },
-        response::RssFolderResponseDto,
+        response::{FolderItemsResponseDto, RssFolderResponseDto},
     },
-    service::folder_service,
+    model::error::OmniNewsError,
+    service::{folder_service, smart_folder_service},
+    utils::{date_util::LocalizeTimestamps, embedding_util::EmbeddingService},
 };
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
@@ -22,7 +26,14 @@ pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, O
         find_folders,
         update_folder,
         delete_folder,
-        delete_channel_from_folder
+        delete_channel_from_folder,
+        find_folder_items,
+        reorder_folders,
+        reorder_channels_in_folder,
+        create_smart_folder,
+        update_smart_folder,
+        delete_smart_folder,
+        find_smart_folder_items
     ]
 }
 
@@ -30,7 +41,9 @@ pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, O
 ///
 /// 사용자가 사용하는 새 폴더를 생성합니다.
 ///
-/// ### `folder_name`: 폴더 이름 (예: "Development")
+/// ### `folder_name`: 폴더 이름 (예: "Development", 필수)
+///
+/// ### `parent_folder_id`: 상위 폴더 ID (선택, 한 단계까지만 중첩 가능)
 ///
 #[openapi(tag = "Folder")]
 #[post("/folder", data = "<folder>")]
@@ -38,16 +51,15 @@ pub async fn create_folder(
     pool: &State<MySqlPool>,
     folder: Json<CreateFolderRequestDto>,
     user: AuthenticatedUser,
-) -> Result<Json<i32>, Status> {
-    match folder_service::create_folder(pool, user.user_email, folder.into_inner()).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<i32>, OmniNewsError> {
+    folder_service::create_folder(pool, user.user_email, folder.into_inner())
+        .await
+        .map(Json)
 }
 
 /// # 폴더에 채널 추가 API
 ///
-/// 폴더에 채널을 추가합니다.
+/// 폴더에 채널을 추가합니다. 구독 중인 채널만 추가할 수 있고, 같은 채널은 한 번만 추가됩니다.
 ///
 /// ### `folder_id`: 폴더 ID (예: 3)
 ///
@@ -58,54 +70,57 @@ pub async fn create_folder(
 pub async fn add_channel_to_folder(
     pool: &State<MySqlPool>,
     channel_folder_id: Json<ChannelFolderRequestDto>,
-    _auth: AuthenticatedUser,
-) -> Result<Status, Status> {
-    match folder_service::add_channel_to_folder(pool, channel_folder_id.into_inner()).await {
-        Ok(_) => Ok(Status::Created),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    user: AuthenticatedUser,
+) -> Result<Status, OmniNewsError> {
+    folder_service::add_channel_to_folder(pool, user.user_email, channel_folder_id.into_inner())
+        .await?;
+    Ok(Status::Created)
 }
 
 /// # 폴더 조회 API
 ///
-/// 사용자의 폴더 목록을 반환합니다.
+/// 사용자의 폴더 목록을 정렬 순서대로 반환합니다. 하위 폴더는 `parent_folder_id`로 구분합니다.
+///
+/// 스마트 폴더는 일반 폴더 뒤에 `folder_type: "smart"`로 포함되며, `folder_id`는 스마트 폴더 ID입니다.
 ///
 #[openapi(tag = "Folder")]
 #[get("/folder")]
 pub async fn find_folders(
     pool: &State<MySqlPool>,
     user: AuthenticatedUser,
-) -> Result<Json<Vec<RssFolderResponseDto>>, Status> {
-    match folder_service::fetch_folders(pool, user.user_email).await {
-        Ok(folders) => Ok(Json(folders)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<Vec<RssFolderResponseDto>>, OmniNewsError> {
+    folder_service::fetch_folders(pool, user.user_email)
+        .await
+        .map(Json)
 }
 
 /// # 폴더 업데이트 API
 ///
-/// 폴더의 이름을 업데이트합니다.
+/// 폴더의 이름과 상위 폴더를 업데이트합니다.
 ///
 /// ### `folder_id`: 수정할 폴더 ID (예: 3)
 ///
-/// ### `folder_name`: 새 폴더 이름 (예: "Development")
+/// ### `folder_name`: 새 폴더 이름 (예: "Development", 없으면 이름을 바꾸지 않음)
+///
+/// ### `parent_folder_id`: 옮길 상위 폴더 ID (없으면 상위 폴더를 바꾸지 않음)
+///
+/// ### `move_to_top_level`: true면 최상위 폴더로 이동 (`parent_folder_id`와 함께 사용할 수 없음)
 ///
 #[openapi(tag = "Folder")]
 #[put("/folder", data = "<folder>")]
 pub async fn update_folder(
     pool: &State<MySqlPool>,
     folder: Json<UpdateFolderRequestDto>,
-    _auth: AuthenticatedUser,
-) -> Result<Json<i32>, Status> {
-    match folder_service::update_folder(pool, folder.into_inner()).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    user: AuthenticatedUser,
+) -> Result<Json<i32>, OmniNewsError> {
+    folder_service::update_folder(pool, user.user_email, folder.into_inner())
+        .await
+        .map(Json)
 }
 
 /// # 폴더 삭제 API
 ///
-/// 폴더를 삭제합니다.
+/// 폴더를 삭제합니다. 하위 폴더는 최상위 폴더로 이동합니다.
 ///
 /// ### `folder_id`: 삭제할 폴더 ID (예: 3)
 ///
@@ -114,12 +129,10 @@ pub async fn update_folder(
 pub async fn delete_folder(
     pool: &State<MySqlPool>,
     folder_id: Json<DeleteFolderRequestDto>,
-    _auth: AuthenticatedUser,
-) -> Result<&str, Status> {
-    match folder_service::delete_folder(pool, folder_id.into_inner()).await {
-        Ok(_) => Ok("Success"),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    user: AuthenticatedUser,
+) -> Result<&str, OmniNewsError> {
+    folder_service::delete_folder(pool, user.user_email, folder_id.into_inner()).await?;
+    Ok("Success")
 }
 
 /// # 폴더에서 채널 삭제 API
@@ -135,12 +148,165 @@ pub async fn delete_folder(
 pub async fn delete_channel_from_folder(
     pool: &State<MySqlPool>,
     channel_folder_id: Json<ChannelFolderRequestDto>,
-    _auth: AuthenticatedUser,
-) -> Result<&str, Status> {
-    match folder_service::delete_channel_from_folder(pool, channel_folder_id.into_inner()).await {
-        Ok(_) => Ok("Success"),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    user: AuthenticatedUser,
+) -> Result<&str, OmniNewsError> {
+    folder_service::delete_channel_from_folder(
+        pool,
+        user.user_email,
+        channel_folder_id.into_inner(),
+    )
+    .await?;
+    Ok("Success")
+}
+
+/// # 폴더 아이템 조회 API
+///
+/// 폴더와 하위 폴더에 담긴 채널의 아이템을 최신 순으로 반환합니다.
+///
+/// ### `folder_id`: 폴더 ID (예: 3)
+///
+/// ### `cursor`: 이전 응답의 `next_cursor` (첫 페이지는 생략)
+///
+/// ### `limit`: 페이지 크기 (기본값 20, 최대 100)
+///
+#[openapi(tag = "Folder")]
+#[get("/folder/<folder_id>/items?<request..>")]
+pub async fn find_folder_items(
+    pool: &State<MySqlPool>,
+    folder_id: i32,
+    request: FolderItemsRequestDto,
+    user: AuthenticatedUser,
+) -> Result<Json<FolderItemsResponseDto>, OmniNewsError> {
+    folder_service::fetch_folder_items(pool, user.user_email, folder_id, request)
+        .await
+        .map(|dto| Json(dto.localized(&user.timezone)))
 }
 
-// // TODO casecade설정ㅎㅏ기
+/// # 폴더 순서 변경 API
+///
+/// 요청한 순서대로 폴더 순서를 저장합니다.
+///
+/// ### `folder_ids`: 정렬된 폴더 ID 목록 (예: [2, 1, 3])
+///
+#[openapi(tag = "Folder")]
+#[put("/folder/order", data = "<request>")]
+pub async fn reorder_folders(
+    pool: &State<MySqlPool>,
+    request: Json<FolderOrderRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<&str, OmniNewsError> {
+    folder_service::reorder_folders(pool, user.user_email, request.into_inner()).await?;
+    Ok("Success")
+}
+
+/// # 폴더 내 채널 순서 변경 API
+///
+/// 요청한 순서대로 폴더 안의 채널 순서를 저장합니다.
+///
+/// ### `folder_id`: 폴더 ID (예: 3)
+///
+/// ### `channel_ids`: 정렬된 채널 ID 목록 (예: [3, 7, 5])
+///
+#[openapi(tag = "Folder")]
+#[put("/folder/channel/order", data = "<request>")]
+pub async fn reorder_channels_in_folder(
+    pool: &State<MySqlPool>,
+    request: Json<FolderChannelOrderRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<&str, OmniNewsError> {
+    folder_service::reorder_channels_in_folder(pool, user.user_email, request.into_inner()).await?;
+    Ok("Success")
+}
+
+/// # 스마트 폴더 생성 API
+///
+/// 저장된 검색 조건으로 아이템을 모아 보여주는 폴더를 생성합니다. 조건은 하나 이상 필요합니다.
+///
+/// ### `smart_folder_name`: 폴더 이름 (예: "AI 뉴스")
+///
+/// ### `smart_folder_query`: 의미 검색어 (선택, 검색어와 가장 가까운 아이템 최대 2000개 안에서 나머지 조건을 적용)
+///
+/// ### `keywords`: 제목이나 설명에 포함될 키워드 목록, 하나만 포함되어도 됩니다. (선택)
+///
+/// ### `channel_ids`: 대상 채널 ID 목록 (선택)
+///
+/// ### `language`: 채널 언어 (예: "ko", 선택)
+///
+/// ### `days`: 최근 N일 이내 아이템만 (선택, 최대 365)
+///
+#[openapi(tag = "Folder")]
+#[post("/smart-folder", data = "<folder>")]
+pub async fn create_smart_folder(
+    pool: &State<MySqlPool>,
+    folder: Json<CreateSmartFolderRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<i32>, OmniNewsError> {
+    smart_folder_service::create_smart_folder(pool, user.user_email, folder.into_inner())
+        .await
+        .map(Json)
+}
+
+/// # 스마트 폴더 수정 API
+///
+/// 스마트 폴더의 이름과 검색 조건을 요청한 값으로 교체합니다.
+///
+/// ### `smart_folder_id`: 스마트 폴더 ID (예: 4)
+///
+#[openapi(tag = "Folder")]
+#[put("/smart-folder", data = "<folder>")]
+pub async fn update_smart_folder(
+    pool: &State<MySqlPool>,
+    folder: Json<UpdateSmartFolderRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<i32>, OmniNewsError> {
+    smart_folder_service::update_smart_folder(pool, user.user_email, folder.into_inner())
+        .await
+        .map(Json)
+}
+
+/// # 스마트 폴더 삭제 API
+///
+/// ### `smart_folder_id`: 삭제할 스마트 폴더 ID (예: 4)
+///
+#[openapi(tag = "Folder")]
+#[delete("/smart-folder", data = "<folder>")]
+pub async fn delete_smart_folder(
+    pool: &State<MySqlPool>,
+    folder: Json<DeleteSmartFolderRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<&str, OmniNewsError> {
+    smart_folder_service::delete_smart_folder(pool, user.user_email, folder.into_inner()).await?;
+    Ok("Success")
+}
+
+/// # 스마트 폴더 아이템 조회 API
+///
+/// 스마트 폴더의 검색 조건에 맞는 아이템을 최신 순으로 반환합니다.
+///
+/// 의미 검색어가 있으면 검색어와 가장 가까운 아이템 최대 2000개만 대상이 됩니다.
+///
+/// ### `smart_folder_id`: 스마트 폴더 ID (예: 4)
+///
+/// ### `cursor`: 이전 응답의 `next_cursor` (첫 페이지는 생략)
+///
+/// ### `limit`: 페이지 크기 (기본값 20, 최대 100)
+///
+#[openapi(tag = "Folder")]
+#[get("/smart-folder/<smart_folder_id>/items?<request..>")]
+pub async fn find_smart_folder_items(
+    pool: &State<MySqlPool>,
+    embedding_service: &State<EmbeddingService>,
+    smart_folder_id: i32,
+    request: FolderItemsRequestDto,
+    user: AuthenticatedUser,
+) -> Result<Json<FolderItemsResponseDto>, OmniNewsError> {
+    smart_folder_service::fetch_smart_folder_items(
+        pool,
+        embedding_service,
+        user.user_email,
+        smart_folder_id,
+        request,
+    )
+    .await
+    .map(|dto| Json(dto.localized(&user.timezone)))
+}
@@ -0,0 +1,62 @@
+use okapi::openapi3::{OpenApi, Responses};
+use rocket::{
+    http::{ContentType, Header},
+    Responder, State,
+};
+use rocket_okapi::{
+    gen::OpenApiGenerator, openapi, openapi_get_routes_spec, response::OpenApiResponderInner,
+    settings::OpenApiSettings, OpenApiError,
+};
+
+use crate::{
+    config::http_client::HttpClient,
+    model::error::OmniNewsError,
+    service::image_proxy_service::{self, ImageCache},
+};
+
+// 리사이즈 결과는 원본 주소마다 고정이므로 클라이언트에서 오래 캐시해도 된다.
+const IMAGE_CACHE_CONTROL: &str = "public, max-age=604800, immutable";
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings: get_proxied_image]
+}
+
+#[derive(Responder)]
+pub struct ImageResponse {
+    inner: (ContentType, Vec<u8>),
+    cache_control: Header<'static>,
+}
+
+impl OpenApiResponderInner for ImageResponse {
+    fn responses(gen: &mut OpenApiGenerator) -> Result<Responses, OpenApiError> {
+        <(ContentType, Vec<u8>)>::responses(gen)
+    }
+}
+
+/// # 이미지 프록시 API
+///
+/// 아이템, 채널 응답에 포함된 프록시 이미지 주소로 원본 이미지를 받아 줄여서 반환합니다.
+///
+/// 응답은 `Accept` 헤더와 관계없이 항상 JPEG입니다. (주소가 같으면 응답도 같아 CDN에서 그대로 캐시할 수 있음)
+///
+/// ### `signed_url` : 응답 DTO에 포함된 서명된 이미지 경로
+///
+/// ### `w`, `h` : 최대 가로/세로 픽셀 (선택, 원본보다 크게 늘리지 않음)
+///
+#[openapi(tag = "이미지 API")]
+#[get("/img/<signed_url>?<w>&<h>")]
+pub async fn get_proxied_image(
+    image_cache: &State<ImageCache>,
+    http_client: &State<HttpClient>,
+    signed_url: &str,
+    w: Option<u32>,
+    h: Option<u32>,
+) -> Result<ImageResponse, OmniNewsError> {
+    let image =
+        image_proxy_service::get_proxied_image(image_cache, http_client, signed_url, w, h).await?;
+
+    Ok(ImageResponse {
+        inner: (image.format.content_type(), image.bytes),
+        cache_control: Header::new("Cache-Control", IMAGE_CACHE_CONTROL),
+    })
+}
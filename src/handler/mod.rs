@@ -1,10 +1,14 @@
 use okapi::openapi3::OpenApi;
 use rocket_okapi::{get_nested_endpoints_and_docs, settings::OpenApiSettings};
 
+pub mod admin_handler;
 pub mod config_handler;
 pub mod error_handler;
+pub mod feedback_handler;
 pub mod folder_handler;
 pub mod health_handler;
+pub mod image_handler;
+pub mod mute_handler;
 pub mod news_handler;
 pub mod omninews_subscription_handler;
 pub mod premium;
@@ -23,6 +27,10 @@ pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, O
         "/" => folder_handler::get_routes_and_docs(settings),
         "/" => health_handler::get_routes_and_docs(settings),
         "/" => omninews_subscription_handler::get_routes_and_docs(settings),
+        "/" => feedback_handler::get_routes_and_docs(settings),
+        "/" => admin_handler::get_routes_and_docs(settings),
+        "/" => mute_handler::get_routes_and_docs(settings),
+        "/" => image_handler::get_routes_and_docs(settings),
 
         // premium
         "/" => premium::rss_generate::get_routes_and_docs(settings),
@@ -0,0 +1,76 @@
+use okapi::openapi3::OpenApi;
+use rocket::{serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthenticatedUser,
+    dto::mute::{
+        request::{CreateMuteRuleRequestDto, DeleteMuteRuleRequestDto},
+        response::MuteRuleResponseDto,
+    },
+    model::error::OmniNewsError,
+    service::mute_service,
+    utils::date_util::LocalizeTimestamps,
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings: create_mute_rule, find_mute_rules, delete_mute_rule]
+}
+
+/// # 뮤트 규칙 생성 API
+///
+/// 구독 타임라인, 검색, 추천 결과에서 숨길 규칙을 추가합니다.
+///
+/// ### `mute_rule_type`: "keyword", "regex", "author", "channel", "domain" 중 하나
+///
+/// - keyword, regex: 제목과 설명에 적용되며 대소문자를 구분하지 않습니다.
+/// - author: 작성자(rss_author)와 정확히 일치할 때 숨깁니다.
+/// - channel: 채널 ID
+/// - domain: 아이템 링크의 도메인 (하위 도메인 포함, 예: "example.com")
+///
+/// ### `mute_rule_value`: 규칙 값 (최대 200자)
+///
+/// ### `days`: N일 뒤 만료 (선택, 없으면 만료되지 않음)
+///
+#[openapi(tag = "뮤트 API")]
+#[post("/mute", data = "<rule>")]
+pub async fn create_mute_rule(
+    pool: &State<MySqlPool>,
+    rule: Json<CreateMuteRuleRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<i32>, OmniNewsError> {
+    mute_service::create_mute_rule(pool, user.user_email, rule.into_inner())
+        .await
+        .map(Json)
+}
+
+/// # 뮤트 규칙 조회 API
+///
+/// 만료되지 않은 뮤트 규칙을 최신 순으로 반환합니다.
+///
+#[openapi(tag = "뮤트 API")]
+#[get("/mute")]
+pub async fn find_mute_rules(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<MuteRuleResponseDto>>, OmniNewsError> {
+    mute_service::find_mute_rules(pool, user.user_email)
+        .await
+        .map(|dto| Json(dto.localized(&user.timezone)))
+}
+
+/// # 뮤트 규칙 삭제 API
+///
+/// ### `mute_rule_id`: 삭제할 규칙 ID
+///
+#[openapi(tag = "뮤트 API")]
+#[delete("/mute", data = "<rule>")]
+pub async fn delete_mute_rule(
+    pool: &State<MySqlPool>,
+    rule: Json<DeleteMuteRuleRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<&str, OmniNewsError> {
+    mute_service::delete_mute_rule(pool, user.user_email, rule.into_inner()).await?;
+    Ok("Success")
+}
@@ -1,10 +1,11 @@
 use okapi::openapi3::OpenApi;
-use rocket::{http::Status, serde::json::Json, State};
+use rocket::{serde::json::Json, State};
 use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
 use sqlx::MySqlPool;
 
 use crate::{
-    auth_middleware::AuthenticatedUser, dto::news::response::NewsResponseDto, service::news_service,
+    auth_middleware::AuthenticatedUser, dto::news::response::NewsResponseDto,
+    model::error::OmniNewsError, service::news_service, utils::date_util::LocalizeTimestamps,
 };
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
@@ -22,10 +23,9 @@ pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, O
 pub async fn get_news(
     pool: &State<MySqlPool>,
     category: String,
-    _auth: AuthenticatedUser,
-) -> Result<Json<Vec<NewsResponseDto>>, Status> {
-    match news_service::get_news(pool, category).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    auth: AuthenticatedUser,
+) -> Result<Json<Vec<NewsResponseDto>>, OmniNewsError> {
+    news_service::get_news(pool, category)
+        .await
+        .map(|dto| Json(dto.localized(&auth.timezone)))
 }
@@ -1,14 +1,17 @@
 use okapi::openapi3::OpenApi;
-use rocket::{http::Status, serde::json::Json, State};
+use rocket::{serde::json::Json, State};
 use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
 use sqlx::MySqlPool;
 
 use crate::{
     auth_middleware::AuthenticatedUser,
+    config::http_client::HttpClient,
     dto::omninews_subscription::{
         request::OmninewsReceiptRequestDto, response::OmninewsSubscriptionResponseDto,
     },
+    model::error::OmniNewsError,
     service::omninews_subscription_service,
+    utils::date_util::LocalizeTimestamps,
 };
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
@@ -25,11 +28,10 @@ pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, O
 async fn verify_subscription(
     pool: &State<MySqlPool>,
     auth: AuthenticatedUser,
-) -> Result<Json<OmninewsSubscriptionResponseDto>, Status> {
-    match omninews_subscription_service::verify_subscription(pool, &auth.user_email).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<OmninewsSubscriptionResponseDto>, OmniNewsError> {
+    omninews_subscription_service::verify_subscription(pool, &auth.user_email)
+        .await
+        .map(|dto| Json(dto.localized(&auth.timezone)))
 }
 
 #[openapi(tag = "OmniNews Subscription API")]
@@ -47,17 +49,14 @@ async fn register_subscription(
     pool: &State<MySqlPool>,
     subscription: Json<OmninewsReceiptRequestDto>,
     auth: AuthenticatedUser,
-) -> Result<Json<bool>, Status> {
-    match omninews_subscription_service::register_subscription(
+) -> Result<Json<bool>, OmniNewsError> {
+    omninews_subscription_service::register_subscription(
         pool,
         &auth.user_email,
         subscription.into_inner(),
     )
     .await
-    {
-        Ok(response) => Ok(Json(response)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    .map(Json)
 }
 
 #[openapi(tag = "OmniNews Subscription API")]
@@ -72,13 +71,15 @@ async fn register_subscription(
 /// ### `platform` : 구독 플랫폼 (ex. "ios", "android")
 /// ### `is_test` : 테스트 환경 여부 (ex. true, false)
 async fn validate_receipt(
+    http_client: &State<HttpClient>,
     receipt: Json<OmninewsReceiptRequestDto>,
     auth: AuthenticatedUser,
-) -> Result<Json<bool>, Status> {
-    match omninews_subscription_service::validate_receipt(&auth.user_email, receipt.into_inner())
-        .await
-    {
-        Ok(response) => Ok(Json(response)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<bool>, OmniNewsError> {
+    omninews_subscription_service::validate_receipt(
+        http_client,
+        &auth.user_email,
+        receipt.into_inner(),
+    )
+    .await
+    .map(Json)
 }
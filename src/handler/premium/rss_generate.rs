@@ -1,20 +1,20 @@
 use okapi::openapi3::OpenApi;
-use rocket::{http::Status, serde::json::Json, State};
+use rocket::{serde::json::Json, State};
 use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
-use sqlx::MySqlPool;
 
 use crate::{
-    config::webdriver::DriverPool,
+    auth_middleware::AuthenticatedUser,
     dto::premium::rss::{
         request::{RssGenerateByCssReqeustDto, RssGenerateRequestDto},
-        response::RssGenerateResponseDto,
+        response::RssGenerateJobResponseDto,
     },
-    service::premium::premium_rss_service,
-    utils::embedding_util::EmbeddingService,
+    model::{error::OmniNewsError, premium::rss_generate_job::RssGenerateJobKind},
+    service::premium::rss_generate_job_service::RssGenerateJobQueue,
+    utils::date_util::LocalizeTimestamps,
 };
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
-    openapi_get_routes_spec![settings: rss_generate, rss_generate_by_css]
+    openapi_get_routes_spec![settings: rss_generate, rss_generate_by_css, rss_generate_job]
 }
 
 #[openapi(tag = "Premium RSS Generation API")]
@@ -23,20 +23,20 @@ pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, O
 ///
 /// 사이트 종류와 링크를 입력받아 RSS 피드를 생성합니다.
 ///
+/// 생성은 작업 큐에서 처리되며, 응답으로 받은 `job_id`로 진행 상태를 조회합니다.
+/// 프리미엄 구독자가 아니면 403(FORBIDDEN), 끝나지 않은 작업이 너무 많으면 429(TOO_MANY_JOBS)를 반환합니다.
+///
 /// ### `channel_link`: Rss 피드를 생성할 사이트의 링크나 유저 명
 /// ### `kind`: 사이트 종류 (예: "Instagram", "Medium", "Naver" 등)
 pub async fn rss_generate(
-    pool: &State<MySqlPool>,
-    embedding_service: &State<EmbeddingService>,
-    driver_pool: &State<DriverPool>,
+    job_queue: &State<RssGenerateJobQueue>,
+    user: AuthenticatedUser,
     data: Json<RssGenerateRequestDto>,
-) -> Result<Json<RssGenerateResponseDto>, Status> {
-    match premium_rss_service::generate_rss(pool, embedding_service, driver_pool, data.into_inner())
+) -> Result<Json<RssGenerateJobResponseDto>, OmniNewsError> {
+    job_queue
+        .enqueue(user.user_email, RssGenerateJobKind::Site(data.into_inner()))
         .await
-    {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+        .map(|dto| Json(dto.localized(&user.timezone)))
 }
 
 #[openapi(tag = "Premium RSS Generation API")]
@@ -45,6 +45,8 @@ pub async fn rss_generate(
 ///
 /// 사이틀의 CSS 선택자를 제공받아 RSS 피드를 생성합니다.
 /// 채널의 정보는 사용자에게 직접 입력받고, item의 정보는 CSS 선택자를 통해 추출합니다.
+/// 생성은 작업 큐에서 처리되며, 응답으로 받은 `job_id`로 진행 상태를 조회합니다.
+/// 프리미엄 구독자가 아니면 403(FORBIDDEN), 끝나지 않은 작업이 너무 많으면 429(TOO_MANY_JOBS)를 반환합니다.
 ///
 /// ### `channel_link`: Rss 피드를 생성할 사이트의 링크
 /// ### `channel_image_link`: 채널 이미지 링크
@@ -59,20 +61,33 @@ pub async fn rss_generate(
 /// ### `item_pub_date_css`: 아이템 게시 날짜의 CSS 선택자
 /// ### `item_image_css`: 아이템 이미지의 CSS 선택자
 pub async fn rss_generate_by_css(
-    pool: &State<MySqlPool>,
-    embedding_service: &State<EmbeddingService>,
-    driver_pool: &State<DriverPool>,
+    job_queue: &State<RssGenerateJobQueue>,
+    user: AuthenticatedUser,
     data: Json<RssGenerateByCssReqeustDto>,
-) -> Result<Json<RssGenerateResponseDto>, Status> {
-    match premium_rss_service::generate_rss_by_css(
-        pool,
-        embedding_service,
-        driver_pool,
-        data.into_inner(),
-    )
-    .await
-    {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<RssGenerateJobResponseDto>, OmniNewsError> {
+    job_queue
+        .enqueue(user.user_email, RssGenerateJobKind::Css(data.into_inner()))
+        .await
+        .map(|dto| Json(dto.localized(&user.timezone)))
+}
+
+#[openapi(tag = "Premium RSS Generation API")]
+#[get("/premium/rss/jobs/<job_id>")]
+/// # RSS Generation Job API
+///
+/// RSS 생성 작업의 진행 상태를 조회합니다.
+///
+/// ### `status`: Queued, Running, Completed, Failed
+/// ### `queue_position`: 대기 중일 때 앞에 남은 작업 수
+/// ### `result`: 완료 시 생성된 채널
+/// ### `error`: 실패 시 에러 메시지
+pub async fn rss_generate_job(
+    job_queue: &State<RssGenerateJobQueue>,
+    user: AuthenticatedUser,
+    job_id: &str,
+) -> Result<Json<RssGenerateJobResponseDto>, OmniNewsError> {
+    job_queue
+        .find_job(&user.user_email, job_id)
+        .await
+        .map(|dto| Json(dto.localized(&user.timezone)))
 }
@@ -5,10 +5,13 @@ use rocket_okapi::settings::OpenApiSettings;
 use rocket_okapi::{openapi, openapi_get_routes_spec};
 use sqlx::MySqlPool;
 
-use crate::auth_middleware::AuthenticatedUser;
+use crate::auth_middleware::{AdminUser, AuthenticatedUser};
+use crate::config::http_client::HttpClient;
 use crate::dto::rss::request::{CreateRssRequestDto, UpdateRssRankRequestDto};
 use crate::dto::rss::response::{RssChannelResponseDto, RssItemResponseDto};
+use crate::model::error::OmniNewsError;
 use crate::service::{channel_service, item_service};
+use crate::utils::date_util::LocalizeTimestamps;
 use crate::EmbeddingService;
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
@@ -29,22 +32,22 @@ pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, O
 pub async fn create_channel(
     pool: &State<MySqlPool>,
     model: &State<EmbeddingService>,
+    http_client: &State<HttpClient>,
     link: Json<CreateRssRequestDto>,
     _auth: AuthenticatedUser,
-) -> Result<Json<i32>, Status> {
+) -> Result<Json<i32>, OmniNewsError> {
     if link.rss_link.is_empty() {
-        return Err(Status::BadRequest);
+        return Err(OmniNewsError::InvalidRequest("rss_link is required".into()));
     }
 
-    match channel_service::create_rss_and_embedding(pool, model, link.into_inner().rss_link).await {
-        Ok(channel_id) => Ok(Json(channel_id)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    channel_service::create_rss_and_embedding(pool, model, http_client, link.into_inner().rss_link)
+        .await
+        .map(Json)
 }
 
 /// # RSS 일괄 생성 API
 ///
-/// 여러 RSS 링크를 한 번에 처리하여 채널을 생성합니다.
+/// 관리자 전용. 여러 RSS 링크를 한 번에 처리하여 채널을 생성합니다.
 ///
 /// ### `[ { rss_link` : RSS 피드 URL (예: "https://example.com/feed.xml") }, { rss_link: ... } ]
 ///
@@ -53,17 +56,17 @@ pub async fn create_channel(
 pub async fn create_rss_all(
     pool: &State<MySqlPool>,
     model: &State<EmbeddingService>,
+    http_client: &State<HttpClient>,
     links: Json<Vec<CreateRssRequestDto>>,
-    _auth: AuthenticatedUser,
-) -> Result<Json<bool>, Status> {
+    _admin: AdminUser,
+) -> Result<Json<bool>, OmniNewsError> {
     if links.is_empty() {
-        return Err(Status::BadRequest);
+        return Err(OmniNewsError::InvalidRequest("links are required".into()));
     }
 
-    match channel_service::create_rss_all(pool, model, links.into_inner()).await {
-        Ok(result) => Ok(Json(result)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    channel_service::create_rss_all(pool, model, http_client, links.into_inner())
+        .await
+        .map(Json)
 }
 
 /// # RSS 링크로 채널 ID 조회 API
@@ -78,11 +81,10 @@ pub async fn get_channel_id_by_rss_link(
     pool: &State<MySqlPool>,
     channel_rss_link: String,
     _auth: AuthenticatedUser,
-) -> Result<Json<i32>, Status> {
-    match channel_service::find_rss_channel_by_rss_link(pool, channel_rss_link).await {
-        Ok(res) => Ok(Json(res.channel_id.unwrap())),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<i32>, OmniNewsError> {
+    channel_service::find_rss_channel_by_rss_link(pool, channel_rss_link)
+        .await
+        .map(|res| Json(res.channel_id.unwrap()))
 }
 
 /// # RSS 채널 상세정보 조회 API
@@ -97,11 +99,10 @@ pub async fn get_rss_channel_by_id(
     pool: &State<MySqlPool>,
     channel_id: i32,
     _auth: AuthenticatedUser,
-) -> Result<Json<RssChannelResponseDto>, Status> {
-    match channel_service::find_rss_channel_by_id(pool, channel_id).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<RssChannelResponseDto>, OmniNewsError> {
+    channel_service::find_rss_channel_by_id(pool, channel_id)
+        .await
+        .map(Json)
 }
 
 /// # RSS 채널 아이템 조회 API
@@ -115,18 +116,17 @@ pub async fn get_rss_channel_by_id(
 pub async fn get_rss_item_by_channel_id(
     pool: &State<MySqlPool>,
     channel_id: i32,
-    _auth: AuthenticatedUser,
-) -> Result<Json<Vec<RssItemResponseDto>>, Status> {
-    match item_service::get_rss_item_by_channel_id(pool, channel_id).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    auth: AuthenticatedUser,
+) -> Result<Json<Vec<RssItemResponseDto>>, OmniNewsError> {
+    item_service::get_rss_item_by_channel_id(pool, channel_id)
+        .await
+        .map(|dto| Json(dto.localized(&auth.timezone)))
 }
 
 // TODO 추천 채널 기능 변경 후 여기 내용 추가하기
 /// # 추천 RSS 채널 조회 API
 ///
-/// 사용자에게 추천하는 RSS 채널 목록을 반환합니다.
+/// 사용자에게 추천하는 RSS 채널 목록을 반환합니다. 뮤트한 채널은 제외됩니다.
 ///
 /// ## 기능 설명
 /// 랭크 50순위 채널에서 20개 랜덤 반환
@@ -135,18 +135,17 @@ pub async fn get_rss_item_by_channel_id(
 #[get("/rss/recommend/channel")]
 pub async fn get_recommend_channel(
     pool: &State<MySqlPool>,
-    _auth: AuthenticatedUser,
-) -> Result<Json<Vec<RssChannelResponseDto>>, Status> {
-    match channel_service::get_recommend_channel(pool).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<RssChannelResponseDto>>, OmniNewsError> {
+    channel_service::get_recommend_channel(pool, user.user_email)
+        .await
+        .map(Json)
 }
 
 // TODO 추천 rss 기능 변경 후 여기 내용 추가하기
 /// # 추천 RSS 아이템 조회 API
 ///
-/// 사용자에게 추천하는 RSS 아이템 목록을 반환합니다.
+/// 사용자에게 추천하는 RSS 아이템 목록을 반환합니다. 사용자의 뮤트 규칙에 걸리는 아이템은 제외됩니다.
 ///
 /// ## 기능 설명
 /// 상위 100개 중 50개 랜덤 반환
@@ -155,12 +154,11 @@ pub async fn get_recommend_channel(
 #[get("/rss/recommend/item")]
 pub async fn get_recommend_item(
     pool: &State<MySqlPool>,
-    _auth: AuthenticatedUser,
-) -> Result<Json<Vec<RssItemResponseDto>>, Status> {
-    match item_service::get_recommend_item(pool).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<RssItemResponseDto>>, OmniNewsError> {
+    item_service::get_recommend_item(pool, user.user_email)
+        .await
+        .map(|dto| Json(dto.localized(&user.timezone)))
 }
 
 /// # RSS 미리보기 API
@@ -173,13 +171,13 @@ pub async fn get_recommend_item(
 #[get("/rss/preview?<rss_link>")]
 pub async fn get_rss_preview(
     pool: &State<MySqlPool>,
+    http_client: &State<HttpClient>,
     rss_link: String,
     _auth: AuthenticatedUser,
-) -> Result<Json<RssChannelResponseDto>, Status> {
-    match channel_service::get_rss_preview(pool, rss_link).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<RssChannelResponseDto>, OmniNewsError> {
+    channel_service::get_rss_preview(pool, http_client, rss_link)
+        .await
+        .map(Json)
 }
 
 /// # RSS 존재 여부 확인 API
@@ -194,16 +192,15 @@ pub async fn is_rss_exist(
     pool: &State<MySqlPool>,
     rss_link: String,
     _auth: AuthenticatedUser,
-) -> Result<Json<bool>, Status> {
-    match channel_service::is_channel_exist_by_link(pool, rss_link).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<bool>, OmniNewsError> {
+    channel_service::is_channel_exist_by_link(pool, rss_link)
+        .await
+        .map(Json)
 }
 
 /// # RSS 아이템 평가 순위 업데이트 API
 ///
-/// RSS 아이템의 인기도 순위를 업데이트합니다.
+/// 관리자 전용. RSS 아이템의 인기도 순위를 업데이트합니다.
 ///
 /// ### `rss_id` : 업데이트할 RSS 아이템 ID
 ///
@@ -214,9 +211,8 @@ pub async fn is_rss_exist(
 pub async fn update_rss_item_rank(
     pool: &State<MySqlPool>,
     update_rss_rank: Json<UpdateRssRankRequestDto>,
-) -> Result<Status, Status> {
-    match item_service::update_rss_item_rank(pool, update_rss_rank.into_inner()).await {
-        Ok(_) => Ok(Status::Ok),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    _admin: AdminUser,
+) -> Result<Status, OmniNewsError> {
+    item_service::update_rss_item_rank(pool, update_rss_rank.into_inner()).await?;
+    Ok(Status::Ok)
 }
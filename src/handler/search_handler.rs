@@ -1,5 +1,4 @@
 use okapi::openapi3::OpenApi;
-use rocket::http::Status;
 use rocket::serde::json::Json;
 use rocket::State;
 use rocket_okapi::settings::OpenApiSettings;
@@ -7,11 +6,14 @@ use rocket_okapi::{openapi, openapi_get_routes_spec};
 use sqlx::MySqlPool;
 
 use crate::auth_middleware::AuthenticatedUser;
+use crate::config::http_client::HttpClient;
 use crate::dto::news::request::NewsRequestDto;
 use crate::dto::news::response::NewsApiResponseDto;
 use crate::dto::search::request::SearchRequestDto;
 use crate::dto::search::response::SearchResponseDto;
+use crate::model::error::OmniNewsError;
 use crate::service::{channel_service, item_service, news_service};
+use crate::utils::date_util::LocalizeTimestamps;
 use crate::EmbeddingService;
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
@@ -20,7 +22,7 @@ pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, O
 
 /// # 검색 내용으로 RSS 목록 조회 API
 ///
-/// 검색 내용에 해당하는 RSS 아이템 목록을 반환합니다.
+/// 검색 내용에 해당하는 RSS 아이템 목록을 반환합니다. 사용자의 뮤트 규칙에 걸리는 아이템은 제외됩니다.
 ///
 /// ### `search_value` : 검색어 (예: "AI", "경제")
 ///
@@ -34,16 +36,17 @@ pub async fn get_rss_list(
     pool: &State<MySqlPool>,
     model: &State<EmbeddingService>,
     request: SearchRequestDto,
-    _auth: AuthenticatedUser,
-) -> Result<Json<SearchResponseDto>, Status> {
+    user: AuthenticatedUser,
+) -> Result<Json<SearchResponseDto>, OmniNewsError> {
     if request.search_value.is_none() {
-        return Err(Status::BadRequest);
+        return Err(OmniNewsError::InvalidRequest(
+            "search_value is required".into(),
+        ));
     }
 
-    match item_service::get_rss_list(pool, model, request).await {
-        Ok(result) => Ok(Json(result)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    item_service::get_rss_list(pool, model, user.user_email, request)
+        .await
+        .map(|dto| Json(dto.localized(&user.timezone)))
 }
 
 /// # 검색 내용으로 RSS 채널 조회 API
@@ -62,16 +65,17 @@ pub async fn get_channel_list(
     pool: &State<MySqlPool>,
     model: &State<EmbeddingService>,
     request: SearchRequestDto,
-    _auth: AuthenticatedUser,
-) -> Result<Json<SearchResponseDto>, Status> {
+    auth: AuthenticatedUser,
+) -> Result<Json<SearchResponseDto>, OmniNewsError> {
     if request.search_value.is_none() {
-        return Err(Status::BadRequest);
+        return Err(OmniNewsError::InvalidRequest(
+            "search_value is required".into(),
+        ));
     }
 
-    match channel_service::get_channel_list(pool, model, request).await {
-        Ok(result) => Ok(Json(result)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    channel_service::get_channel_list(pool, model, request)
+        .await
+        .map(|dto| Json(dto.localized(&auth.timezone)))
 }
 
 /// # 외부 API 뉴스 조회 API
@@ -87,11 +91,11 @@ pub async fn get_channel_list(
 #[openapi(tag = "검색 API")]
 #[get("/search/news_api?<params..>")]
 pub async fn get_news_by_api(
+    http_client: &State<HttpClient>,
     params: NewsRequestDto,
-    _auth: AuthenticatedUser,
-) -> Result<Json<Vec<NewsApiResponseDto>>, Status> {
-    match news_service::get_news_by_api(params).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    auth: AuthenticatedUser,
+) -> Result<Json<Vec<NewsApiResponseDto>>, OmniNewsError> {
+    news_service::get_news_by_api(http_client, params)
+        .await
+        .map(|dto| Json(dto.localized(&auth.timezone)))
 }
@@ -1,5 +1,4 @@
 use okapi::openapi3::OpenApi;
-use rocket::http::Status;
 use rocket::{serde::json::Json, State};
 use rocket_okapi::settings::OpenApiSettings;
 use rocket_okapi::{openapi, openapi_get_routes_spec};
@@ -8,7 +7,9 @@ use sqlx::MySqlPool;
 use crate::auth_middleware::AuthenticatedUser;
 use crate::dto::rss::response::{RssChannelResponseDto, RssItemResponseDto};
 use crate::dto::subscribe::request::SubscribeRequestDto;
+use crate::model::error::OmniNewsError;
 use crate::service::subscription_service;
+use crate::utils::date_util::LocalizeTimestamps;
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
     openapi_get_routes_spec![settings:
@@ -32,13 +33,9 @@ pub async fn subscribe_channel(
     pool: &State<MySqlPool>,
     user: AuthenticatedUser,
     channel_id: Json<SubscribeRequestDto>,
-) -> Result<&str, Status> {
-    match subscription_service::subscribe_channel(pool, user.user_email, channel_id.into_inner())
-        .await
-    {
-        Ok(_) => Ok("Success subscribe channel"),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<&str, OmniNewsError> {
+    subscription_service::subscribe_channel(pool, user.user_email, channel_id.into_inner()).await?;
+    Ok("Success subscribe channel")
 }
 
 /// # 이미 구독된 채널 확인 API
@@ -53,18 +50,11 @@ pub async fn validate_already_subscribe_channel(
     pool: &State<MySqlPool>,
     user: AuthenticatedUser,
     channel_rss_link: String,
-) -> Result<Json<bool>, Status> {
+) -> Result<Json<bool>, OmniNewsError> {
     info!("channel_rss_link: {}", channel_rss_link);
-    match subscription_service::is_already_subscribe_channel(
-        pool,
-        user.user_email,
-        channel_rss_link,
-    )
-    .await
-    {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    subscription_service::is_already_subscribe_channel(pool, user.user_email, channel_rss_link)
+        .await
+        .map(Json)
 }
 
 /// # 사용자 구독 채널 조회 API
@@ -76,17 +66,16 @@ pub async fn validate_already_subscribe_channel(
 pub async fn get_subscribe_channels(
     pool: &State<MySqlPool>,
     user: AuthenticatedUser,
-) -> Result<Json<Vec<RssChannelResponseDto>>, Status> {
-    match subscription_service::get_subscription_channels(pool, user.user_email).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<Vec<RssChannelResponseDto>>, OmniNewsError> {
+    subscription_service::get_subscription_channels(pool, user.user_email)
+        .await
+        .map(Json)
 }
 
 // TODO 구독한 채널 가져와서 바로 아이템 조회되도록 로직 수정
 /// # 사용자가 구독한 채널의 아이템 조회 API
 ///
-/// 사용자가 구독한 채널의 아이템 목록을 조회합니다.
+/// 사용자가 구독한 채널의 아이템 목록을 조회합니다. 사용자의 뮤트 규칙에 걸리는 아이템은 제외됩니다.
 ///
 /// ### `channel_ids` : 구독한 채널 ID 목록 (예: "1, 2, 3")
 ///
@@ -95,17 +84,16 @@ pub async fn get_subscribe_channels(
 pub async fn get_subscribe_items(
     pool: &State<MySqlPool>,
     channel_ids: String,
-    _auth: AuthenticatedUser,
-) -> Result<Json<Vec<RssItemResponseDto>>, Status> {
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<RssItemResponseDto>>, OmniNewsError> {
     let channel_ids: Vec<i32> = channel_ids
         .split(',')
         .filter_map(|s| s.trim().parse().ok())
         .collect();
 
-    match subscription_service::get_subscription_items(pool, channel_ids).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    subscription_service::get_subscription_items(pool, user.user_email, channel_ids)
+        .await
+        .map(|dto| Json(dto.localized(&user.timezone)))
 }
 
 /// # 구독 취소 API
@@ -120,11 +108,8 @@ pub async fn unsubscribe_channel(
     pool: &State<MySqlPool>,
     user: AuthenticatedUser,
     channel_id: Json<SubscribeRequestDto>,
-) -> Result<&str, Status> {
-    match subscription_service::unsubscribe_channel(pool, user.user_email, channel_id.into_inner())
-        .await
-    {
-        Ok(_) => Ok("Success unsubscribe channel"),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<&str, OmniNewsError> {
+    subscription_service::unsubscribe_channel(pool, user.user_email, channel_id.into_inner())
+        .await?;
+    Ok("Success unsubscribe channel")
 }
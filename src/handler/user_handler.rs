@@ -1,36 +1,43 @@
 use okapi::openapi3::OpenApi;
-use rocket::{http::Status, serde::json::Json, State};
+use rocket::{
+    http::{ContentType, Status},
+    serde::json::Json,
+    State,
+};
 use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
 use sqlx::MySqlPool;
 
 use crate::{
     auth_middleware::AuthenticatedUser,
+    config::http_client::HttpClient,
     dto::{
-        auth::{
-            request::VerifyRefreshTokenRequestDto,
-            response::{AccessTokenResponseDto, JwtTokenResponseDto},
-        },
+        auth::{request::VerifyRefreshTokenRequestDto, response::JwtTokenResponseDto},
         user::{
             request::{
                 AppleLoginRequestDto, LoginUserRequestDto, UserNotificationRequestDto,
-                UserThemeRequestDto,
+                UserThemeRequestDto, UserTimezoneRequestDto,
             },
-            response::UserThemeResponseDto,
+            response::{UserThemeResponseDto, UserTimezoneResponseDto},
         },
     },
+    model::error::OmniNewsError,
     service::user_service,
+    utils::date_util::LocalizeTimestamps,
 };
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
-    openapi_get_routes_spec![settings: verify_refresh_token, verify_access_token, login, apple_login, logout, notification_setting, get_user_theme, user_theme_setting]
+    openapi_get_routes_spec![settings: verify_refresh_token, verify_access_token, login, apple_login, logout, notification_setting, get_user_theme, user_theme_setting, get_user_timezone, user_timezone_setting, delete_user, export_user_data]
 }
 
 /// # 리프레시 토큰 검증 API
 ///
-/// 유효한 리프레시 토큰으로 새 액세스 토큰을 발급합니다.
+/// 유효한 리프레시 토큰으로 새 액세스 토큰과 리프레시 토큰을 발급합니다.
 ///
 /// 클라이언트에서 로그인 실패 시 refresh token 재발급 요청
 ///
+/// 리프레시 토큰은 한 번만 사용할 수 있습니다.
+/// 이미 사용된 토큰이 다시 들어오면 해당 기기의 세션이 폐기됩니다.
+///
 /// ### `token` : 클라이언트가 가지고 있는 refresh token
 ///
 /// ### `email` : 클라이언트의 email 주소
@@ -40,18 +47,17 @@ pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, O
 pub async fn verify_refresh_token(
     pool: &State<MySqlPool>,
     refresh_token: Json<VerifyRefreshTokenRequestDto>,
-) -> Result<Json<AccessTokenResponseDto>, Status> {
-    match user_service::validate_refresh_token(pool, refresh_token.into_inner()).await {
-        Ok(token) => Ok(Json(token)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<JwtTokenResponseDto>, OmniNewsError> {
+    user_service::rotate_refresh_token(pool, refresh_token.into_inner())
+        .await
+        .map(Json)
 }
 
 /// # 로그인/회원가입 API
 ///
 /// 사용자 소셜 로그인 정보로 로그인 또는 신규 가입을 처리합니다.
 ///
-/// ### `user_email` : 사용자의 이메일 주소
+/// ### `user_email` : 사용되지 않습니다. 계정 이메일은 제공자가 검증한 이메일로 정합니다.
 ///
 /// ### `user_display_name` : 사용자의 표시 이름
 ///
@@ -61,16 +67,25 @@ pub async fn verify_refresh_token(
 ///
 /// ### `user_social_provider_id` : 소셜 로그인 제공자의 고유 ID ( 소셜 로그인 제공자에서 제공 )
 ///
+/// ### `device_id` : 기기 고유 ID (같은 기기에서 다시 로그인하면 이전 세션을 대체합니다.)
+///
+/// ### `id_token` : 소셜 로그인 제공자가 발급한 ID 토큰 (kakao는 access token)
+///
+/// 토큰 검증에 실패하거나 `user_social_provider_id`가 토큰과 다르면 401을 반환합니다.
+/// 처음 가입하거나 기존 이메일 계정에 연결할 때는 제공자가 검증한 이메일이 필요하며,
+/// 이메일이 같더라도 다른 제공자 계정과 연결된 계정이면 409(`ACCOUNT_LINKED_TO_OTHER_PROVIDER`)를 반환합니다.
+/// 이때 `msg`에는 연결된 제공자(예: google)가 담깁니다.
+///
 #[openapi(tag = "인증 API")]
 #[post("/user/login", data = "<user_data>")]
 pub async fn login(
     pool: &State<MySqlPool>,
+    http_client: &State<HttpClient>,
     user_data: Json<LoginUserRequestDto>,
-) -> Result<Json<JwtTokenResponseDto>, Status> {
-    match user_service::login_or_create_user(pool, user_data.into_inner()).await {
-        Ok(token) => Ok(Json(token)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<JwtTokenResponseDto>, OmniNewsError> {
+    user_service::login_or_create_user(pool, http_client, user_data.into_inner())
+        .await
+        .map(Json)
 }
 
 /// # 애플 로그인 API
@@ -79,21 +94,25 @@ pub async fn login(
 ///
 /// ### `user_social_provider_id` : 애플에서 발급한 고유 ID (로그인에 사용됩니다.)
 ///
+/// ### `device_id` : 기기 고유 ID
+///
+/// ### `id_token` : 애플에서 발급한 identity token
+///
 #[openapi(tag = "인증 API")]
 #[post("/user/apple/login", data = "<user_data>")]
 pub async fn apple_login(
     pool: &State<MySqlPool>,
+    http_client: &State<HttpClient>,
     user_data: Json<AppleLoginRequestDto>,
-) -> Result<Json<JwtTokenResponseDto>, Status> {
-    match user_service::apple_login(pool, user_data.into_inner()).await {
-        Ok(token) => Ok(Json(token)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<JwtTokenResponseDto>, OmniNewsError> {
+    user_service::apple_login(pool, http_client, user_data.into_inner())
+        .await
+        .map(Json)
 }
 
 /// # 로그아웃 API
 ///
-/// 현재 로그인된 사용자의 액세스 토큰을 삭제합니다.
+/// 현재 기기의 로그인 세션을 폐기합니다. 다른 기기의 세션은 유지됩니다.
 ///
 #[openapi(tag = "인증 API")]
 #[post("/user/logout")]
@@ -101,11 +120,9 @@ pub async fn logout(
     pool: &State<MySqlPool>,
     user: AuthenticatedUser,
     _auth: AuthenticatedUser,
-) -> Result<Status, Status> {
-    match user_service::delete_user_token(pool, user.user_email).await {
-        Ok(_) => Ok(Status::Ok),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Status, OmniNewsError> {
+    user_service::delete_user_token(pool, user.user_email, user.session_id).await?;
+    Ok(Status::Ok)
 }
 
 /// # 액세스 토큰 검증 API
@@ -132,17 +149,14 @@ pub async fn notification_setting(
     pool: &State<MySqlPool>,
     user: AuthenticatedUser,
     notification_data: Json<UserNotificationRequestDto>,
-) -> Result<Status, Status> {
-    match user_service::update_user_notification_setting(
+) -> Result<Status, OmniNewsError> {
+    user_service::update_user_notification_setting(
         pool,
         user.user_email,
         notification_data.into_inner(),
     )
-    .await
-    {
-        Ok(_) => Ok(Status::Ok),
-        Err(_) => Err(Status::InternalServerError),
-    }
+    .await?;
+    Ok(Status::Ok)
 }
 
 /// # 사용자 테마 확인 API
@@ -154,11 +168,10 @@ pub async fn notification_setting(
 pub async fn get_user_theme(
     pool: &State<MySqlPool>,
     user: AuthenticatedUser,
-) -> Result<Json<UserThemeResponseDto>, Status> {
-    match user_service::get_user_theme(pool, user.user_email).await {
-        Ok(res) => Ok(Json(res)),
-        Err(_) => Err(Status::InternalServerError),
-    }
+) -> Result<Json<UserThemeResponseDto>, OmniNewsError> {
+    user_service::get_user_theme(pool, user.user_email)
+        .await
+        .map(Json)
 }
 
 /// # 사용자 테마 설정 API
@@ -173,9 +186,85 @@ pub async fn user_theme_setting(
     pool: &State<MySqlPool>,
     theme_data: Json<UserThemeRequestDto>,
     user: AuthenticatedUser,
-) -> Result<Status, Status> {
-    match user_service::update_user_theme(pool, user.user_email, theme_data.into_inner()).await {
-        Ok(_) => Ok(Status::Ok),
-        Err(_) => Err(Status::InternalServerError),
+) -> Result<Status, OmniNewsError> {
+    user_service::update_user_theme(pool, user.user_email, theme_data.into_inner()).await?;
+    Ok(Status::Ok)
+}
+
+/// # 사용자 시간대 확인 API
+///
+/// 사용자가 설정한 시간대를 확인하는 API입니다.
+///
+/// 인증한 요청의 응답 시각(`*_at`, `*_date`)은 이 시간대의 RFC 3339 문자열로 반환됩니다. (기본 Asia/Seoul)
+///
+#[openapi(tag = "유저 API")]
+#[get("/user/timezone")]
+pub async fn get_user_timezone(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<UserTimezoneResponseDto>, OmniNewsError> {
+    user_service::get_user_timezone(pool, user.user_email)
+        .await
+        .map(Json)
+}
+
+/// # 사용자 시간대 설정 API
+///
+/// 사용자의 시간대를 변경하는 API입니다.
+///
+/// ### `user_timezone` : IANA 시간대 이름 (예: Asia/Seoul, America/New_York)
+///
+#[openapi(tag = "유저 API")]
+#[post("/user/timezone", data = "<timezone_data>")]
+pub async fn user_timezone_setting(
+    pool: &State<MySqlPool>,
+    timezone_data: Json<UserTimezoneRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Status, OmniNewsError> {
+    user_service::update_user_timezone(pool, user.user_email, timezone_data.into_inner()).await?;
+    Ok(Status::Ok)
+}
+
+/// # 회원 탈퇴 API
+///
+/// 모든 기기에서 로그아웃되고 탈퇴 상태가 됩니다.
+/// 유예 기간(기본 30일) 안에 다시 로그인하면 탈퇴가 취소되며, 이후에는 구독, 폴더 등 모든 사용자 데이터가 삭제됩니다.
+///
+#[openapi(tag = "유저 API")]
+#[delete("/user")]
+pub async fn delete_user(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Status, OmniNewsError> {
+    user_service::delete_user(pool, user.user_email).await?;
+    Ok(Status::Ok)
+}
+
+/// # 개인정보 내보내기 API
+///
+/// 프로필, 구독 채널, 폴더, 스마트 폴더, 뮤트 규칙, 읽기 기록, 로그인 기기, 피드백을 내려받습니다.
+///
+/// ### `format` : "json" (기본값) 또는 "zip"
+///
+#[openapi(tag = "유저 API")]
+#[get("/user/export?<format>")]
+pub async fn export_user_data(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+    format: Option<String>,
+) -> Result<(ContentType, Vec<u8>), OmniNewsError> {
+    let export = user_service::export_user_data(pool, user.user_email)
+        .await?
+        .localized(&user.timezone);
+
+    match format.as_deref() {
+        Some("zip") => user_service::make_export_zip(&export).map(|zip| (ContentType::ZIP, zip)),
+        None | Some("json") => serde_json::to_vec(&export)
+            .map(|json| (ContentType::JSON, json))
+            .map_err(|e| OmniNewsError::JsonParseError(e.to_string())),
+        Some(other) => Err(OmniNewsError::InvalidRequest(format!(
+            "unsupported export format: {}",
+            other
+        ))),
     }
 }
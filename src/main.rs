@@ -6,7 +6,9 @@ mod config;
 mod dto;
 mod handler;
 mod model;
+mod rate_limit_middleware;
 mod repository;
+mod scheduler;
 mod service;
 mod utils;
 
@@ -14,13 +16,24 @@ use auth_middleware::{AuthCache, AuthMiddleware, CORS};
 use config::{
     env, logging, openapi::custom_openapi_spec, rapidoc::create_rapidoc, swagger::create_swagger_ui,
 };
-use handler::{config_handler::options_handler, error_handler::error_catchers};
+use handler::{
+    config_handler::{options_handler, rate_limited_handler},
+    error_handler::error_catchers,
+};
+use rate_limit_middleware::RateLimiter;
 use rocket_okapi::mount_endpoints_and_merged_docs;
-use utils::embedding_util::EmbeddingService;
+use utils::embedding_util::{EmbeddingConfig, EmbeddingService};
 
 use crate::{
-    config::webdriver::{DriverPool, DriverPoolConfig},
-    utils::db_util,
+    config::{
+        http_client::{HttpClient, HttpClientConfig},
+        webdriver::{DriverPool, DriverPoolConfig},
+    },
+    service::{
+        image_proxy_service::ImageCache, premium::rss_generate_job_service::RssGenerateJobQueue,
+        reembedding_job_service::ReembeddingJobManager,
+    },
+    utils::{annoy_util, db_util},
 };
 
 pub const CURRENT_VERSION: &str = "v1";
@@ -36,8 +49,40 @@ async fn rocket() -> _ {
 
     let pool = db_util::create_pool().await;
     let pool_middleware = pool.clone();
+    let rate_limiter = RateLimiter::from_env(pool.clone());
+    let http_client = HttpClient::new(HttpClientConfig::from_env());
+
+    scheduler::omninews_subscription_scheduler::start(pool.clone(), http_client.clone());
+    scheduler::user_purge_scheduler::start(pool.clone());
+    scheduler::mute_cleanup_scheduler::start(pool.clone());
+
+    let embedding_service =
+        EmbeddingService::new(EmbeddingConfig::from_env(), http_client.clone());
+    scheduler::channel_metadata_scheduler::start(
+        pool.clone(),
+        embedding_service.clone(),
+        http_client.clone(),
+    );
+
+    let rss_generate_job_queue = RssGenerateJobQueue::new(
+        pool.clone(),
+        embedding_service.clone(),
+        http_client.clone(),
+        driver_pool.clone(),
+    );
+
+    let reembedding_job_manager =
+        ReembeddingJobManager::new(pool.clone(), embedding_service.clone());
+    let resumed_job_manager = reembedding_job_manager.clone();
+    tokio::spawn(async move {
+        resumed_job_manager.resume_running_jobs().await;
+    });
 
-    let embedding_service = EmbeddingService::new();
+    let index_pool = pool.clone();
+    let index_embedding_service = embedding_service.clone();
+    tokio::spawn(async move {
+        annoy_util::rebuild_stale_indexes(&index_pool, &index_embedding_service).await;
+    });
 
     let exempt_paths = vec![
         // omninews
@@ -45,6 +90,8 @@ async fn rocket() -> _ {
         "/v1/api/user/apple/login".to_string(),
         "/v1/api/user/refresh-token".to_string(),
         "/v1/api/health".to_string(),
+        // 이미지 프록시는 <img> 태그로 요청되므로 서명으로 검증한다.
+        "/v1/api/img/".to_string(),
         // openapi
         "/rapidoc/".to_string(),
         "/swagger-ui/".to_string(),
@@ -56,11 +103,17 @@ async fn rocket() -> _ {
         .manage(embedding_service)
         .manage(AuthCache::new())
         .manage(driver_pool)
+        .manage(rss_generate_job_queue)
+        .manage(reembedding_job_manager)
+        .manage(ImageCache::from_env())
+        .manage(http_client)
         .attach(CORS)
         .attach(AuthMiddleware::new(exempt_paths, pool_middleware))
+        // AuthMiddleware 다음에 실행되어야 사용자 이메일로 구분할 수 있다.
+        .attach(rate_limiter)
         .mount("/rapidoc/", create_rapidoc())
         .mount("/swagger-ui/", create_swagger_ui())
-        .mount("/", routes![options_handler])
+        .mount("/", routes![options_handler, rate_limited_handler])
         .register("/", error_catchers());
 
     let openapi_settings = rocket_okapi::settings::OpenApiSettings::default();
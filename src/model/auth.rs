@@ -1,4 +1,4 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Utc};
 
 pub enum TokenType {
     Access,
@@ -9,22 +9,16 @@ pub enum TokenType {
 pub struct JwtToken {
     pub access_token: Option<String>,
     pub refresh_token: Option<String>,
-    pub access_token_expires_at: Option<NaiveDateTime>,
-    pub refresh_token_expires_at: Option<NaiveDateTime>,
-}
-
-#[derive(Debug, Clone)]
-pub struct AccessToken {
-    pub access_token: Option<String>,
-    pub access_token_expires_at: Option<NaiveDateTime>,
+    pub access_token_expires_at: Option<DateTime<Utc>>,
+    pub refresh_token_expires_at: Option<DateTime<Utc>>,
 }
 
 impl JwtToken {
     pub fn new(
         access_token: String,
         refresh_token: String,
-        access_token_expires_at: NaiveDateTime,
-        refresh_token_expires_at: NaiveDateTime,
+        access_token_expires_at: DateTime<Utc>,
+        refresh_token_expires_at: DateTime<Utc>,
     ) -> Self {
         Self {
             access_token: Some(access_token),
@@ -34,12 +28,3 @@ impl JwtToken {
         }
     }
 }
-
-impl AccessToken {
-    pub fn new(access_token: String, access_token_expires_at: NaiveDateTime) -> Self {
-        Self {
-            access_token: Some(access_token),
-            access_token_expires_at: Some(access_token_expires_at),
-        }
-    }
-}
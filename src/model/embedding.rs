@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 
@@ -8,6 +10,9 @@ pub struct NewEmbedding {
     pub rss_id: Option<i32>,
     pub news_id: Option<i32>,
     pub embedding_source_rank: Option<i32>,
+    pub embedding_model_id: Option<String>,
+    pub embedding_dim: Option<i32>,
+    pub embedding_text_recipe_version: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -18,4 +23,148 @@ pub struct Embedding {
     pub rss_id: Option<i32>,
     pub news_id: Option<i32>,
     pub embedding_source_rank: Option<i32>,
+    pub embedding_model_id: Option<String>,
+    pub embedding_dim: Option<i32>,
+    pub embedding_text_recipe_version: Option<i32>,
+}
+
+/// 임베딩을 만든 모델과 문장 형식. 버전이 다른 임베딩끼리는 비교할 수 없다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddingVersion {
+    pub model_id: String,
+    pub text_recipe_version: i32,
+}
+
+/// 임베딩 대상 종류. 검색 인덱스도 종류별로 따로 만든다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbeddingTarget {
+    Channel,
+    Item,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ReembeddingJobStatus {
+    Running,
+    Paused,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ReembeddingJobPhase {
+    Channel,
+    Item,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ReembeddingJob {
+    pub job_id: Option<i32>,
+    pub job_model_id: Option<String>,
+    pub job_text_recipe_version: Option<i32>,
+    pub job_status: Option<String>,
+    pub job_phase: Option<String>,
+    pub job_cursor: Option<i32>,
+    pub job_total: Option<i32>,
+    pub job_processed: Option<i32>,
+    pub job_error: Option<String>,
+    pub job_created_at: Option<DateTime<Utc>>,
+    pub job_updated_at: Option<DateTime<Utc>>,
+    pub job_completed_at: Option<DateTime<Utc>>,
+}
+
+impl NewEmbedding {
+    /// 채널 임베딩. 값과 버전은 embedding_service에서 채운다.
+    pub fn channel(channel_id: i32) -> Self {
+        Self::new(Some(channel_id), None)
+    }
+
+    /// 아이템 임베딩. 값과 버전은 embedding_service에서 채운다.
+    pub fn item(rss_id: Option<i32>) -> Self {
+        Self::new(None, rss_id)
+    }
+
+    fn new(channel_id: Option<i32>, rss_id: Option<i32>) -> Self {
+        Self {
+            embedding_value: None,
+            channel_id,
+            rss_id,
+            news_id: None,
+            embedding_source_rank: Some(0),
+            embedding_model_id: None,
+            embedding_dim: None,
+            embedding_text_recipe_version: None,
+        }
+    }
+}
+
+impl EmbeddingTarget {
+    /// 대상 ID가 들어 있는 embedding 테이블 컬럼
+    pub fn column(&self) -> &'static str {
+        match self {
+            EmbeddingTarget::Channel => "channel_id",
+            EmbeddingTarget::Item => "rss_id",
+        }
+    }
+}
+
+impl ReembeddingJobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReembeddingJobStatus::Running => "running",
+            ReembeddingJobStatus::Paused => "paused",
+            ReembeddingJobStatus::Completed => "completed",
+            ReembeddingJobStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "running" => Some(ReembeddingJobStatus::Running),
+            "paused" => Some(ReembeddingJobStatus::Paused),
+            "completed" => Some(ReembeddingJobStatus::Completed),
+            "failed" => Some(ReembeddingJobStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+impl ReembeddingJobPhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReembeddingJobPhase::Channel => "channel",
+            ReembeddingJobPhase::Item => "item",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "channel" => Some(ReembeddingJobPhase::Channel),
+            "item" => Some(ReembeddingJobPhase::Item),
+            _ => None,
+        }
+    }
+}
+
+impl ReembeddingJob {
+    pub fn status(&self) -> Option<ReembeddingJobStatus> {
+        self.job_status
+            .as_deref()
+            .and_then(ReembeddingJobStatus::parse)
+    }
+
+    pub fn phase(&self) -> ReembeddingJobPhase {
+        self.job_phase
+            .as_deref()
+            .and_then(ReembeddingJobPhase::parse)
+            .unwrap_or(ReembeddingJobPhase::Channel)
+    }
+
+    pub fn version(&self) -> EmbeddingVersion {
+        EmbeddingVersion {
+            model_id: self.job_model_id.clone().unwrap_or_default(),
+            text_recipe_version: self.job_text_recipe_version.unwrap_or_default(),
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use rocket::http::Status;
 use thirtyfour::error::WebDriverError;
 use thiserror::Error;
 
@@ -15,6 +16,12 @@ pub enum OmniNewsError {
     #[error("Failed to embedding sentence")]
     Embedding,
 
+    #[error("Embedding queue is busy")]
+    EmbeddingBusy,
+
+    #[error("Search index is not available")]
+    SearchIndexUnavailable,
+
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
 
@@ -27,12 +34,25 @@ pub enum OmniNewsError {
     #[error("Element not found: {0}")]
     NotFound(String),
 
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+
     #[error("Failed to create JWT token")]
     TokenCreateError,
 
     #[error("Failed to validate JWT token")]
     TokenValidationError,
 
+    #[error("Failed to verify identity token: {0}")]
+    IdentityToken(String),
+
+    /// 같은 이메일의 계정이 다른 소셜 로그인 제공자와 연결되어 있음 (연결된 제공자)
+    #[error("Account is linked to another provider: {0}")]
+    AccountLinkedToOtherProvider(String),
+
     #[error("Configuration error: {0}")]
     Config(String),
 
@@ -42,16 +62,105 @@ pub enum OmniNewsError {
     #[error("Failed extract link")]
     ExtractLinkError,
 
+    #[error("Invalid image: {0}")]
+    InvalidImage(String),
+
+    #[error("Outbound url is not allowed: {0}")]
+    BlockedUrl(String),
+
+    #[error("Response body is too large")]
+    ResponseTooLarge,
+
     #[error("WebDriver error: {0}")]
     WebDriverError(#[from] WebDriverError),
 
     #[error("WebDriver not found")]
     WebDriverNotFound,
 
+    #[error("Job queue is full")]
+    QueueFull,
+
+    #[error("Too many pending jobs")]
+    TooManyJobs,
+
     #[error("WebDriverPool error: {0}")]
     WebDriverPool(#[from] PoolError),
 }
 
+impl OmniNewsError {
+    /// 클라이언트가 분기 처리에 사용하는 고정 에러 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            OmniNewsError::Request(_) => "UPSTREAM_REQUEST_FAILED",
+            OmniNewsError::FetchUrl => "FETCH_URL_FAILED",
+            OmniNewsError::ParseRssChannel => "RSS_PARSE_FAILED",
+            OmniNewsError::Embedding => "EMBEDDING_FAILED",
+            OmniNewsError::EmbeddingBusy => "EMBEDDING_BUSY",
+            OmniNewsError::SearchIndexUnavailable => "SEARCH_INDEX_UNAVAILABLE",
+            OmniNewsError::Database(sqlx::Error::RowNotFound) => "NOT_FOUND",
+            OmniNewsError::Database(_) => "DATABASE_ERROR",
+            OmniNewsError::FetchNews => "NEWS_FETCH_FAILED",
+            OmniNewsError::AlreadyExists => "ALREADY_EXISTS",
+            OmniNewsError::NotFound(_) => "NOT_FOUND",
+            OmniNewsError::Forbidden(_) => "FORBIDDEN",
+            OmniNewsError::InvalidRequest(_) => "INVALID_REQUEST",
+            OmniNewsError::TokenCreateError => "TOKEN_CREATE_FAILED",
+            OmniNewsError::TokenValidationError => "TOKEN_INVALID",
+            OmniNewsError::IdentityToken(_) => "IDENTITY_TOKEN_INVALID",
+            OmniNewsError::AccountLinkedToOtherProvider(_) => "ACCOUNT_LINKED_TO_OTHER_PROVIDER",
+            OmniNewsError::Config(_) => "CONFIG_ERROR",
+            OmniNewsError::JsonParseError(_) => "JSON_PARSE_FAILED",
+            OmniNewsError::ExtractLinkError => "INVALID_LINK",
+            OmniNewsError::InvalidImage(_) => "INVALID_IMAGE",
+            OmniNewsError::BlockedUrl(_) => "URL_NOT_ALLOWED",
+            OmniNewsError::ResponseTooLarge => "RESPONSE_TOO_LARGE",
+            OmniNewsError::WebDriverError(_) => "WEBDRIVER_ERROR",
+            OmniNewsError::WebDriverNotFound => "WEBDRIVER_UNAVAILABLE",
+            OmniNewsError::QueueFull => "QUEUE_FULL",
+            OmniNewsError::TooManyJobs => "TOO_MANY_JOBS",
+            OmniNewsError::WebDriverPool(PoolError::WebDriver(_)) => "WEBDRIVER_ERROR",
+            OmniNewsError::WebDriverPool(_) => "WEBDRIVER_UNAVAILABLE",
+        }
+    }
+
+    pub fn status(&self) -> Status {
+        match self {
+            OmniNewsError::Request(_) | OmniNewsError::FetchUrl | OmniNewsError::FetchNews => {
+                Status::BadGateway
+            }
+            OmniNewsError::ParseRssChannel
+            | OmniNewsError::InvalidImage(_)
+            | OmniNewsError::ResponseTooLarge => Status::UnprocessableEntity,
+            OmniNewsError::Database(sqlx::Error::RowNotFound) | OmniNewsError::NotFound(_) => {
+                Status::NotFound
+            }
+            OmniNewsError::AlreadyExists | OmniNewsError::AccountLinkedToOtherProvider(_) => {
+                Status::Conflict
+            }
+            OmniNewsError::TooManyJobs => Status::TooManyRequests,
+            OmniNewsError::Forbidden(_) => Status::Forbidden,
+            OmniNewsError::InvalidRequest(_)
+            | OmniNewsError::ExtractLinkError
+            | OmniNewsError::BlockedUrl(_) => Status::BadRequest,
+            OmniNewsError::TokenValidationError | OmniNewsError::IdentityToken(_) => {
+                Status::Unauthorized
+            }
+            OmniNewsError::WebDriverNotFound
+            | OmniNewsError::QueueFull
+            | OmniNewsError::EmbeddingBusy
+            | OmniNewsError::SearchIndexUnavailable => Status::ServiceUnavailable,
+            OmniNewsError::WebDriverPool(PoolError::WebDriver(_)) => Status::InternalServerError,
+            OmniNewsError::WebDriverPool(_) => Status::ServiceUnavailable,
+            OmniNewsError::Embedding
+            | OmniNewsError::Database(_)
+            | OmniNewsError::TokenCreateError
+            | OmniNewsError::Config(_)
+            | OmniNewsError::JsonParseError(_)
+            | OmniNewsError::WebDriverError(_) => Status::InternalServerError,
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum PoolError {
     #[error("Pool exhausted")]
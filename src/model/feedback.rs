@@ -1,10 +1,16 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 
+use crate::dto::feedback::request::CreateFeedbackRequestDto;
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct NewFeedback {
     pub feedback_email: Option<String>,
     pub feedback_content: Option<String>,
+    pub feedback_app_version: Option<String>,
+    pub feedback_device_info: Option<String>,
+    pub feedback_created_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -12,4 +18,22 @@ pub struct Feedback {
     pub feedback_id: Option<i32>,
     pub feedback_email: Option<String>,
     pub feedback_content: Option<String>,
+    pub feedback_app_version: Option<String>,
+    pub feedback_device_info: Option<String>,
+    pub feedback_status: Option<String>,
+    pub feedback_tags: Option<String>,
+    pub feedback_created_at: Option<DateTime<Utc>>,
+    pub feedback_resolved_at: Option<DateTime<Utc>>,
+}
+
+impl NewFeedback {
+    pub fn new(user_email: String, feedback: CreateFeedbackRequestDto, now: DateTime<Utc>) -> Self {
+        Self {
+            feedback_email: Some(user_email),
+            feedback_content: feedback.feedback_content,
+            feedback_app_version: feedback.app_version,
+            feedback_device_info: feedback.device_info,
+            feedback_created_at: Some(now),
+        }
+    }
 }
@@ -1,8 +1,144 @@
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+
+use crate::dto::folder::request::CreateSmartFolderRequestDto;
+
+use super::{error::OmniNewsError, rss::RssItem};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RssFolder {
     pub folder_id: Option<i32>,
     pub folder_name: Option<String>,
     pub user_id: Option<i32>,
+    pub parent_folder_id: Option<i32>,
+    pub folder_order: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromRow)]
+pub struct SmartFolder {
+    pub smart_folder_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub smart_folder_name: Option<String>,
+    pub smart_folder_query: Option<String>,
+    pub smart_folder_keywords: Option<String>,
+    pub smart_folder_channel_ids: Option<String>,
+    pub smart_folder_language: Option<String>,
+    pub smart_folder_days: Option<i32>,
+    pub smart_folder_order: Option<i32>,
+    pub smart_folder_created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NewSmartFolder {
+    pub user_id: Option<i32>,
+    pub smart_folder_name: Option<String>,
+    pub smart_folder_query: Option<String>,
+    pub smart_folder_keywords: Option<String>,
+    pub smart_folder_channel_ids: Option<String>,
+    pub smart_folder_language: Option<String>,
+    pub smart_folder_days: Option<i32>,
+    pub smart_folder_created_at: Option<DateTime<Utc>>,
+}
+
+/// 스마트 폴더 아이템 조회 조건. 비어 있는 조건은 적용하지 않는다.
+#[derive(Clone, Debug, Default)]
+pub struct SmartFolderFilter {
+    /// 의미 검색 결과 (None이면 의미 검색을 하지 않음)
+    pub embedding_ids: Option<Vec<i32>>,
+    pub keywords: Vec<String>,
+    pub channel_ids: Vec<i32>,
+    pub language: Option<String>,
+    pub published_after: Option<DateTime<Utc>>,
+}
+
+/// 타임라인 keyset pagination 커서. `<rss_pub_date unix timestamp>_<rss_id>` 형식
+#[derive(Clone, Copy, Debug)]
+pub struct ItemCursor {
+    pub rss_pub_date: DateTime<Utc>,
+    pub rss_id: i32,
+}
+
+impl SmartFolder {
+    pub fn keywords(&self) -> Vec<String> {
+        split_list(self.smart_folder_keywords.as_deref())
+    }
+
+    pub fn channel_ids(&self) -> Vec<i32> {
+        split_list(self.smart_folder_channel_ids.as_deref())
+            .iter()
+            .filter_map(|id| id.parse().ok())
+            .collect()
+    }
+}
+
+impl NewSmartFolder {
+    pub fn new(user_id: i32, folder: CreateSmartFolderRequestDto, now: DateTime<Utc>) -> Self {
+        Self {
+            user_id: Some(user_id),
+            smart_folder_name: folder.smart_folder_name,
+            smart_folder_query: folder.smart_folder_query,
+            smart_folder_keywords: folder.keywords.map(|keywords| join_list(&keywords)),
+            smart_folder_channel_ids: folder.channel_ids.map(|ids| join_list(&ids)),
+            smart_folder_language: folder.language,
+            smart_folder_days: folder.days,
+            smart_folder_created_at: Some(now),
+        }
+    }
+}
+
+impl ItemCursor {
+    /// 첫 페이지 조회용 커서 (MySQL DATETIME의 최댓값)
+    pub fn first() -> Self {
+        Self {
+            rss_pub_date: NaiveDate::from_ymd_opt(9999, 12, 31)
+                .and_then(|date| date.and_hms_opt(23, 59, 59))
+                .map(|datetime| datetime.and_utc())
+                .unwrap_or_default(),
+            rss_id: i32::MAX,
+        }
+    }
+
+    pub fn from_item(item: &RssItem) -> Self {
+        Self {
+            rss_pub_date: item.rss_pub_date.unwrap_or_default(),
+            rss_id: item.rss_id.unwrap_or_default(),
+        }
+    }
+
+    pub fn parse(cursor: &str) -> Result<Self, OmniNewsError> {
+        let invalid = || OmniNewsError::InvalidRequest(format!("Invalid cursor: {}", cursor));
+
+        let (timestamp, rss_id) = cursor.split_once('_').ok_or_else(invalid)?;
+        let timestamp = timestamp.parse::<i64>().map_err(|_| invalid())?;
+        let rss_id = rss_id.parse::<i32>().map_err(|_| invalid())?;
+        let rss_pub_date = DateTime::from_timestamp(timestamp, 0).ok_or_else(invalid)?;
+
+        Ok(Self {
+            rss_pub_date,
+            rss_id,
+        })
+    }
+
+    pub fn encode(&self) -> String {
+        format!("{}_{}", self.rss_pub_date.timestamp(), self.rss_id)
+    }
+}
+
+fn split_list(value: Option<&str>) -> Vec<String> {
+    value
+        .unwrap_or_default()
+        .split(',')
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+fn join_list<T: ToString>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string().trim().replace(',', " "))
+        .filter(|v| !v.is_empty())
+        .collect::<Vec<_>>()
+        .join(",")
 }
@@ -0,0 +1,28 @@
+use rocket::http::ContentType;
+
+/// `image` 크레이트의 WebP 인코더는 무손실만 지원해 사진이 원본보다 커지므로 JPEG만 사용한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProxiedImageFormat {
+    Jpeg,
+}
+
+/// 리사이즈와 재인코딩을 마친 프록시 이미지
+#[derive(Debug, Clone)]
+pub struct ProxiedImage {
+    pub bytes: Vec<u8>,
+    pub format: ProxiedImageFormat,
+}
+
+impl ProxiedImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ProxiedImageFormat::Jpeg => "jpg",
+        }
+    }
+
+    pub fn content_type(&self) -> ContentType {
+        match self {
+            ProxiedImageFormat::Jpeg => ContentType::JPEG,
+        }
+    }
+}
@@ -3,9 +3,13 @@ pub mod embedding;
 pub mod error;
 pub mod feedback;
 pub mod folder;
+pub mod image;
+pub mod mute;
 pub mod news;
 pub mod omninews_subscription;
 pub mod premium;
+pub mod rate_limit;
 pub mod rss;
 pub mod search;
+pub mod session;
 pub mod user;
@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Duration, Utc};
+use regex::{Regex, RegexBuilder};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+
+use crate::dto::mute::request::CreateMuteRuleRequestDto;
+
+use super::rss::{RssChannel, RssItem};
+
+// 사용자가 입력한 정규식이 과도한 메모리를 쓰지 않도록 제한
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MuteRuleType {
+    /// 제목, 설명에 포함된 키워드 (대소문자 무시)
+    Keyword,
+    /// 제목, 설명에 대한 정규식 (대소문자 무시)
+    Regex,
+    /// rss_author 완전 일치
+    Author,
+    /// 채널 전체 (값은 channel_id)
+    Channel,
+    /// rss_link 도메인과 하위 도메인
+    Domain,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MuteRule {
+    pub mute_rule_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub mute_rule_type: Option<String>,
+    pub mute_rule_value: Option<String>,
+    pub mute_rule_expires_at: Option<DateTime<Utc>>,
+    pub mute_rule_created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewMuteRule {
+    pub user_id: Option<i32>,
+    pub mute_rule_type: Option<String>,
+    pub mute_rule_value: Option<String>,
+    pub mute_rule_expires_at: Option<DateTime<Utc>>,
+    pub mute_rule_created_at: Option<DateTime<Utc>>,
+}
+
+/// 사용자의 활성 뮤트 규칙을 아이템에 바로 적용할 수 있게 모아둔 필터
+#[derive(Debug, Clone, Default)]
+pub struct MuteFilter {
+    keywords: Vec<String>,
+    regexes: Vec<Regex>,
+    authors: HashSet<String>,
+    channel_ids: HashSet<i32>,
+    domains: Vec<String>,
+}
+
+impl MuteRuleType {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "keyword" => Some(Self::Keyword),
+            "regex" => Some(Self::Regex),
+            "author" => Some(Self::Author),
+            "channel" => Some(Self::Channel),
+            "domain" => Some(Self::Domain),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Keyword => "keyword",
+            Self::Regex => "regex",
+            Self::Author => "author",
+            Self::Channel => "channel",
+            Self::Domain => "domain",
+        }
+    }
+}
+
+impl NewMuteRule {
+    pub fn new(
+        user_id: i32,
+        rule_type: MuteRuleType,
+        rule: CreateMuteRuleRequestDto,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            user_id: Some(user_id),
+            mute_rule_type: Some(rule_type.as_str().to_string()),
+            mute_rule_value: rule.mute_rule_value.map(|v| v.trim().to_string()),
+            mute_rule_expires_at: rule.days.map(|days| now + Duration::days(days as i64)),
+            mute_rule_created_at: Some(now),
+        }
+    }
+}
+
+impl MuteFilter {
+    /// 잘못된 규칙(컴파일되지 않는 정규식 등)은 건너뛴다.
+    pub fn from_rules(rules: Vec<MuteRule>) -> Self {
+        let mut filter = Self::default();
+
+        for rule in rules {
+            let Some(value) = rule.mute_rule_value.filter(|v| !v.is_empty()) else {
+                continue;
+            };
+            match rule.mute_rule_type.as_deref().and_then(MuteRuleType::parse) {
+                Some(MuteRuleType::Keyword) => filter.keywords.push(value.to_lowercase()),
+                Some(MuteRuleType::Regex) => {
+                    if let Ok(regex) = Self::compile_regex(&value) {
+                        filter.regexes.push(regex);
+                    }
+                }
+                Some(MuteRuleType::Author) => {
+                    filter.authors.insert(value);
+                }
+                Some(MuteRuleType::Channel) => {
+                    if let Ok(channel_id) = value.parse() {
+                        filter.channel_ids.insert(channel_id);
+                    }
+                }
+                Some(MuteRuleType::Domain) => filter.domains.push(value.to_lowercase()),
+                None => {}
+            }
+        }
+
+        filter
+    }
+
+    pub fn compile_regex(pattern: &str) -> Result<Regex, regex::Error> {
+        RegexBuilder::new(pattern)
+            .case_insensitive(true)
+            .size_limit(REGEX_SIZE_LIMIT)
+            .build()
+    }
+
+    pub fn is_item_muted(&self, item: &RssItem) -> bool {
+        if item
+            .channel_id
+            .is_some_and(|id| self.channel_ids.contains(&id))
+        {
+            return true;
+        }
+        if item
+            .rss_author
+            .as_ref()
+            .is_some_and(|author| self.authors.contains(author))
+        {
+            return true;
+        }
+        if !self.domains.is_empty() && self.is_domain_muted(item.rss_link.as_deref()) {
+            return true;
+        }
+
+        let title = item.rss_title.as_deref().unwrap_or_default();
+        let description = item.rss_description.as_deref().unwrap_or_default();
+        if !self.keywords.is_empty() {
+            let title = title.to_lowercase();
+            let description = description.to_lowercase();
+            if self
+                .keywords
+                .iter()
+                .any(|k| title.contains(k) || description.contains(k))
+            {
+                return true;
+            }
+        }
+
+        self.regexes
+            .iter()
+            .any(|regex| regex.is_match(title) || regex.is_match(description))
+    }
+
+    pub fn is_channel_muted(&self, channel: &RssChannel) -> bool {
+        channel
+            .channel_id
+            .is_some_and(|id| self.is_channel_id_muted(id))
+    }
+
+    pub fn is_channel_id_muted(&self, channel_id: i32) -> bool {
+        self.channel_ids.contains(&channel_id)
+    }
+
+    fn is_domain_muted(&self, link: Option<&str>) -> bool {
+        let Some(host) = link
+            .and_then(|link| Url::parse(link).ok())
+            .and_then(|url| url.host_str().map(|host| host.to_lowercase()))
+        else {
+            return false;
+        };
+
+        self.domains
+            .iter()
+            .any(|domain| host == *domain || host.ends_with(&format!(".{}", domain)))
+    }
+}
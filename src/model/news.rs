@@ -1,6 +1,7 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Utc};
+use sqlx::prelude::FromRow;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, FromRow)]
 pub struct News {
     pub news_id: Option<i32>,
     pub news_title: Option<String>,
@@ -8,7 +9,7 @@ pub struct News {
     pub news_summary: Option<String>,
     pub news_link: Option<String>,
     pub news_source: Option<String>,
-    pub news_pub_date: Option<NaiveDateTime>,
+    pub news_pub_date: Option<DateTime<Utc>>,
     pub news_image_link: Option<String>,
     pub news_category: Option<String>,
 }
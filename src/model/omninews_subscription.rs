@@ -1,4 +1,4 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone)]
 pub struct NewOmniNewsSubscription {
@@ -7,8 +7,8 @@ pub struct NewOmniNewsSubscription {
     pub user_subscription_platform: Option<String>,
     pub user_subscription_plan: Option<bool>,
     pub user_subscription_is_test: Option<bool>,
-    pub user_subscription_start_date: Option<NaiveDateTime>,
-    pub user_subscription_end_date: Option<NaiveDateTime>,
+    pub user_subscription_start_date: Option<DateTime<Utc>>,
+    pub user_subscription_end_date: Option<DateTime<Utc>>,
     pub user_subscription_auto_renew: Option<bool>,
 }
 
@@ -47,10 +47,10 @@ pub struct Receipt {
 */
 #[derive(Debug, Clone)]
 pub struct DecodedReceipt {
-    pub purchase_date: NaiveDateTime,
+    pub purchase_date: DateTime<Utc>,
     pub transaction_id: String,
     pub original_transaction_id: String,
     pub auto_renew: bool,
-    pub expires_date: NaiveDateTime,
+    pub expires_date: DateTime<Utc>,
     pub product_id: String,
 }
@@ -1,2 +1,2 @@
 pub mod rss_generate;
-
+pub mod rss_generate_job;
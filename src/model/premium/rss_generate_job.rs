@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::dto::premium::rss::{
+    request::{RssGenerateByCssReqeustDto, RssGenerateRequestDto},
+    response::RssGenerateResponseDto,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum RssGenerateJobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Clone)]
+pub enum RssGenerateJobKind {
+    Site(RssGenerateRequestDto),
+    Css(RssGenerateByCssReqeustDto),
+}
+
+#[derive(Debug, Clone)]
+pub struct RssGenerateJob {
+    pub job_id: String,
+    pub user_email: String,
+    pub kind: RssGenerateJobKind,
+    pub status: RssGenerateJobStatus,
+    pub result: Option<RssGenerateResponseDto>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RssGenerateJob {
+    pub fn new(
+        job_id: String,
+        user_email: String,
+        kind: RssGenerateJobKind,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            job_id,
+            user_email,
+            kind,
+            status: RssGenerateJobStatus::Queued,
+            result: None,
+            error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.status,
+            RssGenerateJobStatus::Completed | RssGenerateJobStatus::Failed
+        )
+    }
+}
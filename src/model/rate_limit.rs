@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use sqlx::prelude::FromRow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitDecision {
+    Allowed,
+    // 다시 시도할 수 있을 때까지의 초
+    Limited { retry_after: u64 },
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RateLimitBucket {
+    pub bucket_tokens: f64,
+    pub bucket_updated_at: DateTime<Utc>,
+}
+
+impl RateLimitBucket {
+    pub fn full(capacity: f64, now: DateTime<Utc>) -> Self {
+        Self {
+            bucket_tokens: capacity,
+            bucket_updated_at: now,
+        }
+    }
+
+    /// 경과 시간만큼 토큰을 채운 뒤 1개를 꺼냅니다.
+    pub fn take(
+        &mut self,
+        capacity: f64,
+        refill_per_sec: f64,
+        now: DateTime<Utc>,
+    ) -> RateLimitDecision {
+        let elapsed = (now - self.bucket_updated_at).num_milliseconds().max(0) as f64 / 1000.0;
+        self.bucket_tokens = (self.bucket_tokens + elapsed * refill_per_sec).min(capacity);
+        self.bucket_updated_at = now;
+
+        if self.bucket_tokens >= 1.0 {
+            self.bucket_tokens -= 1.0;
+            RateLimitDecision::Allowed
+        } else {
+            let retry_after = ((1.0 - self.bucket_tokens) / refill_per_sec).ceil();
+            RateLimitDecision::Limited {
+                retry_after: retry_after.max(1.0) as u64,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn allows_up_to_capacity_then_limits() {
+        let now = Utc::now();
+        let mut bucket = RateLimitBucket::full(3.0, now);
+
+        for _ in 0..3 {
+            assert_eq!(bucket.take(3.0, 1.0, now), RateLimitDecision::Allowed);
+        }
+        assert_eq!(
+            bucket.take(3.0, 1.0, now),
+            RateLimitDecision::Limited { retry_after: 1 }
+        );
+    }
+
+    #[test]
+    fn refills_by_elapsed_time() {
+        let now = Utc::now();
+        let mut bucket = RateLimitBucket::full(2.0, now);
+        bucket.take(2.0, 0.5, now);
+        bucket.take(2.0, 0.5, now);
+
+        // 0.5개/초 → 2초 뒤에 1개
+        assert_eq!(
+            bucket.take(2.0, 0.5, now + Duration::seconds(1)),
+            RateLimitDecision::Limited { retry_after: 1 }
+        );
+        assert_eq!(
+            bucket.take(2.0, 0.5, now + Duration::seconds(2)),
+            RateLimitDecision::Allowed
+        );
+    }
+
+    #[test]
+    fn does_not_refill_beyond_capacity() {
+        let now = Utc::now();
+        let mut bucket = RateLimitBucket::full(2.0, now);
+
+        bucket.take(2.0, 1.0, now + Duration::hours(1));
+
+        assert_eq!(bucket.bucket_tokens, 1.0);
+    }
+
+    #[test]
+    fn retry_after_is_time_until_next_token() {
+        let now = Utc::now();
+        // 0.25개/초 → 1개당 4초
+        let mut bucket = RateLimitBucket::full(2.0, now);
+        bucket.take(2.0, 0.25, now);
+        bucket.take(2.0, 0.25, now);
+
+        assert_eq!(
+            bucket.take(2.0, 0.25, now),
+            RateLimitDecision::Limited { retry_after: 4 }
+        );
+        assert_eq!(
+            bucket.take(2.0, 0.25, now + Duration::seconds(1)),
+            RateLimitDecision::Limited { retry_after: 3 }
+        );
+    }
+}
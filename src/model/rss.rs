@@ -1,4 +1,4 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Utc};
 use rss::Item;
 use sqlx::prelude::FromRow;
 
@@ -12,6 +12,7 @@ pub struct NewRssChannel {
     pub rss_generator: Option<String>,
     pub channel_rank: Option<i32>,
     pub channel_rss_link: Option<String>,
+    pub channel_feed_image_url: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -25,6 +26,8 @@ pub struct RssChannel {
     pub rss_generator: Option<String>,
     pub channel_rank: Option<i32>,
     pub channel_rss_link: Option<String>,
+    // 마지막으로 본 피드 이미지 주소 (channel_image_url은 사이트 아이콘으로 대체될 수 있다)
+    pub channel_feed_image_url: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -34,7 +37,7 @@ pub struct NewRssItem {
     pub rss_description: Option<String>,
     pub rss_link: Option<String>,
     pub rss_author: Option<String>,
-    pub rss_pub_date: Option<NaiveDateTime>,
+    pub rss_pub_date: Option<DateTime<Utc>>,
     pub rss_rank: Option<i32>,
     pub rss_image_link: Option<String>,
 }
@@ -47,7 +50,7 @@ pub struct RssItem {
     pub rss_description: Option<String>,
     pub rss_link: Option<String>,
     pub rss_author: Option<String>,
-    pub rss_pub_date: Option<NaiveDateTime>,
+    pub rss_pub_date: Option<DateTime<Utc>>,
     pub rss_rank: Option<i32>,
     pub rss_image_link: Option<String>,
 }
@@ -68,6 +71,7 @@ impl NewRssChannel {
             channel_title: Some(channel_title),
             channel_link: Some(channel_link),
             channel_description: Some(channel_description),
+            channel_feed_image_url: channel_image_url.clone(),
             channel_image_url,
             channel_language: Some(channel_language),
             rss_generator: Some(rss_generator),
@@ -89,6 +93,7 @@ impl RssChannel {
             rss_generator: new_channel.rss_generator,
             channel_rank: new_channel.channel_rank,
             channel_rss_link: new_channel.channel_rss_link,
+            channel_feed_image_url: new_channel.channel_feed_image_url,
         }
     }
 }
@@ -97,7 +102,7 @@ impl NewRssItem {
     pub fn new(
         channel_id: i32,
         item: &Item,
-        rss_pub_date: Option<NaiveDateTime>,
+        rss_pub_date: Option<DateTime<Utc>>,
         item_image_link: String,
     ) -> Self {
         Self {
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use sqlx::prelude::FromRow;
+
+use super::auth::JwtToken;
+
+#[derive(Debug, Clone)]
+pub struct NewUserSession {
+    pub session_id: Option<String>,
+    pub user_id: Option<i32>,
+    pub session_device_id: Option<String>,
+    pub session_access_token: Option<String>,
+    pub session_refresh_token: Option<String>,
+    pub session_access_token_expires_at: Option<DateTime<Utc>>,
+    pub session_refresh_token_expires_at: Option<DateTime<Utc>>,
+    pub session_created_at: Option<DateTime<Utc>>,
+    pub session_last_used_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct UserSession {
+    pub session_id: Option<String>,
+    pub user_id: Option<i32>,
+    pub session_device_id: Option<String>,
+    pub session_access_token: Option<String>,
+    pub session_refresh_token: Option<String>,
+    pub session_access_token_expires_at: Option<DateTime<Utc>>,
+    pub session_refresh_token_expires_at: Option<DateTime<Utc>>,
+    pub session_rotation_count: Option<i32>,
+    pub session_revoked_at: Option<DateTime<Utc>>,
+    pub session_created_at: Option<DateTime<Utc>>,
+    pub session_last_used_at: Option<DateTime<Utc>>,
+}
+
+impl NewUserSession {
+    pub fn new(
+        session_id: String,
+        user_id: i32,
+        session_device_id: Option<String>,
+        token: JwtToken,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            session_id: Some(session_id),
+            user_id: Some(user_id),
+            session_device_id,
+            session_access_token: token.access_token,
+            session_refresh_token: token.refresh_token,
+            session_access_token_expires_at: token.access_token_expires_at,
+            session_refresh_token_expires_at: token.refresh_token_expires_at,
+            session_created_at: Some(now),
+            session_last_used_at: Some(now),
+        }
+    }
+}
@@ -0,0 +1,330 @@
+use std::{collections::HashMap, env, sync::Mutex, time::Duration};
+
+use chrono::{DateTime, Utc};
+use rocket::{
+    fairing::{Fairing, Info, Kind},
+    http::{uri::Origin, Header, Method, Status},
+    Data, Request, Response,
+};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthCache,
+    model::rate_limit::{RateLimitBucket, RateLimitDecision},
+    repository::rate_limit_repository,
+    server_error, server_info, server_warn,
+};
+
+// 제한된 요청은 이 경로로 바꿔서 실제 핸들러가 실행되지 않도록 한다.
+pub const RATE_LIMITED_PATH: &str = "/rate-limited";
+
+// 메모리 버킷이 이 개수를 넘으면 오래된 버킷을 정리한다.
+const MEMORY_BUCKET_SOFT_LIMIT: usize = 10_000;
+
+/// 라우트 그룹별 token bucket 설정
+///
+/// `capacity`개까지 연속으로 요청할 수 있고, `period` 동안 `capacity`개가 다시 채워진다.
+/// `method`가 있으면 해당 메서드의 요청에만 적용된다.
+#[derive(Debug, Clone)]
+pub struct RateLimitRule {
+    pub group: &'static str,
+    pub method: Option<Method>,
+    pub path_prefixes: Vec<String>,
+    pub capacity: f64,
+    pub period: Duration,
+}
+
+impl RateLimitRule {
+    fn new(group: &'static str, path_prefixes: &[&str], capacity: u32, period_secs: u64) -> Self {
+        Self {
+            group,
+            method: None,
+            path_prefixes: path_prefixes.iter().map(|p| p.to_string()).collect(),
+            capacity: capacity as f64,
+            period: Duration::from_secs(period_secs),
+        }
+    }
+
+    fn with_method(mut self, method: Method) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// `RATE_LIMIT_<GROUP>=<capacity>/<period_secs>` 로 기본값을 덮어씁니다. (예: `RATE_LIMIT_SEARCH=30/60`)
+    ///
+    /// capacity가 0이면 해당 그룹은 제한하지 않습니다.
+    fn with_env_override(mut self) -> Self {
+        let key = format!("RATE_LIMIT_{}", self.group.to_uppercase());
+        let Ok(value) = env::var(&key) else {
+            return self;
+        };
+
+        match value
+            .split_once('/')
+            .map(|(c, p)| (c.trim().parse::<u32>(), p.trim().parse::<u64>()))
+        {
+            Some((Ok(capacity), Ok(period_secs))) if period_secs > 0 => {
+                self.capacity = capacity as f64;
+                self.period = Duration::from_secs(period_secs);
+            }
+            _ => server_warn!("[RateLimit] Invalid {} value: {}", key, value),
+        }
+        self
+    }
+
+    fn refill_per_sec(&self) -> f64 {
+        self.capacity / self.period.as_secs_f64()
+    }
+
+    fn matches(&self, method: Method, path: &str) -> bool {
+        self.method.is_none_or(|m| m == method)
+            && self
+                .path_prefixes
+                .iter()
+                .any(|prefix| path.starts_with(prefix))
+    }
+}
+
+fn default_rules() -> Vec<RateLimitRule> {
+    // 위에서부터 먼저 일치하는 그룹이 적용된다.
+    vec![
+        // Naver API 쿼터 사용
+        RateLimitRule::new("news_api", &["/v1/api/search/news_api"], 10, 60),
+        // 요청마다 임베딩 모델 실행
+        RateLimitRule::new("search", &["/v1/api/search/"], 30, 60),
+        // 외부 피드 fetch (같은 그룹이므로 버킷을 함께 쓴다. GET /rss/channel은 조회라 제외)
+        RateLimitRule::new(
+            "rss_fetch",
+            &["/v1/api/rss/all", "/v1/api/rss/channel"],
+            20,
+            60,
+        )
+        .with_method(Method::Post),
+        RateLimitRule::new("rss_fetch", &["/v1/api/rss/preview"], 20, 60).with_method(Method::Get),
+        RateLimitRule::new("premium", &["/v1/api/premium/"], 10, 60),
+        RateLimitRule::new(
+            "auth",
+            &[
+                "/v1/api/user/login",
+                "/v1/api/user/apple/login",
+                "/v1/api/user/refresh-token",
+            ],
+            20,
+            60,
+        ),
+        // 이미지 프록시는 인증 없이 IP로 구분되고 화면마다 여러 장을 불러오므로 넉넉하게 둔다.
+        RateLimitRule::new("image", &["/v1/api/img/"], 1200, 60),
+        RateLimitRule::new("default", &["/v1/api/"], 300, 60),
+    ]
+    .into_iter()
+    .map(RateLimitRule::with_env_override)
+    .collect()
+}
+
+#[rocket::async_trait]
+pub trait RateLimitBackend: Send + Sync {
+    async fn take(&self, key: &str, rule: &RateLimitRule, now: DateTime<Utc>) -> RateLimitDecision;
+}
+
+/// 서버 인스턴스 하나에서만 유효한 메모리 버킷
+pub struct MemoryRateLimitBackend {
+    buckets: Mutex<HashMap<String, RateLimitBucket>>,
+}
+
+impl MemoryRateLimitBackend {
+    pub fn new() -> Self {
+        Self {
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl RateLimitBackend for MemoryRateLimitBackend {
+    async fn take(&self, key: &str, rule: &RateLimitRule, now: DateTime<Utc>) -> RateLimitDecision {
+        let mut buckets = self.buckets.lock().unwrap();
+
+        if buckets.len() > MEMORY_BUCKET_SOFT_LIMIT {
+            // 가득 찰 만큼 시간이 지난 버킷은 새로 만든 것과 같으므로 지워도 된다.
+            let period = chrono::Duration::from_std(rule.period).unwrap_or_default();
+            buckets.retain(|_, bucket| now - bucket.bucket_updated_at < period);
+        }
+
+        buckets
+            .entry(key.to_string())
+            .or_insert_with(|| RateLimitBucket::full(rule.capacity, now))
+            .take(rule.capacity, rule.refill_per_sec(), now)
+    }
+}
+
+/// 여러 서버 인스턴스가 버킷을 공유하는 MySQL 백엔드
+pub struct MySqlRateLimitBackend {
+    pool: MySqlPool,
+}
+
+impl MySqlRateLimitBackend {
+    pub fn new(pool: MySqlPool) -> Self {
+        let cleanup_pool = pool.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(60 * 10));
+            loop {
+                interval.tick().await;
+                let threshold = Utc::now() - chrono::Duration::hours(1);
+                if let Err(e) =
+                    rate_limit_repository::delete_buckets_updated_before(&cleanup_pool, threshold)
+                        .await
+                {
+                    server_error!("[RateLimit] Failed to clean up rate limit buckets: {}", e);
+                }
+            }
+        });
+
+        Self { pool }
+    }
+}
+
+#[rocket::async_trait]
+impl RateLimitBackend for MySqlRateLimitBackend {
+    async fn take(&self, key: &str, rule: &RateLimitRule, now: DateTime<Utc>) -> RateLimitDecision {
+        match rate_limit_repository::take_token(
+            &self.pool,
+            key,
+            rule.capacity,
+            rule.refill_per_sec(),
+            now,
+        )
+        .await
+        {
+            Ok(decision) => decision,
+            Err(e) => {
+                // 공유 저장소 장애로 전체 API가 막히지 않도록 통과시킨다.
+                server_error!("[RateLimit] Failed to take rate limit token: {}", e);
+                RateLimitDecision::Allowed
+            }
+        }
+    }
+}
+
+// on_request에서 결정한 결과를 on_response로 전달
+struct RateLimitState(Option<u64>);
+
+pub struct RateLimiter {
+    rules: Vec<RateLimitRule>,
+    backend: Box<dyn RateLimitBackend>,
+}
+
+impl RateLimiter {
+    /// `RATE_LIMIT_BACKEND=mysql` 이면 공유 백엔드, 아니면 메모리 백엔드를 사용합니다.
+    pub fn from_env(pool: MySqlPool) -> Self {
+        let backend: Box<dyn RateLimitBackend> =
+            match env::var("RATE_LIMIT_BACKEND").unwrap_or_default().as_str() {
+                "mysql" => Box::new(MySqlRateLimitBackend::new(pool)),
+                _ => Box::new(MemoryRateLimitBackend::new()),
+            };
+
+        Self {
+            rules: default_rules(),
+            backend,
+        }
+    }
+
+    #[allow(clippy::redundant_closure)]
+    fn client_key(req: &Request<'_>) -> String {
+        // AuthMiddleware가 먼저 실행되어 인증된 사용자는 이메일로 구분한다.
+        let request_id = req.local_cache(|| String::new());
+        let user_email = req
+            .rocket()
+            .state::<AuthCache>()
+            .and_then(|cache| cache.user_emails.read().unwrap().get(request_id).cloned());
+
+        match user_email {
+            Some(email) => format!("user:{}", email),
+            None => format!(
+                "ip:{}",
+                req.client_ip()
+                    .map(|ip| ip.to_string())
+                    .unwrap_or_else(|| "unknown".to_string())
+            ),
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for RateLimiter {
+    fn info(&self) -> Info {
+        Info {
+            name: "Token bucket rate limiting",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, _: &mut Data<'_>) {
+        if req.method() == Method::Options {
+            return;
+        }
+
+        let path = req.uri().path().as_str().to_string();
+        let Some(rule) = self
+            .rules
+            .iter()
+            .find(|rule| rule.matches(req.method(), &path))
+        else {
+            return;
+        };
+        if rule.capacity <= 0.0 {
+            return;
+        }
+
+        let key = format!("{}:{}", rule.group, Self::client_key(req));
+        let decision = self.backend.take(&key, rule, Utc::now()).await;
+
+        if let RateLimitDecision::Limited { retry_after } = decision {
+            server_info!("[RateLimit] Too many requests: {} {}", key, path);
+            req.local_cache(|| RateLimitState(Some(retry_after)));
+            req.set_method(Method::Get);
+            req.set_uri(Origin::parse(RATE_LIMITED_PATH).unwrap());
+        }
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        if let RateLimitState(Some(retry_after)) = req.local_cache(|| RateLimitState(None)) {
+            res.set_status(Status::TooManyRequests);
+            res.set_header(Header::new("Retry-After", retry_after.to_string()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group_of(method: Method, path: &str) -> Option<&'static str> {
+        default_rules()
+            .into_iter()
+            .find(|rule| rule.matches(method, path))
+            .map(|rule| rule.group)
+    }
+
+    #[test]
+    fn matches_rule_by_method_and_path() {
+        assert_eq!(
+            group_of(Method::Post, "/v1/api/rss/channel"),
+            Some("rss_fetch")
+        );
+        assert_eq!(group_of(Method::Post, "/v1/api/rss/all"), Some("rss_fetch"));
+        assert_eq!(
+            group_of(Method::Get, "/v1/api/rss/preview"),
+            Some("rss_fetch")
+        );
+        assert_eq!(
+            group_of(Method::Get, "/v1/api/rss/channel"),
+            Some("default")
+        );
+        assert_eq!(
+            group_of(Method::Get, "/v1/api/search/news_api"),
+            Some("news_api")
+        );
+        assert_eq!(group_of(Method::Get, "/v1/api/img/abc"), Some("image"));
+        assert_eq!(group_of(Method::Get, "/health"), None);
+    }
+}
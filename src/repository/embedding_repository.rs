@@ -1,6 +1,12 @@
 use sqlx::{query, MySqlPool};
 
-use crate::{db_util::get_db, model::embedding::NewEmbedding};
+use crate::{
+    db_util::get_db,
+    model::embedding::{EmbeddingTarget, EmbeddingVersion, NewEmbedding},
+};
+
+// 한 INSERT 문에 넣는 최대 행 수 (자리표시자 개수 제한 65535)
+const UPSERT_CHUNK_SIZE: usize = 500;
 
 pub async fn insert_embedding(
     pool: &MySqlPool,
@@ -9,12 +15,18 @@ pub async fn insert_embedding(
     let mut conn = get_db(pool).await?;
 
     let result = query!(
-    "INSERT INTO embedding (embedding_value, channel_id, rss_id, news_id, embedding_source_rank) VALUES (?, ?, ?, ?, ?);",
+        "INSERT INTO embedding
+            (embedding_value, channel_id, rss_id, news_id, embedding_source_rank,
+            embedding_model_id, embedding_dim, embedding_text_recipe_version)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?);",
         embedding.embedding_value,
         embedding.channel_id,
         embedding.rss_id,
         embedding.news_id,
         embedding.embedding_source_rank,
+        embedding.embedding_model_id,
+        embedding.embedding_dim,
+        embedding.embedding_text_recipe_version,
     )
     .execute(&mut *conn)
     .await;
@@ -24,3 +36,135 @@ pub async fn insert_embedding(
         Err(e) => Err(e),
     }
 }
+
+/// 같은 channel_id/rss_id/news_id의 임베딩이 있으면 값과 버전을 교체합니다.
+pub async fn upsert_embedding(
+    pool: &MySqlPool,
+    embedding: NewEmbedding,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO embedding
+            (embedding_value, channel_id, rss_id, news_id, embedding_source_rank,
+            embedding_model_id, embedding_dim, embedding_text_recipe_version)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        ON DUPLICATE KEY UPDATE
+            embedding_value = VALUES(embedding_value),
+            embedding_model_id = VALUES(embedding_model_id),
+            embedding_dim = VALUES(embedding_dim),
+            embedding_text_recipe_version = VALUES(embedding_text_recipe_version)",
+        embedding.embedding_value,
+        embedding.channel_id,
+        embedding.rss_id,
+        embedding.news_id,
+        embedding.embedding_source_rank,
+        embedding.embedding_model_id,
+        embedding.embedding_dim,
+        embedding.embedding_text_recipe_version,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 여러 임베딩을 한 트랜잭션으로 교체합니다. (없으면 생성)
+pub async fn upsert_embeddings(
+    pool: &MySqlPool,
+    embeddings: Vec<NewEmbedding>,
+) -> Result<u64, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let mut affected = 0;
+
+    for chunk in embeddings.chunks(UPSERT_CHUNK_SIZE) {
+        let query = format!(
+            "INSERT INTO embedding
+                (embedding_value, channel_id, rss_id, news_id, embedding_source_rank,
+                embedding_model_id, embedding_dim, embedding_text_recipe_version)
+            VALUES {}
+            ON DUPLICATE KEY UPDATE
+                embedding_value = VALUES(embedding_value),
+                embedding_model_id = VALUES(embedding_model_id),
+                embedding_dim = VALUES(embedding_dim),
+                embedding_text_recipe_version = VALUES(embedding_text_recipe_version)",
+            vec!["(?, ?, ?, ?, ?, ?, ?, ?)"; chunk.len()].join(", ")
+        );
+        let mut query_builder = sqlx::query(&query);
+        for embedding in chunk {
+            query_builder = query_builder
+                .bind(&embedding.embedding_value)
+                .bind(embedding.channel_id)
+                .bind(embedding.rss_id)
+                .bind(embedding.news_id)
+                .bind(embedding.embedding_source_rank)
+                .bind(&embedding.embedding_model_id)
+                .bind(embedding.embedding_dim)
+                .bind(embedding.embedding_text_recipe_version);
+        }
+        affected += query_builder.execute(&mut *tx).await?.rows_affected();
+    }
+
+    tx.commit().await?;
+    Ok(affected)
+}
+
+/// 대상의 임베딩 중 모델이 같은 것에서 가장 많은 문장 형식 버전과 차원을 조회합니다.
+pub async fn select_dominant_embedding_version(
+    pool: &MySqlPool,
+    target: EmbeddingTarget,
+    model_id: &str,
+) -> Result<Option<(i32, i32)>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let query = format!(
+        "SELECT embedding_text_recipe_version, embedding_dim FROM embedding
+        WHERE {} IS NOT NULL
+            AND embedding_model_id = ?
+            AND embedding_text_recipe_version IS NOT NULL
+            AND embedding_dim IS NOT NULL
+        GROUP BY embedding_text_recipe_version, embedding_dim
+        ORDER BY COUNT(*) DESC
+        LIMIT 1",
+        target.column()
+    );
+
+    sqlx::query_as::<_, (i32, i32)>(&query)
+        .bind(model_id)
+        .fetch_optional(&mut *conn)
+        .await
+}
+
+/// 같은 버전과 차원으로 만든 대상의 임베딩을 cursor 다음 ID부터 limit개 조회합니다.
+pub async fn select_embeddings_by_version(
+    pool: &MySqlPool,
+    target: EmbeddingTarget,
+    version: &EmbeddingVersion,
+    dim: i32,
+    cursor: i32,
+    limit: i64,
+) -> Result<Vec<(i32, Vec<u8>)>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let query = format!(
+        "SELECT embedding_id, embedding_value FROM embedding
+        WHERE {} IS NOT NULL
+            AND embedding_model_id = ?
+            AND embedding_text_recipe_version = ?
+            AND embedding_dim = ?
+            AND embedding_id > ?
+        ORDER BY embedding_id
+        LIMIT ?",
+        target.column()
+    );
+
+    sqlx::query_as::<_, (i32, Vec<u8>)>(&query)
+        .bind(&version.model_id)
+        .bind(version.text_recipe_version)
+        .bind(dim)
+        .bind(cursor)
+        .bind(limit)
+        .fetch_all(&mut *conn)
+        .await
+}
@@ -0,0 +1,132 @@
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::feedback::{Feedback, NewFeedback},
+};
+
+pub async fn insert_feedback(pool: &MySqlPool, feedback: NewFeedback) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO feedback
+            (feedback_email, feedback_content, feedback_app_version, feedback_device_info,
+            feedback_created_at)
+        VALUES (?, ?, ?, ?, ?)",
+        feedback.feedback_email,
+        feedback.feedback_content,
+        feedback.feedback_app_version,
+        feedback.feedback_device_info,
+        feedback.feedback_created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_feedbacks(
+    pool: &MySqlPool,
+    feedback_status: Option<String>,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<Feedback>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as::<_, Feedback>(
+        "SELECT * FROM feedback
+            WHERE ? IS NULL OR feedback_status = ?
+        ORDER BY feedback_id DESC
+        LIMIT ? OFFSET ?",
+    )
+    .bind(feedback_status.clone())
+    .bind(feedback_status)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_feedback_tags(
+    pool: &MySqlPool,
+    feedback_id: i32,
+    feedback_tags: String,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE feedback SET feedback_tags = ? WHERE feedback_id = ?",
+        feedback_tags,
+        feedback_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => {
+            if res.rows_affected() > 0 {
+                Ok(feedback_id)
+            } else {
+                Err(sqlx::Error::RowNotFound)
+            }
+        }
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_feedback_resolved(
+    pool: &MySqlPool,
+    feedback_id: i32,
+    resolved_at: DateTime<Utc>,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE feedback
+            SET feedback_status = 'resolved', feedback_resolved_at = ?
+        WHERE feedback_id = ?",
+        resolved_at,
+        feedback_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => {
+            if res.rows_affected() > 0 {
+                Ok(feedback_id)
+            } else {
+                Err(sqlx::Error::RowNotFound)
+            }
+        }
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_feedbacks_by_email(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<Vec<Feedback>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as::<_, Feedback>(
+        "SELECT * FROM feedback WHERE feedback_email = ? ORDER BY feedback_id DESC",
+    )
+    .bind(user_email)
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
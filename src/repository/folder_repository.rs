@@ -2,19 +2,24 @@ use sqlx::MySqlPool;
 
 use crate::{
     db_util::get_db,
-    model::{folder::RssFolder, rss::RssChannel},
+    model::{
+        folder::{ItemCursor, RssFolder},
+        rss::{RssChannel, RssItem},
+    },
 };
 
 pub async fn insert_folder(
     pool: &MySqlPool,
     user_id: i32,
     folder_name: String,
+    parent_folder_id: Option<i32>,
 ) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
     let result = sqlx::query!(
-        "INSERT INTO rss_folder (user_id, folder_name) VALUES (?, ?)",
+        "INSERT INTO rss_folder (user_id, folder_name, parent_folder_id) VALUES (?, ?, ?)",
         user_id,
-        folder_name
+        folder_name,
+        parent_folder_id
     )
     .execute(&mut *conn)
     .await;
@@ -49,7 +54,7 @@ pub async fn select_folders(pool: &MySqlPool, user_id: i32) -> Result<Vec<RssFol
     let mut conn = get_db(pool).await?;
     let result = sqlx::query_as!(
         RssFolder,
-        "SELECT * FROM rss_folder WHERE user_id = ?",
+        "SELECT * FROM rss_folder WHERE user_id = ? ORDER BY folder_order, folder_id",
         user_id
     )
     .fetch_all(&mut *conn)
@@ -70,7 +75,8 @@ pub async fn select_channels_in_folder(
         RssChannel,
         "SELECT rc.* FROM rss_channel rc 
          JOIN channels_in_folder cic ON rc.channel_id = cic.channel_id 
-         WHERE cic.folder_id = ?",
+         WHERE cic.folder_id = ?
+         ORDER BY cic.channel_order, cic.channels_in_folder_id",
         folder_id
     )
     .fetch_all(&mut *conn)
@@ -82,16 +88,96 @@ pub async fn select_channels_in_folder(
     }
 }
 
+pub async fn select_folder_by_id(
+    pool: &MySqlPool,
+    folder_id: i32,
+) -> Result<RssFolder, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = sqlx::query_as!(
+        RssFolder,
+        "SELECT * FROM rss_folder WHERE folder_id = ?",
+        folder_id
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn count_child_folders(pool: &MySqlPool, folder_id: i32) -> Result<i64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = sqlx::query!(
+        "SELECT COUNT(*) AS count FROM rss_folder WHERE parent_folder_id = ?",
+        folder_id
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.count),
+        Err(e) => Err(e),
+    }
+}
+
+/// 폴더와 하위 폴더에 담긴 채널의 아이템을 (rss_pub_date, rss_id) 내림차순으로 조회한다.
+///
+/// `cursor` 보다 이전 아이템만 반환한다. (keyset pagination)
+pub async fn select_items_in_folder(
+    pool: &MySqlPool,
+    folder_id: i32,
+    cursor: ItemCursor,
+    limit: i64,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = sqlx::query_as::<_, RssItem>(
+        "SELECT ri.* FROM rss_item ri
+         WHERE ri.channel_id IN (
+             SELECT cif.channel_id FROM channels_in_folder cif
+             JOIN rss_folder rf ON cif.folder_id = rf.folder_id
+             WHERE rf.folder_id = ? OR rf.parent_folder_id = ?
+         )
+         AND ri.rss_pub_date IS NOT NULL
+         AND (ri.rss_pub_date < ? OR (ri.rss_pub_date = ? AND ri.rss_id < ?))
+         ORDER BY ri.rss_pub_date DESC, ri.rss_id DESC
+         LIMIT ?",
+    )
+    .bind(folder_id)
+    .bind(folder_id)
+    .bind(cursor.rss_pub_date)
+    .bind(cursor.rss_pub_date)
+    .bind(cursor.rss_id)
+    .bind(limit)
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// `update_parent`가 false면 상위 폴더는 그대로 둡니다.
 pub async fn update_folder(
     pool: &MySqlPool,
+    user_id: i32,
     folder_id: i32,
-    folder_name: String,
+    folder_name: Option<String>,
+    update_parent: bool,
+    parent_folder_id: Option<i32>,
 ) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
     let result = sqlx::query!(
-        "UPDATE rss_folder SET folder_name = ? WHERE folder_id = ?",
+        "UPDATE rss_folder
+        SET folder_name = COALESCE(?, folder_name), parent_folder_id = IF(?, ?, parent_folder_id)
+        WHERE folder_id = ? AND user_id = ?",
         folder_name,
-        folder_id
+        update_parent,
+        parent_folder_id,
+        folder_id,
+        user_id
     )
     .execute(&mut *conn)
     .await;
@@ -108,34 +194,60 @@ pub async fn update_folder(
     }
 }
 
-pub async fn delete_folder(pool: &MySqlPool, folder_id: i32) -> Result<(), sqlx::Error> {
-    let mut conn = get_db(pool).await?;
-    let result = sqlx::query!("DELETE FROM rss_folder WHERE folder_id = ?", folder_id)
-        .execute(&mut *conn)
-        .await;
+pub async fn delete_folder(
+    pool: &MySqlPool,
+    user_id: i32,
+    folder_id: i32,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
 
-    match result {
-        Ok(res) => {
-            if res.rows_affected() > 0 {
-                Ok(())
-            } else {
-                Err(sqlx::Error::RowNotFound)
-            }
-        }
-        Err(e) => Err(e),
+    sqlx::query!(
+        "DELETE cif FROM channels_in_folder cif
+            JOIN rss_folder rf ON cif.folder_id = rf.folder_id
+        WHERE rf.folder_id = ? AND rf.user_id = ?",
+        folder_id,
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    // 하위 폴더는 최상위 폴더로 올린다.
+    sqlx::query!(
+        "UPDATE rss_folder SET parent_folder_id = NULL WHERE parent_folder_id = ? AND user_id = ?",
+        folder_id,
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    let result = sqlx::query!(
+        "DELETE FROM rss_folder WHERE folder_id = ? AND user_id = ?",
+        folder_id,
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound);
     }
+
+    tx.commit().await?;
+    Ok(())
 }
 
 pub async fn delete_channel_from_folder(
     pool: &MySqlPool,
+    user_id: i32,
     folder_id: i32,
     channel_id: i32,
 ) -> Result<(), sqlx::Error> {
     let mut conn = get_db(pool).await?;
     let result = sqlx::query!(
-        "DELETE FROM channels_in_folder WHERE folder_id = ? AND channel_id = ?",
+        "DELETE cif FROM channels_in_folder cif
+            JOIN rss_folder rf ON cif.folder_id = rf.folder_id
+        WHERE cif.folder_id = ? AND cif.channel_id = ? AND rf.user_id = ?",
         folder_id,
-        channel_id
+        channel_id,
+        user_id
     )
     .execute(&mut *conn)
     .await;
@@ -151,3 +263,49 @@ pub async fn delete_channel_from_folder(
         Err(e) => Err(e),
     }
 }
+
+/// `folder_ids` 순서대로 folder_order를 0부터 다시 매긴다.
+pub async fn update_folder_orders(
+    pool: &MySqlPool,
+    user_id: i32,
+    folder_ids: Vec<i32>,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    for (order, folder_id) in folder_ids.into_iter().enumerate() {
+        sqlx::query!(
+            "UPDATE rss_folder SET folder_order = ? WHERE folder_id = ? AND user_id = ?",
+            order as i32,
+            folder_id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// `channel_ids` 순서대로 폴더 안의 channel_order를 0부터 다시 매긴다.
+pub async fn update_channel_orders(
+    pool: &MySqlPool,
+    folder_id: i32,
+    channel_ids: Vec<i32>,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    for (order, channel_id) in channel_ids.into_iter().enumerate() {
+        sqlx::query!(
+            "UPDATE channels_in_folder SET channel_order = ? WHERE folder_id = ? AND channel_id = ?",
+            order as i32,
+            folder_id,
+            channel_id
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
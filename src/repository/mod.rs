@@ -1,8 +1,14 @@
 pub mod embedding_repository;
+pub mod feedback_repository;
 pub mod folder_repository;
+pub mod mute_repository;
 pub mod news_repository;
 pub mod omninews_subscription_repository;
+pub mod rate_limit_repository;
+pub mod reembedding_job_repository;
 pub mod rss_channel_repository;
 pub mod rss_item_repository;
+pub mod smart_folder_repository;
 pub mod subscribe_repository;
 pub mod user_repository;
+pub mod user_session_repository;
@@ -0,0 +1,119 @@
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::mute::{MuteRule, NewMuteRule},
+};
+
+pub async fn insert_mute_rule(pool: &MySqlPool, rule: NewMuteRule) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO mute_rule
+            (user_id, mute_rule_type, mute_rule_value, mute_rule_expires_at, mute_rule_created_at)
+        VALUES (?, ?, ?, ?, ?)",
+        rule.user_id,
+        rule.mute_rule_type,
+        rule.mute_rule_value,
+        rule.mute_rule_expires_at,
+        rule.mute_rule_created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+/// 만료되지 않은 규칙만 조회한다.
+pub async fn select_active_mute_rules(
+    pool: &MySqlPool,
+    user_id: i32,
+    now: DateTime<Utc>,
+) -> Result<Vec<MuteRule>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as::<_, MuteRule>(
+        "SELECT * FROM mute_rule
+            WHERE user_id = ? AND (mute_rule_expires_at IS NULL OR mute_rule_expires_at > ?)
+        ORDER BY mute_rule_id DESC",
+    )
+    .bind(user_id)
+    .bind(now)
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 만료된 규칙까지 포함해 사용자의 모든 규칙을 조회합니다. (개인정보 내보내기)
+pub async fn select_mute_rules_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<MuteRule>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as::<_, MuteRule>(
+        "SELECT * FROM mute_rule WHERE user_id = ? ORDER BY mute_rule_id DESC",
+    )
+    .bind(user_id)
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_mute_rule(
+    pool: &MySqlPool,
+    user_id: i32,
+    mute_rule_id: i32,
+) -> Result<(), sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM mute_rule WHERE mute_rule_id = ? AND user_id = ?",
+        mute_rule_id,
+        user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => {
+            if res.rows_affected() > 0 {
+                Ok(())
+            } else {
+                Err(sqlx::Error::RowNotFound)
+            }
+        }
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_mute_rules_expired_before(
+    pool: &MySqlPool,
+    threshold: DateTime<Utc>,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM mute_rule WHERE mute_rule_expires_at <= ?",
+        threshold
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
@@ -8,11 +8,10 @@ pub async fn select_news_by_category(
 ) -> Result<Vec<News>, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
-    let result = query_as!(
-        News,
+    let result = query_as::<_, News>(
         "SELECT * from news WHERE news_category=? ORDER BY news_pub_date DESC LIMIT 100",
-        category,
     )
+    .bind(category)
     .fetch_all(&mut *conn)
     .await;
 
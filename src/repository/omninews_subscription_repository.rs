@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use sqlx::{query, query_as, MySqlPool};
 
 use crate::{
@@ -9,11 +10,10 @@ pub async fn verify_subscription(
     pool: &sqlx::MySqlPool,
     user_email: &str,
 ) -> Result<User, sqlx::Error> {
-    let result = query_as!(
-        User,
+    let result = query_as::<_, User>(
         "SELECT * FROM user WHERE user_email = ? AND user_subscription_plan = 1",
-        user_email
     )
+    .bind(user_email)
     .fetch_one(pool)
     .await;
 
@@ -65,3 +65,49 @@ pub async fn register_subscription(
         Err(e) => Err(e),
     }
 }
+
+pub async fn select_subscriptions_expiring_before(
+    pool: &MySqlPool,
+    threshold: DateTime<Utc>,
+) -> Result<Vec<User>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as::<_, User>(
+        "SELECT * FROM user
+            WHERE user_subscription_plan = 1 AND user_subscription_end_date <= ?",
+    )
+    .bind(threshold)
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_subscription_entitlement(
+    pool: &MySqlPool,
+    user_email: &str,
+    is_active: bool,
+    expires_date: DateTime<Utc>,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE user
+            SET user_subscription_plan = ?,
+                user_subscription_end_date = ?
+            WHERE user_email = ?",
+        is_active,
+        expires_date,
+        user_email,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::rate_limit::{RateLimitBucket, RateLimitDecision},
+};
+
+/// 버킷을 행 잠금(FOR UPDATE)으로 읽고 토큰을 꺼낸 결과를 저장합니다.
+///
+/// 여러 서버 인스턴스가 같은 키를 동시에 갱신해도 한 번에 하나씩 처리됩니다.
+pub async fn take_token(
+    pool: &MySqlPool,
+    bucket_key: &str,
+    capacity: f64,
+    refill_per_sec: f64,
+    now: DateTime<Utc>,
+) -> Result<RateLimitDecision, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    query!(
+        "INSERT IGNORE INTO rate_limit_bucket (bucket_key, bucket_tokens, bucket_updated_at)
+        VALUES (?, ?, ?)",
+        bucket_key,
+        capacity,
+        now,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let mut bucket = query_as!(
+        RateLimitBucket,
+        r#"SELECT bucket_tokens, bucket_updated_at AS "bucket_updated_at: DateTime<Utc>"
+        FROM rate_limit_bucket
+        WHERE bucket_key = ? FOR UPDATE"#,
+        bucket_key,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let decision = bucket.take(capacity, refill_per_sec, now);
+
+    query!(
+        "UPDATE rate_limit_bucket SET bucket_tokens = ?, bucket_updated_at = ?
+        WHERE bucket_key = ?",
+        bucket.bucket_tokens,
+        bucket.bucket_updated_at,
+        bucket_key,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(decision)
+}
+
+/// 오래 사용되지 않은 버킷을 정리합니다.
+pub async fn delete_buckets_updated_before(
+    pool: &MySqlPool,
+    threshold: DateTime<Utc>,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM rate_limit_bucket WHERE bucket_updated_at < ?",
+        threshold,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,142 @@
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::{get_db, placeholders},
+    model::embedding::{
+        EmbeddingVersion, ReembeddingJob, ReembeddingJobPhase, ReembeddingJobStatus,
+    },
+};
+
+pub async fn insert_reembedding_job(
+    pool: &MySqlPool,
+    version: &EmbeddingVersion,
+    total: i32,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        "INSERT INTO reembedding_job
+            (job_model_id, job_text_recipe_version, job_status, job_phase, job_cursor,
+            job_total, job_processed, job_created_at, job_updated_at)
+        VALUES (?, ?, 'running', 'channel', 0, ?, 0, UTC_TIMESTAMP(), UTC_TIMESTAMP());",
+        version.model_id,
+        version.text_recipe_version,
+        total,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_reembedding_job_by_id(
+    pool: &MySqlPool,
+    job_id: i32,
+) -> Result<ReembeddingJob, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as::<_, ReembeddingJob>("SELECT * FROM reembedding_job WHERE job_id = ?;")
+        .bind(job_id)
+        .fetch_one(&mut *conn)
+        .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_latest_reembedding_job(
+    pool: &MySqlPool,
+) -> Result<ReembeddingJob, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as::<_, ReembeddingJob>(
+        "SELECT * FROM reembedding_job ORDER BY job_id DESC LIMIT 1;",
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_reembedding_jobs_by_status(
+    pool: &MySqlPool,
+    status: ReembeddingJobStatus,
+) -> Result<Vec<ReembeddingJob>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as::<_, ReembeddingJob>(
+        "SELECT * FROM reembedding_job WHERE job_status = ? ORDER BY job_id;",
+    )
+    .bind(status.as_str())
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 배치 하나를 처리한 뒤 진행 위치를 저장합니다.
+pub async fn update_reembedding_job_progress(
+    pool: &MySqlPool,
+    job_id: i32,
+    phase: ReembeddingJobPhase,
+    cursor: i32,
+    processed: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        "UPDATE reembedding_job
+        SET job_phase = ?, job_cursor = ?, job_processed = job_processed + ?,
+            job_updated_at = UTC_TIMESTAMP()
+        WHERE job_id = ?;",
+        phase.as_str(),
+        cursor,
+        processed,
+        job_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 작업 상태를 바꿉니다. `from` 상태인 작업만 바꾸므로 동시에 일시 정지된 작업을 덮어쓰지 않습니다.
+pub async fn update_reembedding_job_status(
+    pool: &MySqlPool,
+    job_id: i32,
+    from: &[ReembeddingJobStatus],
+    to: ReembeddingJobStatus,
+    error: Option<String>,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let query = format!(
+        "UPDATE reembedding_job
+        SET job_status = ?, job_error = ?, job_updated_at = UTC_TIMESTAMP(),
+            job_completed_at = IF(? = 'completed', UTC_TIMESTAMP(), NULL)
+        WHERE job_id = ? AND job_status IN ({});",
+        placeholders(from.len())
+    );
+    let mut query_builder = sqlx::query(&query)
+        .bind(to.as_str())
+        .bind(error)
+        .bind(to.as_str())
+        .bind(job_id);
+    for status in from {
+        query_builder = query_builder.bind(status.as_str());
+    }
+    let result = query_builder.execute(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
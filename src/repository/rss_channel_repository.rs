@@ -1,6 +1,7 @@
 use sqlx::{query, query_as, MySqlPool};
 
 use crate::db_util::get_db;
+use crate::model::embedding::EmbeddingVersion;
 use crate::model::rss::{NewRssChannel, RssChannel};
 
 pub async fn select_rss_channel_by_id(
@@ -109,8 +110,8 @@ pub async fn insert_rss_channel(
     let mut conn = get_db(pool).await?;
     let result = query!(
         "INSERT INTO rss_channel 
-            (channel_title, channel_description, channel_link, channel_image_url, channel_language, rss_generator, channel_rank, channel_rss_link)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?);",
+            (channel_title, channel_description, channel_link, channel_image_url, channel_language, rss_generator, channel_rank, channel_rss_link, channel_feed_image_url)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?);",
         rss_channel.channel_title,
         rss_channel.channel_description,
         rss_channel.channel_link,
@@ -119,6 +120,7 @@ pub async fn insert_rss_channel(
         rss_channel.rss_generator,
         rss_channel.channel_rank,
         rss_channel.channel_rss_link,
+        rss_channel.channel_feed_image_url,
     )
     .execute(&mut *conn)
     .await;
@@ -152,3 +154,136 @@ pub async fn update_rss_channel_rank_by_id(
         Ok(false)
     }
 }
+
+pub async fn select_rss_channel_ids(pool: &MySqlPool) -> Result<Vec<i32>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!("SELECT channel_id FROM rss_channel ORDER BY channel_id")
+        .fetch_all(&mut *conn)
+        .await;
+
+    match result {
+        Ok(res) => Ok(res.into_iter().map(|r| r.channel_id).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 채널과 채널에 속한 아이템, 임베딩, 구독, 폴더 연결을 함께 삭제합니다.
+pub async fn delete_rss_channel_by_id(
+    pool: &MySqlPool,
+    channel_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    query!(
+        "DELETE e FROM embedding e
+            JOIN rss_item r ON e.rss_id = r.rss_id
+        WHERE r.channel_id = ?",
+        channel_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    query!("DELETE FROM embedding WHERE channel_id = ?", channel_id)
+        .execute(&mut *tx)
+        .await?;
+    query!("DELETE FROM rss_item WHERE channel_id = ?", channel_id)
+        .execute(&mut *tx)
+        .await?;
+    query!(
+        "DELETE FROM user_subscription_channel WHERE channel_id = ?",
+        channel_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    query!(
+        "DELETE FROM channels_in_folder WHERE channel_id = ?",
+        channel_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    let result = query!("DELETE FROM rss_channel WHERE channel_id = ?", channel_id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn update_rss_channel_metadata(
+    pool: &MySqlPool,
+    channel_id: i32,
+    channel_title: Option<String>,
+    channel_description: Option<String>,
+    channel_image_url: Option<String>,
+    channel_feed_image_url: Option<String>,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        "UPDATE rss_channel
+        SET channel_title = ?, channel_description = ?, channel_image_url = ?,
+            channel_feed_image_url = ?
+        WHERE channel_id = ?;",
+        channel_title,
+        channel_description,
+        channel_image_url,
+        channel_feed_image_url,
+        channel_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 임베딩이 없거나 다른 버전으로 만든 채널을 ID 순으로 조회합니다. (`cursor` 다음 ID부터)
+pub async fn select_rss_channels_to_reembed(
+    pool: &MySqlPool,
+    version: &EmbeddingVersion,
+    cursor: i32,
+    limit: i64,
+) -> Result<Vec<RssChannel>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as!(
+        RssChannel,
+        "SELECT c.* FROM rss_channel c
+        LEFT JOIN embedding e ON e.channel_id = c.channel_id
+        WHERE c.channel_id > ?
+            AND NOT (e.embedding_model_id <=> ? AND e.embedding_text_recipe_version <=> ?)
+        ORDER BY c.channel_id
+        LIMIT ?;",
+        cursor,
+        version.model_id,
+        version.text_recipe_version,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn count_rss_channels_to_reembed(
+    pool: &MySqlPool,
+    version: &EmbeddingVersion,
+) -> Result<i64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        "SELECT COUNT(*) AS count FROM rss_channel c
+        LEFT JOIN embedding e ON e.channel_id = c.channel_id
+        WHERE NOT (e.embedding_model_id <=> ? AND e.embedding_text_recipe_version <=> ?);",
+        version.model_id,
+        version.text_recipe_version,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.count),
+        Err(e) => Err(e),
+    }
+}
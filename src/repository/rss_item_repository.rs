@@ -1,58 +1,103 @@
+use std::collections::{HashMap, HashSet};
+
 use sqlx::{query, query_as, MySqlPool};
 
 use crate::{
-    db_util::get_db,
-    model::rss::{NewRssItem, RssItem},
+    db_util::{get_db, placeholders},
+    model::{
+        embedding::{EmbeddingVersion, NewEmbedding},
+        rss::{NewRssItem, RssItem},
+    },
 };
 
-pub async fn select_item_by_link(
+// 한 INSERT 문에 넣는 최대 행 수 (자리표시자 개수 제한 65535)
+const INSERT_CHUNK_SIZE: usize = 500;
+
+/// rss_link 유니크 인덱스는 해시 컬럼에 걸려 있으므로 해시로 조회한다.
+fn link_hash_placeholders(len: usize) -> String {
+    vec!["UNHEX(SHA2(?, 256))"; len].join(",")
+}
+
+/// 주어진 링크 중 이미 저장된 링크를 한 번에 조회합니다.
+pub async fn select_existing_links(
     pool: &MySqlPool,
-    item_link: String,
-) -> Result<RssItem, sqlx::Error> {
+    links: &[String],
+) -> Result<HashSet<String>, sqlx::Error> {
+    if links.is_empty() {
+        return Ok(HashSet::new());
+    }
     let mut conn = get_db(pool).await?;
-    let result = query_as!(
-        RssItem,
-        "SELECT * FROM rss_item WHERE rss_link=?;",
-        item_link,
-    )
-    .fetch_one(&mut *conn)
-    .await;
 
-    match result {
-        Ok(res) => Ok(res),
-        Err(e) => Err(e),
+    let mut existing = HashSet::new();
+    for chunk in links.chunks(INSERT_CHUNK_SIZE) {
+        let query = format!(
+            "SELECT rss_link FROM rss_item WHERE rss_link_hash IN ({})",
+            link_hash_placeholders(chunk.len())
+        );
+        let mut query_builder = sqlx::query_scalar::<_, String>(&query);
+        for link in chunk {
+            query_builder = query_builder.bind(link);
+        }
+        existing.extend(query_builder.fetch_all(&mut *conn).await?);
     }
+    Ok(existing)
 }
 
-pub async fn select_rss_item_by_embedding_id(
+/// 임베딩 ID 순서대로 아이템을 조회합니다. 아이템이 없는 임베딩은 건너뜁니다.
+pub async fn select_rss_items_by_embedding_ids(
     pool: &MySqlPool,
-    embedding_id: i32,
-) -> Result<RssItem, sqlx::Error> {
+    embedding_ids: &[i32],
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    if embedding_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
     let mut conn = get_db(pool).await?;
-    let result = query_as!(
-        RssItem,
-        "SELECT r.* 
-        FROM rss_item r 
-        JOIN embedding e 
-        ON r.rss_id = e.rss_id
-        WHERE e.embedding_id=?;",
-        embedding_id as i32,
-    )
-    .fetch_one(&mut *conn)
-    .await;
+    let query = format!(
+        "SELECT embedding_id, rss_id FROM embedding
+        WHERE embedding_id IN ({}) AND rss_id IS NOT NULL",
+        placeholders(embedding_ids.len())
+    );
+    let mut query_builder = query_as::<_, (i32, i32)>(&query);
+    for embedding_id in embedding_ids {
+        query_builder = query_builder.bind(embedding_id);
+    }
+    let rss_ids: HashMap<i32, i32> = query_builder
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .collect();
+    if rss_ids.is_empty() {
+        return Ok(vec![]);
+    }
 
-    match result {
-        Ok(res) => Ok(res),
-        Err(e) => Err(e),
+    let query = format!(
+        "SELECT * FROM rss_item WHERE rss_id IN ({})",
+        placeholders(rss_ids.len())
+    );
+    let mut query_builder = query_as::<_, RssItem>(&query);
+    for rss_id in rss_ids.values() {
+        query_builder = query_builder.bind(rss_id);
     }
+    let mut items: HashMap<i32, RssItem> = query_builder
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .filter_map(|item| item.rss_id.map(|rss_id| (rss_id, item)))
+        .collect();
+
+    Ok(embedding_ids
+        .iter()
+        .filter_map(|embedding_id| rss_ids.get(embedding_id))
+        .filter_map(|rss_id| items.remove(rss_id))
+        .collect())
 }
 
 pub async fn select_rss_items_order_by_rss_rank(
     pool: &MySqlPool,
 ) -> Result<Vec<RssItem>, sqlx::Error> {
     let mut conn = get_db(pool).await?;
-    let result = query_as!(
-        RssItem,
+    let result = query_as::<_, RssItem>(
         "SELECT * FROM rss_item ORDER BY rss_rank DESC
          LIMIT 100;",
     )
@@ -71,13 +116,12 @@ pub async fn select_rss_items_by_channel_id(
 ) -> Result<Vec<RssItem>, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
-    let result = query_as!(
-        RssItem,
+    let result = query_as::<_, RssItem>(
         "SELECT * FROM rss_item r
         WHERE r.channel_id = ?
         ORDER BY r.rss_pub_date DESC;",
-        channel_id,
     )
+    .bind(channel_id)
     .fetch_all(&mut *conn)
     .await;
 
@@ -87,28 +131,90 @@ pub async fn select_rss_items_by_channel_id(
     }
 }
 
-pub async fn insert_rss_item(pool: &MySqlPool, rss_item: NewRssItem) -> Result<i32, sqlx::Error> {
-    let mut conn = get_db(pool).await?;
-    let result = query!(
-        "INSERT INTO rss_item 
-            (channel_id, rss_title, rss_description, rss_link, rss_author, rss_pub_date, rss_rank, rss_image_link)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
-        rss_item.channel_id,
-        rss_item.rss_title,
-        rss_item.rss_description,
-        rss_item.rss_link,
-        rss_item.rss_author,
-        rss_item.rss_pub_date,
-        rss_item.rss_rank,
-        rss_item.rss_image_link,
-    )
-    .execute(&mut *conn)
-    .await;
+/// 아이템과 아이템의 임베딩을 한 트랜잭션에서 일괄 저장하고 저장된 아이템 ID를 돌려줍니다.
+///
+/// 다른 작업이 먼저 저장한 링크는 건너뛴다. (rss_link 유니크 인덱스)
+/// INSERT IGNORE는 잘린 값이나 외래 키 위반도 경고로 넘기므로 중복 키만 ON DUPLICATE KEY UPDATE로 무시한다.
+pub async fn insert_rss_items_with_embeddings(
+    pool: &MySqlPool,
+    items: Vec<(NewRssItem, NewEmbedding)>,
+) -> Result<Vec<i32>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let mut inserted_ids = Vec::with_capacity(items.len());
 
-    match result {
-        Ok(res) => Ok(res.last_insert_id() as i32),
-        Err(e) => Err(e),
+    for chunk in items.chunks(INSERT_CHUNK_SIZE) {
+        let query = format!(
+            "INSERT INTO rss_item
+                (channel_id, rss_title, rss_description, rss_link, rss_author, rss_pub_date, rss_rank, rss_image_link)
+            VALUES {}
+            ON DUPLICATE KEY UPDATE rss_id = rss_id",
+            vec!["(?, ?, ?, ?, ?, ?, ?, ?)"; chunk.len()].join(", ")
+        );
+        let mut query_builder = sqlx::query(&query);
+        for (item, _) in chunk {
+            query_builder = query_builder
+                .bind(item.channel_id)
+                .bind(&item.rss_title)
+                .bind(&item.rss_description)
+                .bind(&item.rss_link)
+                .bind(&item.rss_author)
+                .bind(item.rss_pub_date)
+                .bind(item.rss_rank)
+                .bind(&item.rss_image_link);
+        }
+        query_builder.execute(&mut *tx).await?;
+
+        // 다중 행 INSERT는 last_insert_id로 각 행의 ID를 알 수 없으므로 링크로 다시 조회한다.
+        let query = format!(
+            "SELECT rss_id, rss_link FROM rss_item WHERE rss_link_hash IN ({})",
+            link_hash_placeholders(chunk.len())
+        );
+        let mut query_builder = sqlx::query_as::<_, (i32, String)>(&query);
+        for (item, _) in chunk {
+            query_builder = query_builder.bind(&item.rss_link);
+        }
+        let ids: HashMap<String, i32> = query_builder
+            .fetch_all(&mut *tx)
+            .await?
+            .into_iter()
+            .map(|(rss_id, rss_link)| (rss_link, rss_id))
+            .collect();
+
+        let embeddings: Vec<(i32, &NewEmbedding)> = chunk
+            .iter()
+            .filter_map(|(item, embedding)| {
+                let rss_id = *ids.get(item.rss_link.as_deref()?)?;
+                Some((rss_id, embedding))
+            })
+            .collect();
+        if embeddings.is_empty() {
+            continue;
+        }
+
+        let query = format!(
+            "INSERT INTO embedding
+                (embedding_value, rss_id, embedding_source_rank, embedding_model_id, embedding_dim, embedding_text_recipe_version)
+            VALUES {}
+            ON DUPLICATE KEY UPDATE rss_id = rss_id",
+            vec!["(?, ?, ?, ?, ?, ?)"; embeddings.len()].join(", ")
+        );
+        let mut query_builder = sqlx::query(&query);
+        for (rss_id, embedding) in &embeddings {
+            query_builder = query_builder
+                .bind(&embedding.embedding_value)
+                .bind(*rss_id)
+                .bind(embedding.embedding_source_rank)
+                .bind(&embedding.embedding_model_id)
+                .bind(embedding.embedding_dim)
+                .bind(embedding.embedding_text_recipe_version);
+        }
+        query_builder.execute(&mut *tx).await?;
+
+        inserted_ids.extend(embeddings.iter().map(|(rss_id, _)| *rss_id));
     }
+
+    tx.commit().await?;
+    Ok(inserted_ids)
 }
 
 pub async fn update_rss_channel_rank_by_id(
@@ -134,3 +240,94 @@ pub async fn update_rss_channel_rank_by_id(
         Ok(false)
     }
 }
+
+/// 채널 이미지로 대체되었던(또는 비어 있는) 아이템 이미지를 새 채널 이미지로 바꿉니다.
+pub async fn update_rss_item_fallback_images_by_channel_id(
+    pool: &MySqlPool,
+    channel_id: i32,
+    old_image_link: String,
+    new_image_link: String,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        "UPDATE rss_item
+        SET rss_image_link = ?
+        WHERE channel_id = ?
+            AND (rss_image_link IS NULL OR rss_image_link = '' OR rss_image_link = ?);",
+        new_image_link,
+        channel_id,
+        old_image_link,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 아이템과 아이템의 임베딩을 함께 삭제합니다.
+pub async fn delete_rss_item_by_id(pool: &MySqlPool, rss_id: i32) -> Result<bool, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    query!("DELETE FROM embedding WHERE rss_id = ?", rss_id)
+        .execute(&mut *tx)
+        .await?;
+    let result = query!("DELETE FROM rss_item WHERE rss_id = ?", rss_id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// 임베딩이 없거나 다른 버전으로 만든 아이템을 ID 순으로 조회합니다. (`cursor` 다음 ID부터)
+pub async fn select_rss_items_to_reembed(
+    pool: &MySqlPool,
+    version: &EmbeddingVersion,
+    cursor: i32,
+    limit: i64,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as::<_, RssItem>(
+        "SELECT r.* FROM rss_item r
+        LEFT JOIN embedding e ON e.rss_id = r.rss_id
+        WHERE r.rss_id > ?
+            AND NOT (e.embedding_model_id <=> ? AND e.embedding_text_recipe_version <=> ?)
+        ORDER BY r.rss_id
+        LIMIT ?;",
+    )
+    .bind(cursor)
+    .bind(&version.model_id)
+    .bind(version.text_recipe_version)
+    .bind(limit)
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn count_rss_items_to_reembed(
+    pool: &MySqlPool,
+    version: &EmbeddingVersion,
+) -> Result<i64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        "SELECT COUNT(*) AS count FROM rss_item r
+        LEFT JOIN embedding e ON e.rss_id = r.rss_id
+        WHERE NOT (e.embedding_model_id <=> ? AND e.embedding_text_recipe_version <=> ?);",
+        version.model_id,
+        version.text_recipe_version,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.count),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,218 @@
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::{get_db, placeholders},
+    model::{
+        folder::{ItemCursor, NewSmartFolder, SmartFolder, SmartFolderFilter},
+        rss::RssItem,
+    },
+};
+
+pub async fn insert_smart_folder(
+    pool: &MySqlPool,
+    folder: NewSmartFolder,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO smart_folder
+            (user_id, smart_folder_name, smart_folder_query, smart_folder_keywords,
+            smart_folder_channel_ids, smart_folder_language, smart_folder_days,
+            smart_folder_created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        folder.user_id,
+        folder.smart_folder_name,
+        folder.smart_folder_query,
+        folder.smart_folder_keywords,
+        folder.smart_folder_channel_ids,
+        folder.smart_folder_language,
+        folder.smart_folder_days,
+        folder.smart_folder_created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_smart_folders(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<SmartFolder>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as::<_, SmartFolder>(
+        "SELECT * FROM smart_folder WHERE user_id = ? ORDER BY smart_folder_order, smart_folder_id",
+    )
+    .bind(user_id)
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_smart_folder_by_id(
+    pool: &MySqlPool,
+    smart_folder_id: i32,
+) -> Result<SmartFolder, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as::<_, SmartFolder>("SELECT * FROM smart_folder WHERE smart_folder_id = ?")
+        .bind(smart_folder_id)
+        .fetch_one(&mut *conn)
+        .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_smart_folder(
+    pool: &MySqlPool,
+    smart_folder_id: i32,
+    folder: NewSmartFolder,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE smart_folder
+            SET smart_folder_name = ?, smart_folder_query = ?, smart_folder_keywords = ?,
+                smart_folder_channel_ids = ?, smart_folder_language = ?, smart_folder_days = ?
+        WHERE smart_folder_id = ? AND user_id = ?",
+        folder.smart_folder_name,
+        folder.smart_folder_query,
+        folder.smart_folder_keywords,
+        folder.smart_folder_channel_ids,
+        folder.smart_folder_language,
+        folder.smart_folder_days,
+        smart_folder_id,
+        folder.user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(_) => Ok(smart_folder_id),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_smart_folder(
+    pool: &MySqlPool,
+    user_id: i32,
+    smart_folder_id: i32,
+) -> Result<(), sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM smart_folder WHERE smart_folder_id = ? AND user_id = ?",
+        smart_folder_id,
+        user_id
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => {
+            if res.rows_affected() > 0 {
+                Ok(())
+            } else {
+                Err(sqlx::Error::RowNotFound)
+            }
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// 스마트 폴더 조건에 맞는 아이템을 (rss_pub_date, rss_id) 내림차순으로 조회한다.
+///
+/// 조건이 여러 개면 모두 만족해야 하고, 키워드는 하나만 제목이나 설명에 포함되면 된다.
+pub async fn select_smart_folder_items(
+    pool: &MySqlPool,
+    filter: SmartFolderFilter,
+    cursor: ItemCursor,
+    limit: i64,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let mut conditions = vec![
+        "ri.rss_pub_date IS NOT NULL".to_string(),
+        "(ri.rss_pub_date < ? OR (ri.rss_pub_date = ? AND ri.rss_id < ?))".to_string(),
+    ];
+    if let Some(embedding_ids) = &filter.embedding_ids {
+        conditions.push(format!(
+            "ri.rss_id IN (SELECT e.rss_id FROM embedding e WHERE e.embedding_id IN ({}))",
+            placeholders(embedding_ids.len())
+        ));
+    }
+    if !filter.keywords.is_empty() {
+        conditions.push(format!(
+            "({})",
+            vec!["ri.rss_title LIKE ? OR ri.rss_description LIKE ?"; filter.keywords.len()]
+                .join(" OR ")
+        ));
+    }
+    if !filter.channel_ids.is_empty() {
+        conditions.push(format!(
+            "ri.channel_id IN ({})",
+            placeholders(filter.channel_ids.len())
+        ));
+    }
+    if filter.language.is_some() {
+        conditions.push("rc.channel_language LIKE ?".to_string());
+    }
+    if filter.published_after.is_some() {
+        conditions.push("ri.rss_pub_date >= ?".to_string());
+    }
+
+    let query = format!(
+        "SELECT ri.* FROM rss_item ri
+            JOIN rss_channel rc ON ri.channel_id = rc.channel_id
+        WHERE {}
+        ORDER BY ri.rss_pub_date DESC, ri.rss_id DESC
+        LIMIT ?",
+        conditions.join(" AND ")
+    );
+
+    let mut query_builder = query_as::<_, RssItem>(&query)
+        .bind(cursor.rss_pub_date)
+        .bind(cursor.rss_pub_date)
+        .bind(cursor.rss_id);
+    for id in filter.embedding_ids.unwrap_or_default() {
+        query_builder = query_builder.bind(id);
+    }
+    for keyword in filter.keywords {
+        let pattern = format!("%{}%", escape_like(&keyword));
+        query_builder = query_builder.bind(pattern.clone()).bind(pattern);
+    }
+    for id in filter.channel_ids {
+        query_builder = query_builder.bind(id);
+    }
+    if let Some(language) = filter.language {
+        query_builder = query_builder.bind(format!("{}%", escape_like(&language)));
+    }
+    if let Some(published_after) = filter.published_after {
+        query_builder = query_builder.bind(published_after);
+    }
+
+    let result = query_builder.bind(limit).fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
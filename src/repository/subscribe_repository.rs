@@ -103,23 +103,31 @@ pub async fn is_already_subscribe_channel(
     }
 }
 
+/// 구독 해제 시 사용자의 폴더에 담긴 해당 채널도 함께 정리한다.
 pub async fn delete_subscribe_channel(
     pool: &MySqlPool,
     user_id: i32,
     channel_id: i32,
 ) -> Result<i32, sqlx::Error> {
-    let mut conn = get_db(pool).await?;
+    let mut tx = pool.begin().await?;
 
+    query!(
+        "DELETE cif FROM channels_in_folder cif
+            JOIN rss_folder rf ON cif.folder_id = rf.folder_id
+        WHERE rf.user_id = ? AND cif.channel_id = ?",
+        user_id,
+        channel_id
+    )
+    .execute(&mut *tx)
+    .await?;
     let result = query!(
         "DELETE FROM user_subscription_channel WHERE user_id = ? AND channel_id = ?",
         user_id,
         channel_id
     )
-    .execute(&mut *conn)
-    .await;
+    .execute(&mut *tx)
+    .await?;
 
-    match result {
-        Ok(res) => Ok(res.last_insert_id() as i32),
-        Err(e) => Err(e),
-    }
+    tx.commit().await?;
+    Ok(result.rows_affected() as i32)
 }
@@ -1,9 +1,9 @@
-use chrono::NaiveDateTime;
-use sqlx::{query, MySqlPool};
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_as, MySqlPool};
 
 use crate::{
     db_util::get_db,
-    model::{auth::JwtToken, user::NewUser},
+    model::user::{NewUser, User},
 };
 
 pub async fn insert_user(pool: &MySqlPool, user: NewUser) -> Result<i32, sqlx::Error> {
@@ -12,19 +12,13 @@ pub async fn insert_user(pool: &MySqlPool, user: NewUser) -> Result<i32, sqlx::E
     let result = query!(
         "INSERT INTO user 
             (user_email, user_display_name, user_photo_url, user_social_login_provider,
-            user_social_provider_id, user_access_token, user_refresh_token, user_access_token_expires_at,
-            user_refresh_token_expires_at, user_last_active_at,
-            user_created_at, user_updated_at)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ? )",
+            user_social_provider_id, user_last_active_at, user_created_at, user_updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ? )",
         user.user_email,
         user.user_display_name,
         user.user_photo_url,
         user.user_social_login_provider,
         user.user_social_provider_id,
-        user.user_access_token,
-        user.user_refresh_token,
-        user.user_access_token_expires_at,
-        user.user_refresh_token_expires_at,
         user.user_last_active_at,
         user.user_created_at,
         user.user_updated_at,
@@ -53,36 +47,43 @@ pub async fn select_user_id_by_email(
     }
 }
 
-pub async fn select_user_email_by_social_provider_id(
+/// 소셜 로그인 제공자와 제공자의 사용자 ID로 계정을 조회합니다.
+pub async fn select_user_by_social_provider_id(
     pool: &MySqlPool,
-    user_social_provider_id: String,
-) -> Result<String, sqlx::Error> {
+    user_social_login_provider: &str,
+    user_social_provider_id: &str,
+) -> Result<User, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
-    let result = query!(
-        "SELECT user_email FROM user WHERE user_social_provider_id = ?",
-        user_social_provider_id
+    let result = query_as::<_, User>(
+        "SELECT * FROM user
+        WHERE user_social_login_provider = ? AND user_social_provider_id = ?",
     )
+    .bind(user_social_login_provider)
+    .bind(user_social_provider_id)
     .fetch_one(&mut *conn)
     .await;
 
     match result {
-        Ok(res) => Ok(res.user_email),
+        Ok(res) => Ok(res),
         Err(e) => Err(e),
     }
 }
 
-pub async fn delete_user_token_by_email(
+pub async fn update_user_notification_setting(
     pool: &MySqlPool,
     user_email: String,
+    notification_push: bool,
+    user_fcm_token: String,
 ) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
     let result = query!(
         "UPDATE user
-            SET user_access_token = NULL, user_refresh_token = NULL,
-                user_access_token_expires_at = NULL, user_refresh_token_expires_at = NULL
+            SET user_notification_push = ?, user_fcm_token = ?
         WHERE user_email = ?",
+        notification_push,
+        user_fcm_token,
         user_email
     )
     .execute(&mut *conn)
@@ -94,100 +95,77 @@ pub async fn delete_user_token_by_email(
     }
 }
 
-pub async fn validate_users_all_tokens(
-    pool: &MySqlPool,
-    user_email: String,
-) -> Result<(bool, bool), sqlx::Error> {
+pub async fn get_user_theme(pool: &MySqlPool, user_email: String) -> Result<String, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
-    let access_result = query!(
-        "SELECT user_email FROM user
-        WHERE user_email = ? AND user_access_token IS NOT NULL AND user_access_token_expires_at > NOW()",
-        user_email
-    )
-    .fetch_one(&mut *conn)
-    .await;
-
-    let refresh_result = query!(
-        "SELECT user_email FROM user
-        WHERE user_email = ? AND user_refresh_token IS NOT NULL AND user_refresh_token_expires_at > NOW()",
+    let result = query!(
+        "SELECT user_theme FROM user WHERE user_email = ?",
         user_email
     )
     .fetch_one(&mut *conn)
     .await;
 
-    match (access_result, refresh_result) {
-        (Ok(_), Ok(_)) => Ok((true, true)),
-        (Ok(_), Err(_)) => Ok((true, false)),
-        (Err(_), Ok(_)) => Ok((false, true)),
-        (Err(_), Err(_)) => Ok((false, false)),
+    match result {
+        Ok(res) => Ok(res.user_theme.unwrap_or_else(|| "default".to_string())),
+        Err(e) => Err(e),
     }
 }
 
-pub async fn validate_access_token_by_user_email(
+pub async fn update_user_theme(
     pool: &MySqlPool,
-    token: String,
-    email: String,
-) -> Result<String, sqlx::Error> {
+    user_email: String,
+    theme: String,
+) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
+    info!("theme: {}, user_email: {}", theme, user_email);
 
     let result = query!(
-        "SELECT user_email FROM user
-        WHERE user_access_token = ? AND user_email = ?",
-        token,
-        email
+        "UPDATE user
+            SET user_theme = ?
+        WHERE user_email = ?",
+        theme,
+        user_email
     )
-    .fetch_one(&mut *conn)
+    .execute(&mut *conn)
     .await;
 
     match result {
-        Ok(res) => Ok(res.user_email),
-        Err(_) => Err(sqlx::Error::RowNotFound),
+        Ok(res) => Ok(res.rows_affected() as i32),
+        Err(e) => Err(e),
     }
 }
 
-pub async fn validate_refresh_token_by_user_email(
+pub async fn get_user_timezone(
     pool: &MySqlPool,
-    token: String,
-    email: String,
-) -> Result<JwtToken, sqlx::Error> {
+    user_email: String,
+) -> Result<Option<String>, sqlx::Error> {
     let mut conn = get_db(pool).await?;
-    info!("Validating refresh token: {}, for email: {}", token, email);
 
     let result = query!(
-        "SELECT * FROM user
-        WHERE user_refresh_token = ? AND user_email = ? AND user_refresh_token_expires_at > NOW();",
-        token,
-        email
+        "SELECT user_timezone FROM user WHERE user_email = ?",
+        user_email
     )
     .fetch_one(&mut *conn)
     .await;
 
     match result {
-        Ok(res) => Ok(JwtToken {
-            access_token: res.user_access_token,
-            access_token_expires_at: res.user_access_token_expires_at,
-            refresh_token: res.user_refresh_token,
-            refresh_token_expires_at: res.user_refresh_token_expires_at,
-        }),
-        Err(_) => Err(sqlx::Error::RowNotFound),
+        Ok(res) => Ok(res.user_timezone),
+        Err(e) => Err(e),
     }
 }
 
-pub async fn update_user_access_token(
+pub async fn update_user_timezone(
     pool: &MySqlPool,
     user_email: String,
-    access_token: String,
-    access_token_expires_at: NaiveDateTime,
+    user_timezone: String,
 ) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
     let result = query!(
         "UPDATE user
-            SET user_access_token = ?, user_access_token_expires_at = ?
+            SET user_timezone = ?
         WHERE user_email = ?",
-        access_token,
-        access_token_expires_at,
+        user_timezone,
         user_email
     )
     .execute(&mut *conn)
@@ -199,22 +177,25 @@ pub async fn update_user_access_token(
     }
 }
 
-pub async fn update_uesr_tokens(
+pub async fn update_user_info(
     pool: &MySqlPool,
-    user_email: String,
-    tokens: JwtToken,
+    user_email: Option<String>,
+    user_display_name: Option<String>,
+    user_photo_url: Option<String>,
+    user_social_login_provider: Option<String>,
+    user_social_provider_id: Option<String>,
 ) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
     let result = query!(
         "UPDATE user
-            SET user_access_token = ?, user_refresh_token = ?,
-                user_access_token_expires_at = ?, user_refresh_token_expires_at = ?
+            SET user_display_name = ?, user_photo_url = ?,
+                user_social_login_provider = ?, user_social_provider_id = ?
         WHERE user_email = ?",
-        tokens.access_token.unwrap(),
-        tokens.refresh_token.unwrap(),
-        tokens.access_token_expires_at.unwrap(),
-        tokens.refresh_token_expires_at.unwrap(),
+        user_display_name,
+        user_photo_url,
+        user_social_login_provider,
+        user_social_provider_id,
         user_email
     )
     .execute(&mut *conn)
@@ -226,97 +207,271 @@ pub async fn update_uesr_tokens(
     }
 }
 
-pub async fn update_user_notification_setting(
+pub async fn select_user_by_email(
     pool: &MySqlPool,
     user_email: String,
-    notification_push: bool,
-    user_fcm_token: String,
-) -> Result<i32, sqlx::Error> {
+) -> Result<User, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
-    let result = query!(
-        "UPDATE user
-            SET user_notification_push = ?, user_fcm_token = ?
-        WHERE user_email = ?",
-        notification_push,
-        user_fcm_token,
-        user_email
+    let result = query_as::<_, User>("SELECT * FROM user WHERE user_email = ?")
+        .bind(user_email)
+        .fetch_one(&mut *conn)
+        .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_user_by_id(pool: &MySqlPool, user_id: i32) -> Result<User, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as::<_, User>("SELECT * FROM user WHERE user_id = ?")
+        .bind(user_id)
+        .fetch_one(&mut *conn)
+        .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 이메일 또는 표시 이름에 검색어가 포함된 사용자를 최신 가입 순으로 조회합니다.
+pub async fn select_users(
+    pool: &MySqlPool,
+    search_value: Option<String>,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<User>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let pattern = search_value.map(|v| format!("%{}%", v));
+
+    let result = query_as::<_, User>(
+        "SELECT * FROM user
+            WHERE ? IS NULL OR user_email LIKE ? OR user_display_name LIKE ?
+        ORDER BY user_id DESC
+        LIMIT ? OFFSET ?",
     )
-    .execute(&mut *conn)
+    .bind(pattern.clone())
+    .bind(pattern.clone())
+    .bind(pattern)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(&mut *conn)
     .await;
 
     match result {
-        Ok(res) => Ok(res.rows_affected() as i32),
+        Ok(res) => Ok(res),
         Err(e) => Err(e),
     }
 }
 
-pub async fn get_user_theme(pool: &MySqlPool, user_email: String) -> Result<String, sqlx::Error> {
+pub async fn update_user_status(
+    pool: &MySqlPool,
+    user_id: i32,
+    user_status: &str,
+) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
     let result = query!(
-        "SELECT user_theme FROM user WHERE user_email = ?",
-        user_email
+        "UPDATE user SET user_status = ? WHERE user_id = ?",
+        user_status,
+        user_id,
     )
-    .fetch_one(&mut *conn)
+    .execute(&mut *conn)
     .await;
 
     match result {
-        Ok(res) => Ok(res.user_theme.unwrap_or_else(|| "default".to_string())),
+        Ok(res) => {
+            if res.rows_affected() > 0 {
+                Ok(user_id)
+            } else {
+                Err(sqlx::Error::RowNotFound)
+            }
+        }
         Err(e) => Err(e),
     }
 }
 
-pub async fn update_user_theme(
+/// 탈퇴 요청: 상태만 변경하고 데이터는 유예 기간 동안 보관합니다.
+pub async fn soft_delete_user(
     pool: &MySqlPool,
-    user_email: String,
-    theme: String,
+    user_id: i32,
+    deleted_at: DateTime<Utc>,
 ) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
-    info!("theme: {}, user_email: {}", theme, user_email);
 
     let result = query!(
         "UPDATE user
-            SET user_theme = ?
-        WHERE user_email = ?",
-        theme,
-        user_email
+            SET user_status = 'deleted', user_deleted_at = ?, user_fcm_token = NULL,
+                user_notification_push = FALSE
+        WHERE user_id = ?",
+        deleted_at,
+        user_id,
     )
     .execute(&mut *conn)
     .await;
 
     match result {
-        Ok(res) => Ok(res.rows_affected() as i32),
+        Ok(res) => {
+            if res.rows_affected() > 0 {
+                Ok(user_id)
+            } else {
+                Err(sqlx::Error::RowNotFound)
+            }
+        }
         Err(e) => Err(e),
     }
 }
 
-pub async fn update_user_info(
-    pool: &MySqlPool,
-    user_email: Option<String>,
-    user_display_name: Option<String>,
-    user_photo_url: Option<String>,
-    user_social_login_provider: Option<String>,
-    user_social_provider_id: Option<String>,
-) -> Result<i32, sqlx::Error> {
+/// 유예 기간 안에 다시 로그인하면 탈퇴 요청을 취소합니다.
+pub async fn restore_user(pool: &MySqlPool, user_id: i32) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
     let result = query!(
-        "UPDATE user
-            SET user_display_name = ?, user_photo_url = ?,
-                user_social_login_provider = ?, user_social_provider_id = ?
-        WHERE user_email = ?",
-        user_display_name,
-        user_photo_url,
-        user_social_login_provider,
-        user_social_provider_id,
-        user_email
+        "UPDATE user SET user_status = 'active', user_deleted_at = NULL
+        WHERE user_id = ? AND user_status = 'deleted'",
+        user_id,
     )
     .execute(&mut *conn)
     .await;
 
     match result {
-        Ok(res) => Ok(res.rows_affected() as i32),
+        Ok(_) => Ok(user_id),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_user_ids_deleted_before(
+    pool: &MySqlPool,
+    threshold: DateTime<Utc>,
+) -> Result<Vec<i32>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT user_id FROM user
+            WHERE user_status = 'deleted' AND user_deleted_at <= ?",
+        threshold,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.into_iter().map(|r| r.user_id).collect()),
         Err(e) => Err(e),
     }
 }
+
+/// 사용자와 사용자별 데이터(구독, 폴더, 세션)를 완전히 삭제합니다.
+///
+/// 피드백은 운영 기록으로 남기되 이메일은 지웁니다.
+pub async fn purge_user(pool: &MySqlPool, user_id: i32) -> Result<bool, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    // 탈퇴 상태인 사용자 행을 잠가 정리 중에 로그인(탈퇴 취소)으로 복구되지 않게 한다.
+    let locked = query!(
+        "SELECT user_id FROM user WHERE user_id = ? AND user_status = 'deleted' FOR UPDATE",
+        user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+    if locked.is_none() {
+        return Ok(false);
+    }
+
+    query!(
+        "DELETE cif FROM channels_in_folder cif
+            JOIN rss_folder rf ON cif.folder_id = rf.folder_id
+        WHERE rf.user_id = ?",
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    query!("DELETE FROM rss_folder WHERE user_id = ?", user_id)
+        .execute(&mut *tx)
+        .await?;
+    query!("DELETE FROM smart_folder WHERE user_id = ?", user_id)
+        .execute(&mut *tx)
+        .await?;
+    query!("DELETE FROM mute_rule WHERE user_id = ?", user_id)
+        .execute(&mut *tx)
+        .await?;
+    query!(
+        "DELETE FROM user_subscription_channel WHERE user_id = ?",
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    query!("DELETE FROM user_session WHERE user_id = ?", user_id)
+        .execute(&mut *tx)
+        .await?;
+    query!(
+        "UPDATE feedback f
+        JOIN user u ON f.feedback_email = u.user_email
+            SET f.feedback_email = NULL
+        WHERE u.user_id = ?",
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    let result = query!(
+        "DELETE FROM user WHERE user_id = ? AND user_status = 'deleted'",
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn insert_test_user(pool: &MySqlPool, email: &str, status: &str) -> i32 {
+        sqlx::query("INSERT INTO user (user_email, user_status) VALUES (?, ?)")
+            .bind(email)
+            .bind(status)
+            .execute(pool)
+            .await
+            .unwrap()
+            .last_insert_id() as i32
+    }
+
+    async fn user_exists(pool: &MySqlPool, user_id: i32) -> bool {
+        sqlx::query("SELECT user_id FROM user WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_optional(pool)
+            .await
+            .unwrap()
+            .is_some()
+    }
+
+    #[ignore = "requires DATABASE_URL (MySQL)"]
+    #[sqlx::test]
+    async fn purges_only_deleted_user(pool: MySqlPool) {
+        let active = insert_test_user(&pool, "active@example.com", "active").await;
+        let deleted = insert_test_user(&pool, "deleted@example.com", "deleted").await;
+        sqlx::query("INSERT INTO rss_folder (user_id, folder_name) VALUES (?, 'keep')")
+            .bind(active)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        // 탈퇴를 취소한 사용자는 데이터를 지우지 않는다.
+        assert!(!purge_user(&pool, active).await.unwrap());
+        assert!(user_exists(&pool, active).await);
+        let folders: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM rss_folder WHERE user_id = ?")
+            .bind(active)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(folders, 1);
+
+        assert!(purge_user(&pool, deleted).await.unwrap());
+        assert!(!user_exists(&pool, deleted).await);
+    }
+}
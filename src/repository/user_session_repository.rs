@@ -0,0 +1,197 @@
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::{
+        auth::JwtToken,
+        session::{NewUserSession, UserSession},
+    },
+};
+
+pub async fn insert_session(
+    pool: &MySqlPool,
+    session: NewUserSession,
+) -> Result<String, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO user_session
+            (session_id, user_id, session_device_id, session_access_token, session_refresh_token,
+            session_access_token_expires_at, session_refresh_token_expires_at,
+            session_created_at, session_last_used_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        session.session_id,
+        session.user_id,
+        session.session_device_id,
+        session.session_access_token,
+        session.session_refresh_token,
+        session.session_access_token_expires_at,
+        session.session_refresh_token_expires_at,
+        session.session_created_at,
+        session.session_last_used_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(_) => Ok(session.session_id.unwrap_or_default()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_session_by_id(
+    pool: &MySqlPool,
+    session_id: &str,
+) -> Result<UserSession, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as::<_, UserSession>("SELECT * FROM user_session WHERE session_id = ?")
+        .bind(session_id)
+        .fetch_one(&mut *conn)
+        .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 폐기되지 않은 세션의 access token이면 사용자의 시간대 설정을 반환합니다.
+pub async fn validate_access_token_by_user_email(
+    pool: &MySqlPool,
+    token: String,
+    email: String,
+) -> Result<Option<String>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT u.user_timezone FROM user_session s
+        JOIN user u ON s.user_id = u.user_id
+        WHERE s.session_access_token = ? AND u.user_email = ?
+            AND s.session_revoked_at IS NULL",
+        token,
+        email
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.user_timezone),
+        Err(_) => Err(sqlx::Error::RowNotFound),
+    }
+}
+
+/// 기존 refresh token이 그대로일 때만 교체합니다. (동시 요청 시 한 요청만 성공)
+pub async fn rotate_session_tokens(
+    pool: &MySqlPool,
+    session_id: &str,
+    old_refresh_token: &str,
+    tokens: JwtToken,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE user_session
+            SET session_access_token = ?, session_refresh_token = ?,
+                session_access_token_expires_at = ?, session_refresh_token_expires_at = ?,
+                session_rotation_count = session_rotation_count + 1,
+                session_last_used_at = NOW()
+        WHERE session_id = ? AND session_refresh_token = ? AND session_revoked_at IS NULL",
+        tokens.access_token,
+        tokens.refresh_token,
+        tokens.access_token_expires_at,
+        tokens.refresh_token_expires_at,
+        session_id,
+        old_refresh_token,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn revoke_session(pool: &MySqlPool, session_id: &str) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE user_session
+            SET session_access_token = NULL, session_refresh_token = NULL,
+                session_revoked_at = NOW()
+        WHERE session_id = ?",
+        session_id
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn revoke_sessions_by_user_email(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE user_session s
+        JOIN user u ON s.user_id = u.user_id
+            SET s.session_access_token = NULL, s.session_refresh_token = NULL,
+                s.session_revoked_at = NOW()
+        WHERE u.user_email = ? AND s.session_revoked_at IS NULL",
+        user_email
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+/// 같은 기기에서 다시 로그인하면 이전 세션은 제거합니다.
+pub async fn delete_sessions_by_device(
+    pool: &MySqlPool,
+    user_id: i32,
+    device_id: &str,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM user_session WHERE user_id = ? AND session_device_id = ?",
+        user_id,
+        device_id
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_sessions_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<UserSession>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as::<_, UserSession>(
+        "SELECT * FROM user_session WHERE user_id = ? ORDER BY session_created_at DESC",
+    )
+    .bind(user_id)
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
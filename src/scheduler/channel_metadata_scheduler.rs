@@ -0,0 +1,41 @@
+use std::{env, time::Duration};
+
+use sqlx::MySqlPool;
+
+use crate::{
+    config::http_client::HttpClient, rss_info, service::channel_metadata_service,
+    utils::embedding_util::EmbeddingService,
+};
+
+// 기본 하루마다 실행
+const DEFAULT_INTERVAL_SECS: u64 = 24 * 60 * 60;
+
+/// 채널 제목, 설명, 이미지를 원본 피드와 맞추는 작업을 백그라운드로 실행합니다.
+///
+/// - `CHANNEL_METADATA_REFRESH_INTERVAL_SECS` : 실행 주기 (초)
+pub fn start(pool: MySqlPool, embedding_service: EmbeddingService, http_client: HttpClient) {
+    let interval = env::var("CHANNEL_METADATA_REFRESH_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+
+    tokio::spawn(async move {
+        rss_info!(
+            "[Scheduler] Channel metadata refresh started: interval={}s",
+            interval
+        );
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+        // 서버 시작 직후에는 실행하지 않는다.
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let refreshed = channel_metadata_service::refresh_all_channel_metadata(
+                &pool,
+                &embedding_service,
+                &http_client,
+            )
+            .await;
+            rss_info!("[Scheduler] Refreshed metadata of {} channels", refreshed);
+        }
+    });
+}
@@ -0,0 +1,4 @@
+pub mod channel_metadata_scheduler;
+pub mod mute_cleanup_scheduler;
+pub mod omninews_subscription_scheduler;
+pub mod user_purge_scheduler;
@@ -0,0 +1,30 @@
+use std::{env, time::Duration};
+
+use sqlx::MySqlPool;
+
+use crate::{service::mute_service, user_info};
+
+// 기본 하루마다 실행 (만료된 규칙은 조회 시 이미 제외되므로 정리만 한다)
+const DEFAULT_INTERVAL_SECS: u64 = 60 * 60 * 24;
+
+/// 만료된 뮤트 규칙을 삭제하는 작업을 백그라운드로 실행합니다.
+///
+/// - `MUTE_CLEANUP_INTERVAL_SECS` : 실행 주기 (초)
+pub fn start(pool: MySqlPool) {
+    let interval = env::var("MUTE_CLEANUP_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+
+    tokio::spawn(async move {
+        user_info!(
+            "[Scheduler] Mute rule cleanup started: interval={}s",
+            interval
+        );
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+        loop {
+            ticker.tick().await;
+            mute_service::delete_expired_mute_rules(&pool).await;
+        }
+    });
+}
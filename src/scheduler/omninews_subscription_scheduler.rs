@@ -0,0 +1,49 @@
+use std::{env, time::Duration};
+
+use sqlx::MySqlPool;
+
+use crate::{
+    config::http_client::HttpClient, omninews_subscription_error, omninews_subscription_info,
+    service::omninews_subscription_service,
+};
+
+// 기본 1시간마다 실행
+const DEFAULT_INTERVAL_SECS: u64 = 60 * 60;
+// 기본 24시간 이내 만료 예정 구독까지 확인
+const DEFAULT_WINDOW_HOURS: i64 = 24;
+
+/// App Store 구독 상태 대조 작업을 백그라운드로 실행합니다.
+///
+/// - `SUBSCRIPTION_RECONCILE_INTERVAL_SECS` : 실행 주기 (초)
+/// - `SUBSCRIPTION_RECONCILE_WINDOW_HOURS` : 만료 임박으로 간주할 기간 (시간)
+pub fn start(pool: MySqlPool, http_client: HttpClient) {
+    let interval = env::var("SUBSCRIPTION_RECONCILE_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+    let window = env::var("SUBSCRIPTION_RECONCILE_WINDOW_HOURS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(DEFAULT_WINDOW_HOURS);
+
+    tokio::spawn(async move {
+        omninews_subscription_info!(
+            "[Scheduler] Subscription reconcile started: interval={}s, window={}h",
+            interval,
+            window
+        );
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+        loop {
+            ticker.tick().await;
+            if let Err(e) = omninews_subscription_service::reconcile_subscriptions(
+                &pool,
+                &http_client,
+                chrono::Duration::hours(window),
+            )
+            .await
+            {
+                omninews_subscription_error!("[Scheduler] Subscription reconcile failed: {}", e);
+            }
+        }
+    });
+}
@@ -0,0 +1,42 @@
+use std::{env, time::Duration};
+
+use sqlx::MySqlPool;
+
+use crate::{service::user_service, user_info};
+
+// 기본 1시간마다 실행
+const DEFAULT_INTERVAL_SECS: u64 = 60 * 60;
+// 탈퇴 요청 후 기본 30일 뒤 완전 삭제
+const DEFAULT_GRACE_DAYS: i64 = 30;
+
+/// 유예 기간이 지난 탈퇴 사용자를 완전히 삭제하는 작업을 백그라운드로 실행합니다.
+///
+/// - `USER_PURGE_INTERVAL_SECS` : 실행 주기 (초)
+/// - `USER_DELETION_GRACE_DAYS` : 탈퇴 요청 후 데이터를 보관하는 기간 (일)
+pub fn start(pool: MySqlPool) {
+    let interval = env::var("USER_PURGE_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+    let grace_days = env::var("USER_DELETION_GRACE_DAYS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(DEFAULT_GRACE_DAYS);
+
+    tokio::spawn(async move {
+        user_info!(
+            "[Scheduler] User purge started: interval={}s, grace={}d",
+            interval,
+            grace_days
+        );
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+        loop {
+            ticker.tick().await;
+            let purged =
+                user_service::purge_deleted_users(&pool, chrono::Duration::days(grace_days)).await;
+            if purged > 0 {
+                user_info!("[Scheduler] Purged {} deleted users", purged);
+            }
+        }
+    });
+}
@@ -0,0 +1,155 @@
+use sqlx::MySqlPool;
+
+use crate::{
+    config::webdriver::DriverPool,
+    dto::admin::{
+        request::{AdminUserSearchRequestDto, AdminUserStatusRequestDto},
+        response::{
+            AdminUserResponseDto, DriverPoolStatsResponseDto, EmbeddingQueueStatsResponseDto,
+        },
+    },
+    embedding_error, embedding_info,
+    model::error::OmniNewsError,
+    repository::{user_repository, user_session_repository},
+    server_error, server_info,
+    service::channel_service,
+    utils::embedding_util::EmbeddingService,
+};
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+const MAX_PAGE_SIZE: i64 = 100;
+
+pub async fn find_users(
+    pool: &MySqlPool,
+    request: AdminUserSearchRequestDto,
+) -> Result<Vec<AdminUserResponseDto>, OmniNewsError> {
+    let page_size = request
+        .page_size
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let offset = (request.page.unwrap_or(1).max(1) - 1) * page_size;
+    let search_value = request.search_value.filter(|v| !v.trim().is_empty());
+
+    match user_repository::select_users(pool, search_value, page_size, offset).await {
+        Ok(users) => Ok(AdminUserResponseDto::from_model_list(users)),
+        Err(e) => {
+            server_error!("[Service] Failed to select users: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 사용자를 정지(suspended)하거나 다시 활성화(active)합니다.
+///
+/// 정지된 사용자는 모든 기기의 세션이 폐기됩니다.
+pub async fn update_user_status(
+    pool: &MySqlPool,
+    request: AdminUserStatusRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let user_id = request.user_id.unwrap_or_default();
+    let user_status = request.user_status.unwrap_or_default();
+    if !matches!(user_status.as_str(), "active" | "suspended") {
+        return Err(OmniNewsError::InvalidRequest(format!(
+            "unsupported user status: {}",
+            user_status
+        )));
+    }
+
+    let user = user_repository::select_user_by_id(pool, user_id)
+        .await
+        .map_err(|e| {
+            server_error!("[Service] Failed to select user by id: {}", e);
+            OmniNewsError::NotFound(format!("user {}", user_id))
+        })?;
+
+    if let Err(e) = user_repository::update_user_status(pool, user_id, &user_status).await {
+        server_error!("[Service] Failed to update user status: {}", e);
+        return Err(OmniNewsError::Database(e));
+    }
+
+    if user_status == "suspended" {
+        if let Err(e) = user_session_repository::revoke_sessions_by_user_email(
+            pool,
+            user.user_email.unwrap_or_default(),
+        )
+        .await
+        {
+            server_error!(
+                "[Service] Failed to revoke sessions of suspended user: {}",
+                e
+            );
+            return Err(OmniNewsError::Database(e));
+        }
+    }
+
+    server_info!(
+        "[Service] User {} status changed to {}",
+        user_id,
+        user_status
+    );
+    Ok(user_id)
+}
+
+/// 임베딩 재계산은 오래 걸리므로 백그라운드에서 실행하고 대상 채널 수를 바로 반환합니다.
+pub async fn rebuild_embeddings(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    channel_id: Option<i32>,
+) -> Result<usize, OmniNewsError> {
+    let channel_ids = match channel_id {
+        Some(channel_id) => vec![channel_id],
+        None => channel_service::find_rss_channel_ids(pool).await?,
+    };
+    let target_count = channel_ids.len();
+
+    let pool = pool.clone();
+    let embedding_service = embedding_service.clone();
+    tokio::spawn(async move {
+        embedding_info!(
+            "[Service] Start rebuilding embeddings of {} channels",
+            channel_ids.len()
+        );
+        let mut rebuilt_items = 0;
+        for channel_id in channel_ids {
+            match channel_service::rebuild_channel_embedding(&pool, &embedding_service, channel_id)
+                .await
+            {
+                Ok(count) => rebuilt_items += count,
+                Err(e) => {
+                    embedding_error!(
+                        "[Service] Failed to rebuild embedding of channel {}: {}",
+                        channel_id,
+                        e
+                    );
+                }
+            }
+        }
+        embedding_info!(
+            "[Service] Finished rebuilding embeddings: {} items",
+            rebuilt_items
+        );
+    });
+
+    Ok(target_count)
+}
+
+pub async fn driver_pool_stats(driver_pool: &DriverPool) -> DriverPoolStatsResponseDto {
+    let (idle, total) = driver_pool.stats().await;
+    DriverPoolStatsResponseDto {
+        idle,
+        total,
+        max_sessions: driver_pool.max_sessions(),
+    }
+}
+
+pub fn embedding_queue_stats(
+    embedding_service: &EmbeddingService,
+) -> EmbeddingQueueStatsResponseDto {
+    let config = embedding_service.config();
+    EmbeddingQueueStatsResponseDto {
+        queued: embedding_service.queue_depth(),
+        queue_capacity: config.queue_capacity,
+        workers: config.workers,
+        max_batch_size: config.max_batch_size,
+    }
+}
@@ -0,0 +1,283 @@
+use reqwest::{header::CONTENT_TYPE, Url};
+use scraper::{Html, Selector};
+use sqlx::MySqlPool;
+
+use crate::{
+    config::http_client::HttpClient,
+    model::{embedding::NewEmbedding, error::OmniNewsError, rss::RssChannel},
+    repository::{rss_channel_repository, rss_item_repository},
+    rss_error, rss_info, rss_warn,
+    service::{channel_service, embedding_service},
+    utils::{
+        embedding_util::{make_embedding_text, EmbeddingService},
+        url_policy_util,
+    },
+};
+
+// rss_channel.channel_image_url 컬럼 길이
+const MAX_IMAGE_URL_LEN: usize = 1000;
+// 아이콘으로 허용하는 최대 이미지 크기 (5MB)
+const MAX_ICON_BYTES: u64 = 5 * 1024 * 1024;
+// 직접 생성한 채널은 원본 RSS가 없으므로 갱신하지 않는다.
+const GENERATED_CHANNEL_GENERATOR: &str = "Omninews";
+
+/// 채널 아이콘 후보를 순서대로 확인하여 실제로 받을 수 있는 첫 번째 이미지 주소를 반환합니다.
+///
+/// 피드 이미지 → `<link rel="icon">` → `apple-touch-icon` → `og:image` → `/favicon.ico` 순서로 확인합니다.
+pub async fn resolve_channel_icon(
+    http_client: &HttpClient,
+    feed_image_url: Option<String>,
+    channel_link: &str,
+) -> Option<String> {
+    let base = Url::parse(channel_link).ok();
+
+    let mut candidates = vec![];
+    if let Some(url) = feed_image_url.filter(|url| !url.trim().is_empty()) {
+        candidates.push(url);
+    }
+    if let Some(base) = &base {
+        candidates.extend(fetch_page_icon_candidates(http_client, base).await);
+        if let Ok(favicon) = base.join("/favicon.ico") {
+            candidates.push(favicon.to_string());
+        }
+    }
+
+    for candidate in candidates {
+        let url = match &base {
+            Some(base) => base.join(&candidate).ok(),
+            None => Url::parse(&candidate).ok(),
+        };
+        let Some(url) = url else {
+            continue;
+        };
+        if is_valid_image_url(http_client, url.as_str()).await {
+            return Some(url.to_string());
+        }
+    }
+
+    rss_warn!("[Service] Failed to resolve channel icon: {}", channel_link);
+    None
+}
+
+/// 이미지 주소를 실제로 요청하여 이미지 응답인지 확인합니다.
+pub async fn is_valid_image_url(http_client: &HttpClient, url: &str) -> bool {
+    if url.len() > MAX_IMAGE_URL_LEN {
+        return false;
+    }
+    let Ok(request) = http_client.outbound_get(url) else {
+        return false;
+    };
+
+    let response = match http_client.send(request).await {
+        Ok(response) if response.status().is_success() => response,
+        _ => return false,
+    };
+
+    let is_image = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|content_type| content_type.to_lowercase().starts_with("image/"));
+    let is_small_enough = response
+        .content_length()
+        .is_none_or(|len| len > 0 && len <= MAX_ICON_BYTES);
+
+    is_image && is_small_enough
+}
+
+async fn is_current_image_valid(http_client: &HttpClient, url: Option<&str>) -> bool {
+    match url {
+        Some(url) => is_valid_image_url(http_client, url).await,
+        None => false,
+    }
+}
+
+async fn fetch_page_icon_candidates(http_client: &HttpClient, base: &Url) -> Vec<String> {
+    let Ok(request) = http_client.outbound_get(base.as_str()) else {
+        return vec![];
+    };
+    let html = match http_client.send(request).await {
+        Ok(response) if response.status().is_success() => {
+            url_policy_util::read_body_limited(response, url_policy_util::MAX_RESPONSE_BYTES)
+                .await
+                .unwrap_or_default()
+        }
+        _ => return vec![],
+    };
+    extract_icon_candidates(&String::from_utf8_lossy(&html))
+}
+
+fn extract_icon_candidates(html: &str) -> Vec<String> {
+    let document = Html::parse_document(html);
+    let link_selector = Selector::parse("link[rel][href]").unwrap();
+    let og_image_selector =
+        Selector::parse(r#"meta[property="og:image"], meta[name="og:image"]"#).unwrap();
+
+    let mut icons = vec![];
+    let mut touch_icons = vec![];
+    for element in document.select(&link_selector) {
+        let rel = element.attr("rel").unwrap_or_default().to_lowercase();
+        let href = element.attr("href").unwrap_or_default().trim().to_string();
+        if href.is_empty() {
+            continue;
+        }
+
+        let rels: Vec<&str> = rel.split_whitespace().collect();
+        if rels
+            .iter()
+            .any(|r| *r == "apple-touch-icon" || *r == "apple-touch-icon-precomposed")
+        {
+            touch_icons.push(href);
+        } else if rels.contains(&"icon") {
+            icons.push(href);
+        }
+    }
+
+    let og_images = document
+        .select(&og_image_selector)
+        .filter_map(|element| element.attr("content"))
+        .map(|content| content.trim().to_string())
+        .filter(|content| !content.is_empty());
+
+    icons
+        .into_iter()
+        .chain(touch_icons)
+        .chain(og_images)
+        .collect()
+}
+
+/// 원본 피드를 다시 읽어 채널 제목, 설명, 이미지가 바뀌었으면 갱신합니다.
+///
+/// 채널 아이콘은 피드 이미지가 지난번과 달라졌거나 현재 이미지를 받을 수 없을 때만 다시 찾습니다.
+///
+/// 변경 사항이 있으면 true를 반환합니다.
+pub async fn refresh_channel_metadata(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    http_client: &HttpClient,
+    channel: RssChannel,
+) -> Result<bool, OmniNewsError> {
+    let channel_id = channel.channel_id.unwrap_or_default();
+    let rss_link = channel.channel_rss_link.clone().unwrap_or_default();
+    let feed = channel_service::parse_rss_link_to_channel(http_client, &rss_link).await?;
+
+    let title = Some(feed.title().trim().to_string())
+        .filter(|title| !title.is_empty())
+        .or(channel.channel_title.clone());
+    let description = Some(feed.description().trim().to_string())
+        .filter(|description| !description.is_empty())
+        .or(channel.channel_description.clone());
+
+    let channel_link = Some(feed.link().to_string())
+        .filter(|link| !link.is_empty())
+        .or(channel.channel_link.clone())
+        .unwrap_or_default();
+    let feed_image_url = feed
+        .image()
+        .map(|image| image.url().to_string())
+        .filter(|url| !url.is_empty());
+    let current_image_url = channel
+        .channel_image_url
+        .clone()
+        .filter(|url| !url.is_empty());
+
+    // 피드 이미지가 지난번과 같고 현재 이미지가 살아 있으면 아이콘을 다시 찾지 않는다.
+    let feed_image_changed = feed_image_url != channel.channel_feed_image_url;
+    let image_url = if feed_image_changed
+        || !is_current_image_valid(http_client, current_image_url.as_deref()).await
+    {
+        resolve_channel_icon(http_client, feed_image_url.clone(), &channel_link)
+            .await
+            .or(current_image_url.clone())
+    } else {
+        current_image_url.clone()
+    };
+
+    let text_changed = title != channel.channel_title || description != channel.channel_description;
+    let image_changed = image_url != current_image_url;
+    if !text_changed && !image_changed && !feed_image_changed {
+        return Ok(false);
+    }
+
+    rss_channel_repository::update_rss_channel_metadata(
+        pool,
+        channel_id,
+        title.clone(),
+        description.clone(),
+        image_url.clone(),
+        feed_image_url,
+    )
+    .await
+    .map_err(|e| {
+        rss_error!("[Service] Failed to update rss channel metadata: {:?}", e);
+        OmniNewsError::Database(e)
+    })?;
+
+    if text_changed {
+        let embedding_text =
+            make_embedding_text(&title.unwrap_or_default(), &description.unwrap_or_default());
+        let embedding = NewEmbedding::channel(channel_id);
+        embedding_service::refresh_embedding(pool, embedding_service, embedding_text, embedding)
+            .await?;
+    }
+
+    if let (true, Some(new_image_url)) = (image_changed, image_url) {
+        if let Err(e) = rss_item_repository::update_rss_item_fallback_images_by_channel_id(
+            pool,
+            channel_id,
+            current_image_url.unwrap_or_default(),
+            new_image_url,
+        )
+        .await
+        {
+            rss_error!(
+                "[Service] Failed to update fallback item images of channel {}: {:?}",
+                channel_id,
+                e
+            );
+        }
+    }
+
+    rss_info!("[Service] Refreshed rss channel metadata: {}", channel_id);
+    Ok(true)
+}
+
+/// 모든 채널의 메타데이터를 갱신하고 변경된 채널 수를 반환합니다.
+pub async fn refresh_all_channel_metadata(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    http_client: &HttpClient,
+) -> usize {
+    let channel_ids = match channel_service::find_rss_channel_ids(pool).await {
+        Ok(ids) => ids,
+        Err(_) => return 0,
+    };
+
+    let mut refreshed = 0;
+    for channel_id in channel_ids {
+        let channel = match rss_channel_repository::select_rss_channel_by_id(pool, channel_id).await
+        {
+            Ok(channel) => channel,
+            Err(e) => {
+                rss_error!("[Service] Failed to select rss channel by id: {:?}", e);
+                continue;
+            }
+        };
+        if channel.rss_generator.as_deref() == Some(GENERATED_CHANNEL_GENERATOR) {
+            continue;
+        }
+
+        match refresh_channel_metadata(pool, embedding_service, http_client, channel).await {
+            Ok(true) => refreshed += 1,
+            Ok(false) => {}
+            Err(e) => {
+                rss_warn!(
+                    "[Service] Failed to refresh rss channel metadata {}: {:?}",
+                    channel_id,
+                    e
+                );
+            }
+        }
+    }
+    refreshed
+}
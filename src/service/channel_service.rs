@@ -1,10 +1,8 @@
-use reqwest::Url;
 use rss::Channel;
-use serde_json::Value;
 use sqlx::MySqlPool;
-use thirtyfour::WebDriver;
 
 use crate::{
+    config::http_client::HttpClient,
     dto::{
         rss::{request::CreateRssRequestDto, response::RssChannelResponseDto},
         search::{request::SearchRequestDto, response::SearchResponseDto},
@@ -17,8 +15,11 @@ use crate::{
     },
     repository::rss_channel_repository,
     rss_error, rss_info, rss_warn,
-    service::embedding_service,
-    utils::{annoy_util::load_channel_annoy, embedding_util::EmbeddingService},
+    service::{channel_metadata_service, embedding_service, mute_service},
+    utils::{
+        annoy_util::load_channel_annoy,
+        embedding_util::{make_embedding_text, EmbeddingService},
+    },
 };
 
 use super::item_service;
@@ -26,11 +27,12 @@ use super::item_service;
 pub async fn create_rss_all(
     pool: &MySqlPool,
     model: &EmbeddingService,
+    http_client: &HttpClient,
     links: Vec<CreateRssRequestDto>,
 ) -> Result<bool, OmniNewsError> {
     for link in links {
         rss_info!("[Service] Add : {}", link.rss_link);
-        let _ = create_rss_and_embedding(pool, model, link.rss_link)
+        let _ = create_rss_and_embedding(pool, model, http_client, link.rss_link)
             .await
             .unwrap_or_default();
     }
@@ -40,9 +42,10 @@ pub async fn create_rss_all(
 pub async fn create_rss_and_embedding(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
+    http_client: &HttpClient,
     link: String,
 ) -> Result<i32, OmniNewsError> {
-    let rss_channel = parse_rss_link_to_channel(&link).await?;
+    let rss_channel = parse_rss_link_to_channel(http_client, &link).await?;
     if &rss_channel.title == "Not Found" || rss_channel.title.is_empty() {
         error!(
             "[Service] Failed to parse RSS link: {}, title is empty or not found",
@@ -53,16 +56,27 @@ pub async fn create_rss_and_embedding(
         ));
     }
 
-    create_rss_and_embedding_by_channel(pool, embedding_service, rss_channel, link, false).await
+    create_rss_and_embedding_by_channel(
+        pool,
+        embedding_service,
+        http_client,
+        rss_channel,
+        link,
+        false,
+    )
+    .await
 }
 
-pub async fn create_rss_and_embedding_with_web_driver(
+/// 브라우저로 찾아낸 피드 주소로 생성 채널을 만듭니다.
+///
+/// 피드 본문은 브라우저가 아닌 외부 요청 정책이 적용된 클라이언트로 받습니다.
+pub async fn create_generated_rss_and_embedding(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
+    http_client: &HttpClient,
     link: String,
-    driver: &WebDriver,
 ) -> Result<i32, OmniNewsError> {
-    let rss_channel = parse_rss_link_to_channel_with_web_driver(&link, driver).await?;
+    let rss_channel = parse_rss_link_to_channel(http_client, &link).await?;
 
     if &rss_channel.title == "Not Found" || rss_channel.title.is_empty() {
         error!(
@@ -73,17 +87,41 @@ pub async fn create_rss_and_embedding_with_web_driver(
             "Failed to parse RSS link".to_string(),
         ));
     }
-    create_rss_and_embedding_by_channel(pool, embedding_service, rss_channel, link, true).await
+    create_rss_and_embedding_by_channel(
+        pool,
+        embedding_service,
+        http_client,
+        rss_channel,
+        link,
+        true,
+    )
+    .await
 }
 
 pub async fn create_rss_and_embedding_by_channel(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
-    rss_channel: Channel,
+    http_client: &HttpClient,
+    mut rss_channel: Channel,
     rss_link: String,
     is_generated_channel: bool,
 ) -> Result<i32, OmniNewsError> {
-    let channel = make_rss_channel(&rss_channel, rss_link, is_generated_channel);
+    let mut channel = make_rss_channel(&rss_channel, rss_link, is_generated_channel);
+
+    // 피드 이미지가 없거나 깨진 경우 사이트 아이콘을 찾아 채널과 아이템 대체 이미지로 사용한다.
+    channel.channel_image_url = channel_metadata_service::resolve_channel_icon(
+        http_client,
+        channel.channel_image_url.clone(),
+        rss_channel.link(),
+    )
+    .await
+    .or(channel.channel_image_url);
+    if let Some(image_url) = &channel.channel_image_url {
+        let mut image = rss_channel.image().cloned().unwrap_or_default();
+        image.set_url(image_url.clone());
+        rss_channel.set_image(image);
+    }
+
     let channel_id = store_channel_and_embedding(pool, embedding_service, channel).await?;
 
     let _ = item_service::create_rss_items_and_embedding(
@@ -102,83 +140,15 @@ pub async fn create_rss_and_embedding_by_channel(
     Ok(channel_id)
 }
 
-pub async fn parse_rss_link_to_channel(link: &str) -> Result<Channel, OmniNewsError> {
-    let response = reqwest::get(link).await.map_err(|e| {
-        rss_error!("[Service] Not found url : {}", link);
-        OmniNewsError::Request(e)
-    })?;
-
-    let body = response.text().await.map_err(OmniNewsError::Request)?;
-    Channel::read_from(body.as_bytes()).map_err(|e| {
-        rss_error!("[Service] Failed to read from rss body: {:?}", e);
-        OmniNewsError::ParseRssChannel
-    })
-}
-
-pub async fn parse_rss_link_to_channel_with_web_driver(
+pub async fn parse_rss_link_to_channel(
+    http_client: &HttpClient,
     link: &str,
-    driver: &WebDriver,
 ) -> Result<Channel, OmniNewsError> {
-    if let Ok(u) = Url::parse(link) {
-        let origin = format!("{}://{}/", u.scheme(), u.host_str().unwrap_or_default());
-        let _ = driver.goto(&origin).await;
-    }
-    // async script로 fetch → text 본문 받기
-    // file download이므로, 본문을 text로 변환하여 반환
-    let js = r#"
-                const url = arguments[0];
-                const done = arguments[arguments.length - 1];
-                fetch(url, {
-                    method: 'GET',
-                    headers: {
-                    'Accept': 'application/rss+xml, application/atom+xml, application/xml;q=0.9, text/xml;q=0.8, */*;q=0.1',
-                    'Cache-Control': 'no-cache',
-                    },
-                    credentials: 'include'
-                }).then(async (r) => {
-                    const body = await r.text();
-                    done({
-                        ok: r.ok,
-                        status: r.status,
-                        contentType: r.headers.get('content-type'),
-                        body
-                    });
-                }).catch(e => done({ ok: false, status: 0, contentType: null, body: String(e) }));
-            "#;
-
-    let ret = driver
-        .execute_async(js, vec![Value::String(link.to_string())])
-        .await?;
+    let body = http_client.fetch_text(link).await.map_err(|e| {
+        rss_error!("[Service] Not found url : {}", link);
+        e
+    })?;
 
-    let obj = ret.json().as_object().unwrap();
-    let ok = obj.get("ok").and_then(|v| v.as_bool()).unwrap_or(false);
-    let status = obj.get("status").and_then(|v| v.as_i64()).unwrap_or(0) as i32;
-    let ctype = obj
-        .get("contentType")
-        .and_then(|v| v.as_str())
-        .unwrap_or("")
-        .to_lowercase();
-    let body = obj
-        .get("body")
-        .and_then(|v| v.as_str())
-        .ok_or("no body")
-        .unwrap()
-        .to_string();
-
-    if !ok || status >= 400 {
-        error!(
-            "[Service] Failed to fetch rss link: {}, status: {}, content-type: {}, body: {}",
-            link, status, ctype, body
-        );
-        return Err(OmniNewsError::WebDriverNotFound);
-    }
-    if ctype.contains("text/html") && body.contains("Attention Required") {
-        error!(
-            "[Service] WebDriver blocked by Cloudflare or similar service for link: {}",
-            link
-        );
-        return Err(OmniNewsError::WebDriverNotFound);
-    }
     Channel::read_from(body.as_bytes()).map_err(|e| {
         rss_error!("[Service] Failed to read from rss body: {:?}", e);
         OmniNewsError::ParseRssChannel
@@ -230,18 +200,12 @@ pub async fn store_channel_and_embedding(
         Err(_) => {
             let channel_id = store_rss_channel(pool, rss_channel.clone()).await?;
 
-            let embedding_text = prepare_embedding_text(
+            let embedding_text = make_embedding_text(
                 &rss_channel.channel_title.unwrap_or_default(),
                 &rss_channel.channel_description.unwrap_or_default(),
             );
 
-            let embedding = NewEmbedding {
-                embedding_value: None,
-                channel_id: Some(channel_id),
-                rss_id: None,
-                news_id: None,
-                embedding_source_rank: Some(0),
-            };
+            let embedding = NewEmbedding::channel(channel_id);
             embedding_service::create_embedding(pool, embedding_service, embedding_text, embedding)
                 .await?;
             Ok(channel_id)
@@ -373,63 +337,16 @@ async fn push_rss_channel(
     }
 }
 
-fn prepare_embedding_text(title: &str, description: &str) -> String {
-    // 1. HTML 태그 제거
-    let clean_description = remove_html_tags(description);
-
-    // 2. 구조화된 형식으로 정보 표현
-    let mut text = format!("제목: {}. 내용: {}", title, clean_description);
-
-    // 3. 특수문자 정리 및 중복 공백 제거 - 한글 보존 처리 추가
-    text = text
-        .replace(
-            |c: char| {
-                !c.is_alphanumeric()
-                    && !c.is_whitespace()
-                    && !is_hangul(c)
-                    && c != '.'
-                    && c != ','
-                    && c != ':'
-            },
-            " ",
-        )
-        .replace("  ", " ")
-        .trim()
-        .to_string();
-
-    // 4. 텍스트 길이 제한 (임베딩 모델의 최대 입력 길이 고려)
-    if text.len() > 512 {
-        text.truncate(512);
-    }
-
-    // 5. 제목 반복으로 중요성 강조 (선택적)
-    text = format!("{}. {}", text, title);
-
-    text
-}
-
-// 한글 문자 판별 함수 추가
-fn is_hangul(c: char) -> bool {
-    let cp = c as u32;
-    // 한글 유니코드 범위 (가~힣)
-    (0xAC00..=0xD7A3).contains(&cp) ||
-    // 한글 자음/모음
-    (0x1100..=0x11FF).contains(&cp) ||
-    (0x3130..=0x318F).contains(&cp)
-}
-
-// HTML 태그 제거 함수
-fn remove_html_tags(text: &str) -> String {
-    let re = regex::Regex::new(r"<[^>]*>").unwrap();
-    re.replace_all(text, "").to_string()
-}
-
 // TODO 랭크 50순위 채널에서 20개 랜덤 반환
 pub async fn get_recommend_channel(
     pool: &MySqlPool,
+    user_email: String,
 ) -> Result<Vec<RssChannelResponseDto>, OmniNewsError> {
+    let mute_filter = mute_service::load_mute_filter(pool, user_email).await?;
+
     match rss_channel_repository::select_rss_channels_order_by_channel_rank(pool).await {
-        Ok(res) => {
+        Ok(mut res) => {
+            res.retain(|channel| !mute_filter.is_channel_muted(channel));
             //            let mut rng = rng();
             //            res.shuffle(&mut rng);
             //            Ok(res.into_iter().take(20).collect())
@@ -447,12 +364,13 @@ pub async fn get_recommend_channel(
 
 pub async fn get_rss_preview(
     pool: &MySqlPool,
+    http_client: &HttpClient,
     rss_link: String,
 ) -> Result<RssChannelResponseDto, OmniNewsError> {
     match rss_channel_repository::select_rss_channel_by_rss_link(pool, rss_link.clone()).await {
         Ok(res) => Ok(RssChannelResponseDto::from_model(res)),
         Err(_) => {
-            let rss_channel = parse_rss_link_to_channel(&rss_link).await?;
+            let rss_channel = parse_rss_link_to_channel(http_client, &rss_link).await?;
             let new_channel = make_rss_channel(&rss_channel, rss_link.clone(), false);
             let channel = RssChannel::new(new_channel);
             Ok(RssChannelResponseDto::from_model(channel))
@@ -493,3 +411,53 @@ pub async fn update_rss_channel_rank(
         }
     }
 }
+
+pub async fn find_rss_channel_ids(pool: &MySqlPool) -> Result<Vec<i32>, OmniNewsError> {
+    match rss_channel_repository::select_rss_channel_ids(pool).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            rss_error!("[Service] Failed to select rss channel ids: {:?}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn delete_rss_channel(pool: &MySqlPool, channel_id: i32) -> Result<(), OmniNewsError> {
+    match rss_channel_repository::delete_rss_channel_by_id(pool, channel_id).await {
+        Ok(true) => {
+            rss_info!("[Service] Deleted rss channel: {}", channel_id);
+            Ok(())
+        }
+        Ok(false) => Err(OmniNewsError::NotFound(format!("channel {}", channel_id))),
+        Err(e) => {
+            rss_error!("[Service] Failed to delete rss channel: {:?}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 채널과 채널에 속한 아이템의 임베딩을 다시 계산합니다.
+///
+/// 다시 계산한 아이템 수를 반환합니다.
+pub async fn rebuild_channel_embedding(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    channel_id: i32,
+) -> Result<usize, OmniNewsError> {
+    let channel = rss_channel_repository::select_rss_channel_by_id(pool, channel_id)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select rss channel by id: {:?}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    let embedding_text = make_embedding_text(
+        &channel.channel_title.unwrap_or_default(),
+        &channel.channel_description.unwrap_or_default(),
+    );
+    let embedding = NewEmbedding::channel(channel_id);
+    embedding_service::refresh_embedding(pool, embedding_service, embedding_text, embedding)
+        .await?;
+
+    item_service::rebuild_item_embeddings_by_channel_id(pool, embedding_service, channel_id).await
+}
@@ -4,19 +4,19 @@ use crate::{
     embedding_error,
     model::{embedding::NewEmbedding, error::OmniNewsError},
     repository::embedding_repository,
-    utils::embedding_util::{embedding_sentence, encode_embedding, EmbeddingService},
+    utils::embedding_util::{
+        embedding_sentence, embedding_sentences, encode_embedding, EmbeddingService,
+    },
 };
 
 pub async fn create_embedding(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
     sentence: String,
-    mut embedding: NewEmbedding,
+    embedding: NewEmbedding,
 ) -> Result<i32, OmniNewsError> {
     let embedding_value = embedding_sentence(embedding_service, sentence).await?;
-    let encoded_embedding_value = encode_embedding(&embedding_value);
-
-    embedding.embedding_value = Some(encoded_embedding_value);
+    let embedding = fill_embedding(embedding_service, embedding, &embedding_value);
 
     match embedding_repository::insert_embedding(pool, embedding).await {
         Ok(res) => Ok(res),
@@ -26,3 +26,68 @@ pub async fn create_embedding(
         }
     }
 }
+
+/// 기존 임베딩을 새로 계산한 값으로 교체합니다. (없으면 생성)
+pub async fn refresh_embedding(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    sentence: String,
+    embedding: NewEmbedding,
+) -> Result<(), OmniNewsError> {
+    let embedding_value = embedding_sentence(embedding_service, sentence).await?;
+    let embedding = fill_embedding(embedding_service, embedding, &embedding_value);
+
+    match embedding_repository::upsert_embedding(pool, embedding).await {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            embedding_error!("[Service] Failed to upsert embedding: {}", e);
+            Err(OmniNewsError::Embedding)
+        }
+    }
+}
+
+/// 여러 문장을 한 번에 임베딩해 값과 버전을 채운 임베딩을 만듭니다. 결과는 입력 순서와 같습니다.
+pub async fn make_embeddings(
+    embedding_service: &EmbeddingService,
+    sentences: Vec<String>,
+    embeddings: Vec<NewEmbedding>,
+) -> Result<Vec<NewEmbedding>, OmniNewsError> {
+    let values = embedding_sentences(embedding_service, sentences).await?;
+    Ok(embeddings
+        .into_iter()
+        .zip(values.iter())
+        .map(|(embedding, value)| fill_embedding(embedding_service, embedding, value))
+        .collect())
+}
+
+/// 여러 임베딩을 새로 계산해 한 번에 교체합니다. (없으면 생성)
+pub async fn refresh_embeddings(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    sentences: Vec<String>,
+    embeddings: Vec<NewEmbedding>,
+) -> Result<(), OmniNewsError> {
+    let embeddings = make_embeddings(embedding_service, sentences, embeddings).await?;
+
+    match embedding_repository::upsert_embeddings(pool, embeddings).await {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            embedding_error!("[Service] Failed to upsert embeddings: {}", e);
+            Err(OmniNewsError::Embedding)
+        }
+    }
+}
+
+// 임베딩 값과 함께 현재 모델/문장 형식 버전을 기록한다.
+fn fill_embedding(
+    embedding_service: &EmbeddingService,
+    mut embedding: NewEmbedding,
+    embedding_value: &[f32],
+) -> NewEmbedding {
+    let version = embedding_service.version();
+    embedding.embedding_value = Some(encode_embedding(embedding_value));
+    embedding.embedding_dim = Some(embedding_value.len() as i32);
+    embedding.embedding_model_id = Some(version.model_id);
+    embedding.embedding_text_recipe_version = Some(version.text_recipe_version);
+    embedding
+}
@@ -0,0 +1,117 @@
+use chrono::Utc;
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::feedback::{
+        request::{
+            CreateFeedbackRequestDto, FeedbackResolveRequestDto, FeedbackSearchRequestDto,
+            FeedbackTagRequestDto,
+        },
+        response::FeedbackResponseDto,
+    },
+    model::{error::OmniNewsError, feedback::NewFeedback},
+    repository::feedback_repository,
+    user_error, user_info,
+};
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+const MAX_PAGE_SIZE: i64 = 100;
+const MAX_FEEDBACK_CONTENT_LEN: usize = 2000;
+
+pub async fn create_feedback(
+    pool: &MySqlPool,
+    user_email: String,
+    feedback: CreateFeedbackRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let content = feedback.feedback_content.clone().unwrap_or_default();
+    if content.trim().is_empty() || content.chars().count() > MAX_FEEDBACK_CONTENT_LEN {
+        return Err(OmniNewsError::InvalidRequest(
+            "feedback content must be 1..=2000 characters".into(),
+        ));
+    }
+
+    let new_feedback = NewFeedback::new(user_email.clone(), feedback, Utc::now());
+    match feedback_repository::insert_feedback(pool, new_feedback).await {
+        Ok(feedback_id) => {
+            user_info!(
+                "[Service] Feedback created: {} by {}",
+                feedback_id,
+                user_email
+            );
+            Ok(feedback_id)
+        }
+        Err(e) => {
+            user_error!("[Service] Failed to insert feedback: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn find_feedbacks(
+    pool: &MySqlPool,
+    request: FeedbackSearchRequestDto,
+) -> Result<Vec<FeedbackResponseDto>, OmniNewsError> {
+    let page_size = request
+        .page_size
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let offset = (request.page.unwrap_or(1).max(1) - 1) * page_size;
+
+    match feedback_repository::select_feedbacks(pool, request.feedback_status, page_size, offset)
+        .await
+    {
+        Ok(feedbacks) => Ok(FeedbackResponseDto::from_model_list(feedbacks)),
+        Err(e) => {
+            user_error!("[Service] Failed to select feedbacks: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 피드백의 태그를 요청한 목록으로 교체합니다.
+pub async fn tag_feedback(
+    pool: &MySqlPool,
+    request: FeedbackTagRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let feedback_id = request.feedback_id.unwrap_or_default();
+    let tags = request
+        .feedback_tags
+        .unwrap_or_default()
+        .into_iter()
+        .map(|tag| tag.trim().replace(',', ""))
+        .filter(|tag| !tag.is_empty())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    match feedback_repository::update_feedback_tags(pool, feedback_id, tags).await {
+        Ok(res) => Ok(res),
+        Err(sqlx::Error::RowNotFound) => {
+            Err(OmniNewsError::NotFound(format!("feedback {}", feedback_id)))
+        }
+        Err(e) => {
+            user_error!("[Service] Failed to update feedback tags: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn resolve_feedback(
+    pool: &MySqlPool,
+    request: FeedbackResolveRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let feedback_id = request.feedback_id.unwrap_or_default();
+
+    match feedback_repository::update_feedback_resolved(pool, feedback_id, Utc::now()).await {
+        Ok(res) => {
+            user_info!("[Service] Feedback resolved: {}", feedback_id);
+            Ok(res)
+        }
+        Err(sqlx::Error::RowNotFound) => {
+            Err(OmniNewsError::NotFound(format!("feedback {}", feedback_id)))
+        }
+        Err(e) => {
+            user_error!("[Service] Failed to resolve feedback: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
@@ -1,28 +1,45 @@
+use std::collections::HashSet;
+
 use sqlx::MySqlPool;
 
 use crate::{
     dto::folder::{
         request::{
             ChannelFolderRequestDto, CreateFolderRequestDto, DeleteFolderRequestDto,
+            FolderChannelOrderRequestDto, FolderItemsRequestDto, FolderOrderRequestDto,
             UpdateFolderRequestDto,
         },
-        response::RssFolderResponseDto,
+        response::{FolderItemsResponseDto, RssFolderResponseDto},
+    },
+    folder_error, folder_warn,
+    model::{
+        error::OmniNewsError,
+        folder::{ItemCursor, RssFolder},
     },
-    folder_error,
-    model::error::OmniNewsError,
-    repository::folder_repository,
+    repository::{folder_repository, subscribe_repository},
 };
 
-use super::user_service;
+use super::{mute_service, smart_folder_service, user_service};
+
+const DEFAULT_ITEMS_LIMIT: i64 = 20;
+const MAX_ITEMS_LIMIT: i64 = 100;
 
 pub async fn create_folder(
     pool: &MySqlPool,
     user_email: String,
     folder: CreateFolderRequestDto,
 ) -> Result<i32, OmniNewsError> {
+    let folder_name = validate_folder_name(folder.folder_name)?
+        .ok_or_else(|| OmniNewsError::InvalidRequest("folder_name is required".to_string()))?;
     let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
 
-    match folder_repository::insert_folder(pool, user_id, folder.folder_name.unwrap()).await {
+    if let Some(parent_folder_id) = folder.parent_folder_id {
+        validate_parent_folder(pool, user_id, None, parent_folder_id).await?;
+    }
+
+    match folder_repository::insert_folder(pool, user_id, folder_name, folder.parent_folder_id)
+        .await
+    {
         Ok(res) => Ok(res),
         Err(e) => {
             folder_error!("[Service] Failed to create folder: {}", e);
@@ -33,16 +50,38 @@ pub async fn create_folder(
 
 pub async fn add_channel_to_folder(
     pool: &MySqlPool,
+    user_email: String,
     channel_folder_id: ChannelFolderRequestDto,
 ) -> Result<(), OmniNewsError> {
-    match folder_repository::insert_channel_to_folder(
-        pool,
-        channel_folder_id.folder_id.unwrap(),
-        channel_folder_id.channel_id.unwrap(),
-    )
-    .await
-    {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let folder_id = channel_folder_id.folder_id.unwrap_or_default();
+    let channel_id = channel_folder_id.channel_id.unwrap_or_default();
+
+    find_owned_folder(pool, user_id, folder_id).await?;
+
+    match subscribe_repository::is_already_subscribe_channel(pool, user_id, channel_id).await {
+        Ok(true) => {}
+        Ok(false) => {
+            folder_warn!(
+                "[Service] Channel {} is not subscribed by user {}",
+                channel_id,
+                user_id
+            );
+            return Err(OmniNewsError::InvalidRequest(
+                "Only subscribed channels can be added to a folder".to_string(),
+            ));
+        }
+        Err(e) => {
+            folder_error!("[Service] Failed to check subscription: {}", e);
+            return Err(OmniNewsError::Database(e));
+        }
+    }
+
+    match folder_repository::insert_channel_to_folder(pool, folder_id, channel_id).await {
         Ok(_) => Ok(()),
+        Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+            Err(OmniNewsError::AlreadyExists)
+        }
         Err(e) => {
             folder_error!("[Service] Failed to add channel in folder: {}", e);
             Err(OmniNewsError::Database(e))
@@ -63,17 +102,21 @@ pub async fn fetch_folders(
                 match folder_repository::select_channels_in_folder(pool, folder.folder_id.unwrap())
                     .await
                 {
-                    Ok(channels) => result.push(RssFolderResponseDto::new(
-                        folder.folder_id,
-                        folder.folder_name,
-                        channels,
-                    )),
+                    Ok(channels) => result.push(RssFolderResponseDto::new(folder, channels)),
                     Err(e) => {
                         folder_error!("[Service] Failed to fetch channels in folder: {}", e);
                         return Err(OmniNewsError::Database(e));
                     }
                 }
             }
+
+            // 스마트 폴더는 일반 폴더 뒤에 붙인다.
+            let smart_folders = smart_folder_service::find_smart_folders(pool, user_id).await?;
+            result.extend(
+                smart_folders
+                    .into_iter()
+                    .map(RssFolderResponseDto::from_smart_folder),
+            );
             Ok(result)
         }
         Err(e) => {
@@ -85,12 +128,34 @@ pub async fn fetch_folders(
 
 pub async fn update_folder(
     pool: &MySqlPool,
+    user_email: String,
     folder: UpdateFolderRequestDto,
 ) -> Result<i32, OmniNewsError> {
+    // 이름은 요청에 있을 때만 바꾼다. (상위 폴더만 옮기는 요청)
+    let folder_name = validate_folder_name(folder.folder_name)?;
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let folder_id = folder.folder_id.unwrap_or_default();
+
+    find_owned_folder(pool, user_id, folder_id).await?;
+
+    // 상위 폴더는 요청에 있을 때만 바꾼다. (이름만 바꿀 때 하위 폴더가 최상위로 올라가지 않도록)
+    let move_to_top_level = folder.move_to_top_level.unwrap_or(false);
+    if move_to_top_level && folder.parent_folder_id.is_some() {
+        return Err(OmniNewsError::InvalidRequest(
+            "parent_folder_id and move_to_top_level cannot be used together".to_string(),
+        ));
+    }
+    if let Some(parent_folder_id) = folder.parent_folder_id {
+        validate_parent_folder(pool, user_id, Some(folder_id), parent_folder_id).await?;
+    }
+
     match folder_repository::update_folder(
         pool,
-        folder.folder_id.unwrap(),
-        folder.folder_name.unwrap(),
+        user_id,
+        folder_id,
+        folder_name,
+        move_to_top_level || folder.parent_folder_id.is_some(),
+        folder.parent_folder_id,
     )
     .await
     {
@@ -102,12 +167,27 @@ pub async fn update_folder(
     }
 }
 
+/// 폴더 이름의 앞뒤 공백을 제거하고, 빈 이름은 거부합니다.
+fn validate_folder_name(folder_name: Option<String>) -> Result<Option<String>, OmniNewsError> {
+    match folder_name.map(|name| name.trim().to_string()) {
+        Some(name) if name.is_empty() => Err(OmniNewsError::InvalidRequest(
+            "folder_name must not be empty".to_string(),
+        )),
+        name => Ok(name),
+    }
+}
+
 pub async fn delete_folder(
     pool: &MySqlPool,
+    user_email: String,
     folder_id: DeleteFolderRequestDto,
 ) -> Result<(), OmniNewsError> {
-    let folder_id = folder_id.folder_id.unwrap();
-    match folder_repository::delete_folder(pool, folder_id).await {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let folder_id = folder_id.folder_id.unwrap_or_default();
+
+    find_owned_folder(pool, user_id, folder_id).await?;
+
+    match folder_repository::delete_folder(pool, user_id, folder_id).await {
         Ok(_) => Ok(()),
         Err(e) => {
             folder_error!("[Service] Failed to delete folder: {}", e);
@@ -118,12 +198,19 @@ pub async fn delete_folder(
 
 pub async fn delete_channel_from_folder(
     pool: &MySqlPool,
+    user_email: String,
     channel_folder_id: ChannelFolderRequestDto,
 ) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let folder_id = channel_folder_id.folder_id.unwrap_or_default();
+
+    find_owned_folder(pool, user_id, folder_id).await?;
+
     match folder_repository::delete_channel_from_folder(
         pool,
-        channel_folder_id.folder_id.unwrap(),
-        channel_folder_id.channel_id.unwrap(),
+        user_id,
+        folder_id,
+        channel_folder_id.channel_id.unwrap_or_default(),
     )
     .await
     {
@@ -134,3 +221,180 @@ pub async fn delete_channel_from_folder(
         }
     }
 }
+
+/// 폴더와 하위 폴더에 담긴 채널의 아이템을 최신 순으로 조회합니다.
+///
+/// 커서는 `<rss_pub_date unix timestamp>_<rss_id>` 형식이며 마지막으로 받은 아이템을 가리킵니다.
+pub async fn fetch_folder_items(
+    pool: &MySqlPool,
+    user_email: String,
+    folder_id: i32,
+    request: FolderItemsRequestDto,
+) -> Result<FolderItemsResponseDto, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email.clone()).await?;
+    find_owned_folder(pool, user_id, folder_id).await?;
+    let mute_filter = mute_service::load_mute_filter(pool, user_email).await?;
+
+    let limit = request
+        .limit
+        .unwrap_or(DEFAULT_ITEMS_LIMIT)
+        .clamp(1, MAX_ITEMS_LIMIT);
+    let cursor = match request.cursor.as_deref() {
+        Some(cursor) => ItemCursor::parse(cursor)?,
+        None => ItemCursor::first(),
+    };
+
+    match mute_service::fill_item_page(&mute_filter, cursor, limit, |cursor, limit| {
+        folder_repository::select_items_in_folder(pool, folder_id, cursor, limit)
+    })
+    .await
+    {
+        Ok((items, next_cursor)) => Ok(FolderItemsResponseDto::new(items, next_cursor)),
+        Err(e) => {
+            folder_error!("[Service] Failed to fetch items in folder: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn reorder_folders(
+    pool: &MySqlPool,
+    user_email: String,
+    request: FolderOrderRequestDto,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let folder_ids = request.folder_ids.unwrap_or_default();
+
+    let owned_folder_ids: HashSet<i32> =
+        match folder_repository::select_folders(pool, user_id).await {
+            Ok(folders) => folders.into_iter().filter_map(|f| f.folder_id).collect(),
+            Err(e) => {
+                folder_error!("[Service] Failed to fetch folders: {}", e);
+                return Err(OmniNewsError::Database(e));
+            }
+        };
+    if folder_ids.iter().any(|id| !owned_folder_ids.contains(id)) {
+        return Err(OmniNewsError::Forbidden(
+            "Folder belongs to another user".to_string(),
+        ));
+    }
+
+    match folder_repository::update_folder_orders(pool, user_id, folder_ids).await {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            folder_error!("[Service] Failed to reorder folders: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn reorder_channels_in_folder(
+    pool: &MySqlPool,
+    user_email: String,
+    request: FolderChannelOrderRequestDto,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let folder_id = request.folder_id.unwrap_or_default();
+
+    find_owned_folder(pool, user_id, folder_id).await?;
+
+    match folder_repository::update_channel_orders(
+        pool,
+        folder_id,
+        request.channel_ids.unwrap_or_default(),
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            folder_error!("[Service] Failed to reorder channels in folder: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 폴더가 없으면 NotFound, 다른 사용자의 폴더면 Forbidden을 반환한다.
+async fn find_owned_folder(
+    pool: &MySqlPool,
+    user_id: i32,
+    folder_id: i32,
+) -> Result<RssFolder, OmniNewsError> {
+    match folder_repository::select_folder_by_id(pool, folder_id).await {
+        Ok(folder) if folder.user_id == Some(user_id) => Ok(folder),
+        Ok(_) => {
+            folder_warn!(
+                "[Service] User {} tried to access folder {} of another user",
+                user_id,
+                folder_id
+            );
+            Err(OmniNewsError::Forbidden(
+                "Folder belongs to another user".to_string(),
+            ))
+        }
+        Err(sqlx::Error::RowNotFound) => {
+            Err(OmniNewsError::NotFound("Folder not found".to_string()))
+        }
+        Err(e) => {
+            folder_error!("[Service] Failed to find folder: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 폴더는 한 단계까지만 중첩할 수 있다.
+///
+/// 상위 폴더는 최상위 폴더여야 하고, 하위 폴더를 가진 폴더는 다른 폴더 아래로 옮길 수 없다.
+async fn validate_parent_folder(
+    pool: &MySqlPool,
+    user_id: i32,
+    folder_id: Option<i32>,
+    parent_folder_id: i32,
+) -> Result<(), OmniNewsError> {
+    if folder_id == Some(parent_folder_id) {
+        return Err(OmniNewsError::InvalidRequest(
+            "A folder cannot be its own parent".to_string(),
+        ));
+    }
+
+    let parent = find_owned_folder(pool, user_id, parent_folder_id).await?;
+    if parent.parent_folder_id.is_some() {
+        return Err(OmniNewsError::InvalidRequest(
+            "Folders can only be nested one level deep".to_string(),
+        ));
+    }
+
+    if let Some(folder_id) = folder_id {
+        match folder_repository::count_child_folders(pool, folder_id).await {
+            Ok(0) => {}
+            Ok(_) => {
+                return Err(OmniNewsError::InvalidRequest(
+                    "A folder with sub folders cannot be nested".to_string(),
+                ))
+            }
+            Err(e) => {
+                folder_error!("[Service] Failed to count sub folders: {}", e);
+                return Err(OmniNewsError::Database(e));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_folder_name() {
+        assert_eq!(validate_folder_name(None).unwrap(), None);
+        assert_eq!(
+            validate_folder_name(Some("  Development ".to_string())).unwrap(),
+            Some("Development".to_string())
+        );
+        assert!(matches!(
+            validate_folder_name(Some("   ".to_string())),
+            Err(OmniNewsError::InvalidRequest(_))
+        ));
+    }
+}
@@ -0,0 +1,448 @@
+use std::{
+    collections::HashMap,
+    env,
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+
+use jsonwebtoken::{decode, decode_header, jwk::JwkSet, Algorithm, DecodingKey, Validation};
+use lazy_static::lazy_static;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    config::http_client::HttpClient, model::error::OmniNewsError, user_error, user_info, user_warn,
+};
+
+// JWKS는 1시간 동안 캐시, kid를 찾지 못하면 다시 가져온다.
+const JWKS_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+// 알 수 없는 kid로 JWKS를 반복해서 가져오지 않도록 최소 간격을 둔다.
+const JWKS_MIN_REFETCH_INTERVAL: Duration = Duration::from_secs(60);
+
+lazy_static! {
+    static ref JWKS_CACHE: RwLock<HashMap<String, (Instant, JwkSet)>> = RwLock::new(HashMap::new());
+}
+
+/// 소셜 로그인 제공자에서 검증된 사용자 정보
+#[derive(Debug, Clone)]
+pub struct VerifiedIdentity {
+    pub provider_id: String,
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdTokenClaims {
+    sub: String,
+    email: Option<String>,
+    // Apple은 "true" 문자열, Google은 bool로 내려준다.
+    email_verified: Option<Value>,
+}
+
+struct OidcProvider {
+    jwks_url: String,
+    issuers: Vec<String>,
+    audiences: Vec<String>,
+}
+
+struct KakaoProvider {
+    token_info_url: String,
+    user_me_url: String,
+    app_id: String,
+}
+
+/// 제공자의 ID 토큰(Kakao는 access token)을 검증합니다.
+pub async fn verify_identity(
+    http_client: &HttpClient,
+    provider: &str,
+    token: &str,
+) -> Result<VerifiedIdentity, OmniNewsError> {
+    if token.is_empty() {
+        user_error!("[Service] Identity token is missing: provider={}", provider);
+        return Err(OmniNewsError::IdentityToken("missing token".into()));
+    }
+
+    match provider {
+        "google" => verify_id_token(http_client, &google_provider()?, token).await,
+        "apple" => verify_id_token(http_client, &apple_provider()?, token).await,
+        "kakao" => verify_kakao_token(http_client, &kakao_provider()?, token).await,
+        _ => {
+            user_error!("[Service] Unsupported login provider: {}", provider);
+            Err(OmniNewsError::IdentityToken(format!(
+                "unsupported provider: {}",
+                provider
+            )))
+        }
+    }
+}
+
+fn google_provider() -> Result<OidcProvider, OmniNewsError> {
+    Ok(OidcProvider {
+        jwks_url: env::var("GOOGLE_JWKS_URL")
+            .unwrap_or_else(|_| "https://www.googleapis.com/oauth2/v3/certs".to_string()),
+        issuers: env_list("GOOGLE_ISSUERS").unwrap_or_else(|| {
+            vec![
+                "https://accounts.google.com".to_string(),
+                "accounts.google.com".to_string(),
+            ]
+        }),
+        audiences: env_list("GOOGLE_CLIENT_IDS")
+            .ok_or_else(|| OmniNewsError::Config("GOOGLE_CLIENT_IDS not set".into()))?,
+    })
+}
+
+fn apple_provider() -> Result<OidcProvider, OmniNewsError> {
+    Ok(OidcProvider {
+        jwks_url: env::var("APPLE_JWKS_URL")
+            .unwrap_or_else(|_| "https://appleid.apple.com/auth/keys".to_string()),
+        issuers: env_list("APPLE_ISSUERS")
+            .unwrap_or_else(|| vec!["https://appleid.apple.com".to_string()]),
+        audiences: env_list("APPLE_CLIENT_IDS")
+            .or_else(|| env_list("APPLE_BUNDLE_ID"))
+            .ok_or_else(|| OmniNewsError::Config("APPLE_CLIENT_IDS not set".into()))?,
+    })
+}
+
+fn kakao_provider() -> Result<KakaoProvider, OmniNewsError> {
+    Ok(KakaoProvider {
+        token_info_url: env::var("KAKAO_TOKEN_INFO_URL")
+            .unwrap_or_else(|_| "https://kapi.kakao.com/v1/user/access_token_info".to_string()),
+        user_me_url: env::var("KAKAO_USER_ME_URL")
+            .unwrap_or_else(|_| "https://kapi.kakao.com/v2/user/me".to_string()),
+        app_id: env::var("KAKAO_APP_ID")
+            .map_err(|_| OmniNewsError::Config("KAKAO_APP_ID not set".into()))?,
+    })
+}
+
+fn env_list(key: &str) -> Option<Vec<String>> {
+    let values = env::var(key)
+        .ok()?
+        .split(',')
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect::<Vec<_>>();
+
+    if values.is_empty() {
+        None
+    } else {
+        Some(values)
+    }
+}
+
+async fn verify_id_token(
+    http_client: &HttpClient,
+    provider: &OidcProvider,
+    token: &str,
+) -> Result<VerifiedIdentity, OmniNewsError> {
+    let header = decode_header(token).map_err(|e| {
+        user_error!("[Service] Failed to decode id token header: {}", e);
+        OmniNewsError::IdentityToken("invalid token header".into())
+    })?;
+    let kid = header
+        .kid
+        .ok_or_else(|| OmniNewsError::IdentityToken("missing kid".into()))?;
+
+    let decoding_key = find_decoding_key(http_client, &provider.jwks_url, &kid).await?;
+
+    let mut validation = Validation::new(Algorithm::RS256);
+    validation.set_issuer(&provider.issuers);
+    validation.set_audience(&provider.audiences);
+    validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+    let claims = decode::<IdTokenClaims>(token, &decoding_key, &validation)
+        .map_err(|e| {
+            user_warn!("[Service] Id token validation failed: {}", e);
+            OmniNewsError::IdentityToken(e.to_string())
+        })?
+        .claims;
+
+    let email_verified = match &claims.email_verified {
+        Some(Value::Bool(v)) => *v,
+        Some(Value::String(v)) => v == "true",
+        _ => false,
+    };
+
+    Ok(VerifiedIdentity {
+        provider_id: claims.sub,
+        email: claims.email.filter(|_| email_verified),
+    })
+}
+
+async fn find_decoding_key(
+    http_client: &HttpClient,
+    jwks_url: &str,
+    kid: &str,
+) -> Result<DecodingKey, OmniNewsError> {
+    let (cached, recently_fetched) = {
+        let cache = JWKS_CACHE.read().unwrap();
+        let entry = cache
+            .get(jwks_url)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < JWKS_CACHE_TTL);
+        (
+            entry.and_then(|(_, jwks)| jwks.find(kid).cloned()),
+            entry.is_some_and(|(fetched_at, _)| fetched_at.elapsed() < JWKS_MIN_REFETCH_INTERVAL),
+        )
+    };
+
+    let jwk = match cached {
+        Some(jwk) => jwk,
+        None if recently_fetched => {
+            user_warn!("[Service] Unknown key id: {}", kid);
+            return Err(OmniNewsError::IdentityToken("unknown kid".into()));
+        }
+        None => {
+            // 키 교체(rotation)를 고려해 캐시에 없으면 다시 가져온다.
+            let jwks = fetch_jwks(http_client, jwks_url).await?;
+            let jwk = jwks.find(kid).cloned();
+            JWKS_CACHE
+                .write()
+                .unwrap()
+                .insert(jwks_url.to_string(), (Instant::now(), jwks));
+            jwk.ok_or_else(|| {
+                user_warn!("[Service] Unknown key id: {}", kid);
+                OmniNewsError::IdentityToken("unknown kid".into())
+            })?
+        }
+    };
+
+    DecodingKey::from_jwk(&jwk).map_err(|e| {
+        user_error!("[Service] Failed to build decoding key from jwk: {}", e);
+        OmniNewsError::IdentityToken("invalid jwk".into())
+    })
+}
+
+async fn fetch_jwks(http_client: &HttpClient, jwks_url: &str) -> Result<JwkSet, OmniNewsError> {
+    user_info!("[Service] Fetch JWKS: {}", jwks_url);
+    let response = http_client
+        .send(http_client.get(jwks_url))
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to fetch JWKS: {}", e);
+            e
+        })?;
+
+    response.json::<JwkSet>().await.map_err(|e| {
+        user_error!("[Service] Failed to parse JWKS: {}", e);
+        OmniNewsError::JsonParseError("Failed to parse JWKS".into())
+    })
+}
+
+/// Kakao는 토큰 정보 조회(introspection)로 앱 ID와 사용자 ID를 확인합니다.
+async fn verify_kakao_token(
+    http_client: &HttpClient,
+    provider: &KakaoProvider,
+    token: &str,
+) -> Result<VerifiedIdentity, OmniNewsError> {
+    let token_info = fetch_kakao_json(http_client, &provider.token_info_url, token).await?;
+    let token_app_id = token_info
+        .get("app_id")
+        .and_then(|v| v.as_i64())
+        .map(|v| v.to_string())
+        .unwrap_or_default();
+    if token_app_id != provider.app_id {
+        user_warn!(
+            "[Service] Kakao token issued for another app: {}",
+            token_app_id
+        );
+        return Err(OmniNewsError::IdentityToken("audience mismatch".into()));
+    }
+    let provider_id = token_info
+        .get("id")
+        .and_then(|v| v.as_i64())
+        .map(|v| v.to_string())
+        .ok_or_else(|| OmniNewsError::IdentityToken("missing kakao user id".into()))?;
+
+    let user_me = fetch_kakao_json(http_client, &provider.user_me_url, token).await?;
+    let account = user_me.get("kakao_account");
+    let email_verified = account
+        .and_then(|a| a.get("is_email_verified"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let email = account
+        .and_then(|a| a.get("email"))
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string())
+        .filter(|_| email_verified);
+
+    Ok(VerifiedIdentity { provider_id, email })
+}
+
+async fn fetch_kakao_json(
+    http_client: &HttpClient,
+    url: &str,
+    token: &str,
+) -> Result<Value, OmniNewsError> {
+    let response = http_client
+        .send(http_client.get(url).bearer_auth(token))
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to request kakao api: {}", e);
+            e
+        })?;
+
+    if !response.status().is_success() {
+        user_warn!(
+            "[Service] Kakao token rejected: {} - {}",
+            url,
+            response.status()
+        );
+        return Err(OmniNewsError::IdentityToken("kakao token rejected".into()));
+    }
+
+    response.json::<Value>().await.map_err(|e| {
+        user_error!("[Service] Failed to parse kakao response: {}", e);
+        OmniNewsError::JsonParseError("Failed to parse kakao response".into())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::http_client::HttpClientConfig, utils::test_http_server::TestHttpServer};
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::json;
+
+    const PRIVATE_KEY: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/identity/rsa_private.pem"
+    ));
+    const JWKS: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/identity/jwks.json"
+    ));
+    const ISSUER: &str = "https://issuer.test";
+    const AUDIENCE: &str = "com.kdh.omninews";
+
+    async fn oidc_server() -> (TestHttpServer, OidcProvider) {
+        let server = TestHttpServer::start().await;
+        server.route("/jwks", 200, JWKS);
+        let provider = OidcProvider {
+            jwks_url: server.url("/jwks"),
+            issuers: vec![ISSUER.to_string()],
+            audiences: vec![AUDIENCE.to_string()],
+        };
+        (server, provider)
+    }
+
+    fn sign(kid: &str, claims: Value) -> String {
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(kid.to_string());
+        let key = EncodingKey::from_rsa_pem(PRIVATE_KEY.as_bytes()).unwrap();
+        encode(&header, &claims, &key).unwrap()
+    }
+
+    fn claims(iss: &str, aud: &str, exp_offset: i64) -> Value {
+        json!({
+            "sub": "provider-user-1",
+            "iss": iss,
+            "aud": aud,
+            "exp": chrono::Utc::now().timestamp() + exp_offset,
+            "email": "user@example.com",
+            "email_verified": "true",
+        })
+    }
+
+    fn http_client() -> HttpClient {
+        HttpClient::new(HttpClientConfig::default())
+    }
+
+    #[tokio::test]
+    async fn verifies_valid_id_token() {
+        let (_server, provider) = oidc_server().await;
+        let token = sign("test-key", claims(ISSUER, AUDIENCE, 3600));
+
+        let identity = verify_id_token(&http_client(), &provider, &token)
+            .await
+            .unwrap();
+
+        assert_eq!(identity.provider_id, "provider-user-1");
+        assert_eq!(identity.email.as_deref(), Some("user@example.com"));
+    }
+
+    #[tokio::test]
+    async fn rejects_wrong_issuer() {
+        let (_server, provider) = oidc_server().await;
+        let token = sign("test-key", claims("https://evil.test", AUDIENCE, 3600));
+
+        let result = verify_id_token(&http_client(), &provider, &token).await;
+
+        assert!(matches!(result, Err(OmniNewsError::IdentityToken(_))));
+    }
+
+    #[tokio::test]
+    async fn rejects_wrong_audience() {
+        let (_server, provider) = oidc_server().await;
+        let token = sign("test-key", claims(ISSUER, "com.other.app", 3600));
+
+        let result = verify_id_token(&http_client(), &provider, &token).await;
+
+        assert!(matches!(result, Err(OmniNewsError::IdentityToken(_))));
+    }
+
+    #[tokio::test]
+    async fn rejects_expired_token() {
+        let (_server, provider) = oidc_server().await;
+        let token = sign("test-key", claims(ISSUER, AUDIENCE, -3600));
+
+        let result = verify_id_token(&http_client(), &provider, &token).await;
+
+        assert!(matches!(result, Err(OmniNewsError::IdentityToken(_))));
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_kid_without_refetching() {
+        let (server, provider) = oidc_server().await;
+        let client = http_client();
+
+        let valid = sign("test-key", claims(ISSUER, AUDIENCE, 3600));
+        verify_id_token(&client, &provider, &valid).await.unwrap();
+
+        let unknown = sign("rotated-key", claims(ISSUER, AUDIENCE, 3600));
+        for _ in 0..3 {
+            match verify_id_token(&client, &provider, &unknown).await {
+                Err(OmniNewsError::IdentityToken(msg)) => assert_eq!(msg, "unknown kid"),
+                other => panic!("unexpected result: {:?}", other.map(|i| i.provider_id)),
+            }
+        }
+
+        assert_eq!(server.hits("/jwks"), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_kakao_token_when_introspection_fails() {
+        let server = TestHttpServer::start().await;
+        server.route("/token_info", 401, r#"{"code":-401}"#);
+        let provider = KakaoProvider {
+            token_info_url: server.url("/token_info"),
+            user_me_url: server.url("/me"),
+            app_id: "1234".to_string(),
+        };
+
+        let result = verify_kakao_token(&http_client(), &provider, "kakao-token").await;
+
+        assert!(matches!(result, Err(OmniNewsError::IdentityToken(_))));
+        assert_eq!(server.hits("/me"), 0);
+    }
+
+    #[tokio::test]
+    async fn verifies_kakao_token() {
+        let server = TestHttpServer::start().await;
+        server.route("/token_info", 200, r#"{"id":42,"app_id":1234}"#);
+        server.route(
+            "/me",
+            200,
+            r#"{"id":42,"kakao_account":{"email":"user@example.com","is_email_verified":true}}"#,
+        );
+        let provider = KakaoProvider {
+            token_info_url: server.url("/token_info"),
+            user_me_url: server.url("/me"),
+            app_id: "1234".to_string(),
+        };
+
+        let identity = verify_kakao_token(&http_client(), &provider, "kakao-token")
+            .await
+            .unwrap();
+
+        assert_eq!(identity.provider_id, "42");
+        assert_eq!(identity.email.as_deref(), Some("user@example.com"));
+    }
+}
@@ -0,0 +1,407 @@
+use std::{
+    collections::HashMap,
+    env,
+    io::Cursor,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use image::{
+    codecs::jpeg::JpegEncoder, imageops::FilterType, DynamicImage, ImageReader, Limits, RgbImage,
+};
+use reqwest::header::CONTENT_TYPE;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    config::http_client::HttpClient,
+    model::{
+        error::OmniNewsError,
+        image::{ProxiedImage, ProxiedImageFormat},
+    },
+    server_error, server_info, server_warn,
+    utils::{image_proxy_util::verify_signed_image_url, url_policy_util},
+};
+
+// 원본 이미지 최대 크기 (10MB)
+const MAX_SOURCE_BYTES: u64 = 10 * 1024 * 1024;
+// 디코딩을 허용하는 원본 이미지 최대 가로/세로 픽셀
+const MAX_SOURCE_DIMENSION: u32 = 8000;
+const JPEG_QUALITY: u8 = 80;
+// 임의의 크기로 캐시가 채워지지 않도록 요청 크기를 아래 단계로 올림한다.
+const SIZE_STEPS: [u32; 9] = [64, 128, 256, 384, 512, 768, 1024, 1536, 2048];
+const DEFAULT_CACHE_DIR: &str = "../resources/image_cache";
+// 기본 디스크 캐시 한도 (512MB)
+const DEFAULT_CACHE_MAX_BYTES: u64 = 512 * 1024 * 1024;
+
+/// 리사이즈한 이미지를 디스크에 저장하고 최근에 사용하지 않은 파일부터 지우는 캐시
+///
+/// - `IMAGE_CACHE_DIR` : 캐시 디렉터리
+/// - `IMAGE_CACHE_MAX_BYTES` : 캐시 최대 크기 (바이트)
+#[derive(Clone)]
+pub struct ImageCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    index: Arc<Mutex<CacheIndex>>,
+}
+
+#[derive(Default)]
+struct CacheIndex {
+    entries: HashMap<String, CacheEntry>,
+    total_bytes: u64,
+    tick: u64,
+}
+
+struct CacheEntry {
+    size: u64,
+    last_access: u64,
+}
+
+impl ImageCache {
+    pub fn from_env() -> Self {
+        let dir = env::var("IMAGE_CACHE_DIR").unwrap_or_else(|_| DEFAULT_CACHE_DIR.to_string());
+        let max_bytes = env::var("IMAGE_CACHE_MAX_BYTES")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_CACHE_MAX_BYTES);
+        Self::new(PathBuf::from(dir), max_bytes)
+    }
+
+    /// 디렉터리에 남아 있는 캐시 파일을 수정 시각 순서로 색인한다.
+    pub fn new(dir: PathBuf, max_bytes: u64) -> Self {
+        if let Err(e) = std::fs::create_dir_all(&dir) {
+            server_error!("[Image] Failed to create image cache dir {:?}: {}", dir, e);
+        }
+
+        let mut files: Vec<(String, u64, std::time::SystemTime)> = std::fs::read_dir(&dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .filter_map(|entry| {
+                        let metadata = entry.metadata().ok()?;
+                        if !metadata.is_file() {
+                            return None;
+                        }
+                        // 쓰다 만 임시 파일은 정리한다.
+                        if entry.file_name().to_string_lossy().ends_with(".tmp") {
+                            let _ = std::fs::remove_file(entry.path());
+                            return None;
+                        }
+                        Some((
+                            entry.file_name().to_string_lossy().to_string(),
+                            metadata.len(),
+                            metadata.modified().ok()?,
+                        ))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        files.sort_by_key(|(_, _, modified)| *modified);
+
+        let mut index = CacheIndex::default();
+        for (name, size, _) in files {
+            index.tick += 1;
+            index.total_bytes += size;
+            index.entries.insert(
+                name,
+                CacheEntry {
+                    size,
+                    last_access: index.tick,
+                },
+            );
+        }
+        server_info!(
+            "[Image] Image cache loaded: {} files, {} bytes",
+            index.entries.len(),
+            index.total_bytes
+        );
+
+        let cache = Self {
+            dir,
+            max_bytes,
+            index: Arc::new(Mutex::new(index)),
+        };
+        cache.evict();
+        cache
+    }
+
+    async fn get(&self, name: &str) -> Option<Vec<u8>> {
+        {
+            let mut index = self.index.lock().unwrap();
+            index.tick += 1;
+            let tick = index.tick;
+            index.entries.get_mut(name)?.last_access = tick;
+        }
+
+        match tokio::fs::read(self.dir.join(name)).await {
+            Ok(bytes) => Some(bytes),
+            Err(_) => {
+                self.remove(name);
+                None
+            }
+        }
+    }
+
+    async fn put(&self, name: &str, bytes: &[u8]) {
+        // 다른 요청이 쓰다 만 파일을 읽지 않도록 임시 파일에 쓴 뒤 이름을 바꾼다.
+        let tmp_path = self.dir.join(format!("{}.tmp", name));
+        let write_result = match tokio::fs::write(&tmp_path, bytes).await {
+            Ok(_) => tokio::fs::rename(&tmp_path, self.dir.join(name)).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = write_result {
+            server_warn!("[Image] Failed to write image cache {}: {}", name, e);
+            let _ = tokio::fs::remove_file(&tmp_path).await;
+            return;
+        }
+
+        {
+            let mut index = self.index.lock().unwrap();
+            index.tick += 1;
+            let tick = index.tick;
+            let size = bytes.len() as u64;
+            if let Some(old) = index.entries.insert(
+                name.to_string(),
+                CacheEntry {
+                    size,
+                    last_access: tick,
+                },
+            ) {
+                index.total_bytes -= old.size;
+            }
+            index.total_bytes += size;
+        }
+        self.evict();
+    }
+
+    fn remove(&self, name: &str) {
+        let mut index = self.index.lock().unwrap();
+        if let Some(entry) = index.entries.remove(name) {
+            index.total_bytes -= entry.size;
+        }
+    }
+
+    fn evict(&self) {
+        let mut evicted = vec![];
+        {
+            let mut index = self.index.lock().unwrap();
+            while index.total_bytes > self.max_bytes {
+                let Some(name) = index
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_access)
+                    .map(|(name, _)| name.clone())
+                else {
+                    break;
+                };
+                if let Some(entry) = index.entries.remove(&name) {
+                    index.total_bytes -= entry.size;
+                }
+                evicted.push(name);
+            }
+        }
+
+        for name in evicted {
+            let _ = std::fs::remove_file(self.dir.join(name));
+        }
+    }
+}
+
+/// 서명된 이미지 주소의 원본을 받아 요청한 크기로 줄인 이미지를 반환합니다.
+///
+/// 응답 형식은 항상 JPEG이므로 같은 주소, 크기의 이미지는 디스크 캐시에서 바로 내려줍니다.
+pub async fn get_proxied_image(
+    cache: &ImageCache,
+    http_client: &HttpClient,
+    signed_url: &str,
+    width: Option<u32>,
+    height: Option<u32>,
+) -> Result<ProxiedImage, OmniNewsError> {
+    let url = verify_signed_image_url(signed_url)
+        .ok_or_else(|| OmniNewsError::Forbidden("Invalid image signature".to_string()))?;
+
+    let width = width.map(snap_size);
+    let height = height.map(snap_size);
+    let format = ProxiedImageFormat::Jpeg;
+
+    let cache_name = make_cache_name(&url, width, height, format);
+    if let Some(bytes) = cache.get(&cache_name).await {
+        return Ok(ProxiedImage { bytes, format });
+    }
+
+    let source = fetch_source_image(http_client, &url).await?;
+    let bytes = tokio::task::spawn_blocking(move || resize_image(source, width, height, format))
+        .await
+        .map_err(|e| OmniNewsError::InvalidImage(e.to_string()))??;
+
+    cache.put(&cache_name, &bytes).await;
+    Ok(ProxiedImage { bytes, format })
+}
+
+async fn fetch_source_image(http_client: &HttpClient, url: &str) -> Result<Vec<u8>, OmniNewsError> {
+    let response = http_client
+        .send(http_client.outbound_get(url)?)
+        .await
+        .map_err(|e| {
+            server_warn!("[Image] Failed to fetch image {}: {}", url, e);
+            OmniNewsError::FetchUrl
+        })?;
+    if !response.status().is_success() {
+        server_warn!(
+            "[Image] Image responded with {}: {}",
+            response.status(),
+            url
+        );
+        return Err(OmniNewsError::FetchUrl);
+    }
+
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_lowercase();
+    // svg는 디코딩할 수 없고 스크립트를 포함할 수 있으므로 받지 않는다.
+    if !content_type.starts_with("image/") || content_type.starts_with("image/svg") {
+        return Err(OmniNewsError::InvalidImage(format!(
+            "unsupported content type: {}",
+            content_type
+        )));
+    }
+
+    url_policy_util::read_body_limited(response, MAX_SOURCE_BYTES).await
+}
+
+fn resize_image(
+    source: Vec<u8>,
+    width: Option<u32>,
+    height: Option<u32>,
+    format: ProxiedImageFormat,
+) -> Result<Vec<u8>, OmniNewsError> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_SOURCE_DIMENSION);
+    limits.max_image_height = Some(MAX_SOURCE_DIMENSION);
+
+    let mut reader = ImageReader::new(Cursor::new(source))
+        .with_guessed_format()
+        .map_err(|e| OmniNewsError::InvalidImage(e.to_string()))?;
+    reader.limits(limits);
+    let image = reader
+        .decode()
+        .map_err(|e| OmniNewsError::InvalidImage(e.to_string()))?;
+
+    // 지정하지 않은 축은 비율에 맞춰 정해지고, 원본보다 크게 늘리지는 않는다.
+    let max_size = *SIZE_STEPS.last().unwrap();
+    let target_width = width.unwrap_or(max_size).min(image.width());
+    let target_height = height.unwrap_or(max_size).min(image.height());
+    let image = if target_width < image.width() || target_height < image.height() {
+        image.resize(target_width, target_height, FilterType::Lanczos3)
+    } else {
+        image
+    };
+
+    let mut bytes = vec![];
+    let result = match format {
+        ProxiedImageFormat::Jpeg => DynamicImage::ImageRgb8(flatten_on_white(&image))
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut bytes, JPEG_QUALITY)),
+    };
+    result.map_err(|e| OmniNewsError::InvalidImage(e.to_string()))?;
+
+    Ok(bytes)
+}
+
+/// JPEG는 투명도를 담지 못하므로 투명한 부분을 흰 배경에 합성한다.
+fn flatten_on_white(image: &DynamicImage) -> RgbImage {
+    if !image.color().has_alpha() {
+        return image.to_rgb8();
+    }
+
+    let rgba = image.to_rgba8();
+    let mut rgb = RgbImage::new(rgba.width(), rgba.height());
+    for (src, dst) in rgba.pixels().zip(rgb.pixels_mut()) {
+        let [r, g, b, a] = src.0;
+        let alpha = a as u32;
+        dst.0 = [r, g, b].map(|c| ((c as u32 * alpha + 255 * (255 - alpha) + 127) / 255) as u8);
+    }
+    rgb
+}
+
+fn snap_size(size: u32) -> u32 {
+    SIZE_STEPS
+        .into_iter()
+        .find(|step| *step >= size)
+        .unwrap_or(*SIZE_STEPS.last().unwrap())
+}
+
+fn make_cache_name(
+    url: &str,
+    width: Option<u32>,
+    height: Option<u32>,
+    format: ProxiedImageFormat,
+) -> String {
+    let key = format!(
+        "{}|{}|{}",
+        url,
+        width.unwrap_or_default(),
+        height.unwrap_or_default()
+    );
+    let hash = Sha256::digest(key.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+    format!("{}.{}", hash, format.extension())
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{Rgba, RgbaImage};
+
+    use super::*;
+
+    fn temp_cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("omninews-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn snaps_size_up_to_step() {
+        assert_eq!(snap_size(1), 64);
+        assert_eq!(snap_size(64), 64);
+        assert_eq!(snap_size(65), 128);
+        assert_eq!(snap_size(1000), 1024);
+        assert_eq!(snap_size(5000), 2048);
+    }
+
+    #[test]
+    fn flattens_transparent_pixels_on_white() {
+        let mut image = RgbaImage::new(3, 1);
+        image.put_pixel(0, 0, Rgba([0, 0, 0, 0]));
+        image.put_pixel(1, 0, Rgba([0, 0, 0, 255]));
+        image.put_pixel(2, 0, Rgba([255, 0, 0, 128]));
+
+        let rgb = flatten_on_white(&DynamicImage::ImageRgba8(image));
+
+        assert_eq!(rgb.get_pixel(0, 0).0, [255, 255, 255]);
+        assert_eq!(rgb.get_pixel(1, 0).0, [0, 0, 0]);
+        assert_eq!(rgb.get_pixel(2, 0).0, [255, 127, 127]);
+    }
+
+    #[tokio::test]
+    async fn evicts_least_recently_used_image() {
+        let dir = temp_cache_dir("image-cache-lru");
+        let cache = ImageCache::new(dir.clone(), 10);
+
+        cache.put("a.jpg", b"aaaa").await;
+        cache.put("b.jpg", b"bbbb").await;
+        assert!(cache.get("a.jpg").await.is_some());
+        cache.put("c.jpg", b"cccc").await;
+
+        assert_eq!(cache.get("a.jpg").await.as_deref(), Some(&b"aaaa"[..]));
+        assert_eq!(cache.get("b.jpg").await, None);
+        assert_eq!(cache.get("c.jpg").await.as_deref(), Some(&b"cccc"[..]));
+        assert!(!dir.join("b.jpg").exists());
+        assert_eq!(cache.index.lock().unwrap().total_bytes, 8);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use crate::{
     dto::{
         rss::{request::UpdateRssRankRequestDto, response::RssItemResponseDto},
@@ -10,15 +12,22 @@ use crate::{
         search::SearchType,
     },
     repository::rss_item_repository,
-    rss_error, rss_warn,
-    service::embedding_service,
-    utils::{annoy_util::load_rss_annoy, embedding_util::EmbeddingService},
+    rss_error, rss_info,
+    service::{embedding_service, mute_service},
+    utils::{
+        annoy_util::load_rss_annoy,
+        date_util,
+        embedding_util::{make_embedding_text, EmbeddingService},
+    },
 };
-use chrono::{DateTime, NaiveDateTime};
+use chrono::{DateTime, Utc};
 use rss::{Channel, Item};
 use scraper::{Html, Selector};
 use sqlx::MySqlPool;
 
+/// 피드의 아이템을 일괄 저장하고 임베딩합니다.
+///
+/// 이미 저장된 링크는 한 번의 조회로 걸러내고, 새 아이템만 한 번에 임베딩한 뒤 한 트랜잭션으로 저장합니다.
 pub async fn create_rss_items_and_embedding(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
@@ -30,31 +39,77 @@ pub async fn create_rss_items_and_embedding(
         .image()
         .map_or(String::new(), |image| image.url().to_string()); // TODO rss cateogory 미구현 상태.
 
+    let mut links = HashSet::new();
+    let mut items = Vec::new();
     for (i, rss_item) in channel.items_mut().iter_mut().enumerate() {
-        create_rss_item_and_embedding(
-            pool,
-            embedding_service,
-            channel_id,
-            channel_image_url.clone(),
-            if let Some(links) = &item_image_links {
-                links.get(i).cloned()
-            } else {
-                None
-            },
-            rss_item,
-        )
-        .await?;
+        let item_image_link = item_image_links
+            .as_ref()
+            .and_then(|links| links.get(i).cloned());
+
+        let (item, sentence) =
+            match prepare_rss_item(channel_id, &channel_image_url, item_image_link, rss_item) {
+                Ok(prepared) => prepared,
+                Err(e) => {
+                    rss_error!("[Service] Failed to make rss item: {}", e);
+                    continue;
+                }
+            };
+        // 같은 피드 안에서 링크가 겹치면 처음 것만 저장한다.
+        if links.insert(item.rss_link.clone().unwrap_or_default()) {
+            items.push((item, sentence));
+        }
+    }
+
+    let links: Vec<String> = links.into_iter().collect();
+    let existing_links = rss_item_repository::select_existing_links(pool, &links)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select existing item links: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    items
+        .retain(|(item, _)| !existing_links.contains(item.rss_link.as_deref().unwrap_or_default()));
+    if items.is_empty() {
+        return Ok(());
+    }
+
+    let (items, sentences): (Vec<NewRssItem>, Vec<String>) = items.into_iter().unzip();
+    // rss_id는 저장소에서 아이템을 저장한 뒤 채운다.
+    let embeddings = embedding_service::make_embeddings(
+        embedding_service,
+        sentences,
+        vec![NewEmbedding::item(None); items.len()],
+    )
+    .await?;
+
+    match rss_item_repository::insert_rss_items_with_embeddings(
+        pool,
+        items.into_iter().zip(embeddings).collect(),
+    )
+    .await
+    {
+        Ok(ids) => {
+            rss_info!(
+                "[Service] Stored {} items for channel: {}",
+                ids.len(),
+                channel_id
+            );
+            Ok(())
+        }
+        Err(e) => {
+            rss_error!("[Service] Failed to insert rss items: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
     }
-    Ok(())
 }
-pub async fn create_rss_item_and_embedding(
-    pool: &MySqlPool,
-    embedding_service: &EmbeddingService,
+
+/// 저장할 아이템과 임베딩할 문장을 만듭니다.
+fn prepare_rss_item(
     channel_id: i32,
-    channel_image_url: String,
+    channel_image_url: &str,
     item_image_link: Option<String>,
     rss_item: &mut Item,
-) -> Result<bool, OmniNewsError> {
+) -> Result<(NewRssItem, String), OmniNewsError> {
     let description = rss_item.description().unwrap_or("None");
     let extracted_description = extract_html_passage(description);
 
@@ -66,35 +121,18 @@ pub async fn create_rss_item_and_embedding(
 
     rss_item.set_description(extracted_description.0.clone());
 
-    let item_image_link = use_channel_url_if_none(item_image_link, channel_image_url.clone());
+    let item_image_link = use_channel_url_if_none(item_image_link, channel_image_url.to_string());
 
-    let item = match make_rss_item(channel_id, rss_item, item_image_link) {
-        Ok(item) => item,
-        Err(e) => {
-            rss_error!("[Service] Failed to make rss item: {}", e);
-            return Err(e);
-        }
+    let mut item = make_rss_item(channel_id, rss_item, item_image_link)?;
+    if let Some(str) = item.rss_description.as_mut() {
+        *str = str.chars().take(200).collect()
     };
 
-    let item_id = store_rss_item(pool, item.clone()).await.unwrap();
-
-    let sentence = format!(
-        "{}\n{}\n{}",
-        item.rss_title.unwrap_or_default(),
-        extracted_description.0,
-        item.rss_author.unwrap_or_default()
+    let sentence = make_embedding_text(
+        item.rss_title.as_deref().unwrap_or_default(),
+        item.rss_description.as_deref().unwrap_or_default(),
     );
-    let embedding = NewEmbedding {
-        embedding_value: None,
-        channel_id: None,
-        rss_id: Some(item_id),
-        news_id: None,
-        embedding_source_rank: Some(0),
-    };
-
-    let _ =
-        embedding_service::create_embedding(pool, embedding_service, sentence, embedding).await?;
-    Ok(true)
+    Ok((item, sentence))
 }
 
 fn extract_html_passage(html: &str) -> (String, Option<String>) {
@@ -157,68 +195,37 @@ pub fn make_rss_item(
     ))
 }
 
-fn parse_pub_date(pub_date_str: Option<&str>) -> Option<NaiveDateTime> {
-    pub_date_str
-        .and_then(|date_str| {
-            // 1. RFC2822 형식 파싱 시도
-            if let Ok(dt) = DateTime::parse_from_rfc2822(date_str) {
-                // 타임존이 KST/+09:00 인지 확인 (초 단위로 환산해서 비교)
-                if dt.offset().local_minus_utc() == 9 * 3600 {
-                    // 한국 시간이면 타임존 제거만 하고 값 유지
-                    return Some(dt.naive_local());
-                } else {
-                    // 다른 타임존이면 기존처럼 UTC 변환
-                    return Some(dt.naive_utc());
-                }
-            }
-
-            None
-        })
-        .or_else(|| {
-            // 파싱 실패하면 디폴트 시간
-            Some(
-                NaiveDateTime::parse_from_str("1970-01-01T00:00:00", "%Y-%m-%dT%H:%M:%S")
-                    .ok()
-                    .unwrap(),
-            )
-        })
-}
-
-async fn store_rss_item(pool: &MySqlPool, mut rss_item: NewRssItem) -> Result<i32, OmniNewsError> {
-    let item_link = rss_item.rss_link.clone().unwrap_or_default();
-
-    if let Some(str) = rss_item.rss_description.as_mut() {
-        *str = str.chars().take(200).collect()
-    };
-
-    match rss_item_repository::select_item_by_link(pool, item_link).await {
-        Ok(item) => {
-            rss_warn!(
-                "[Service] Item already exists with link: {}",
-                item.rss_link.clone().unwrap_or_default()
-            );
-            Err(OmniNewsError::AlreadyExists)
-        }
-
-        Err(_) => rss_item_repository::insert_rss_item(pool, rss_item)
-            .await
-            .map_err(|e| {
-                rss_error!("[Service] Failed to select item by link : {}", e);
-                OmniNewsError::Database(e)
-            }),
-    }
+fn parse_pub_date(pub_date_str: Option<&str>) -> Option<DateTime<Utc>> {
+    // 날짜가 없거나 해석할 수 없으면 처음 수집한 시각을 사용한다.
+    let pub_date = pub_date_str
+        .and_then(date_util::parse_date)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(Utc::now);
+    Some(pub_date)
 }
 
 pub async fn get_rss_list(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
+    user_email: String,
     value: SearchRequestDto,
 ) -> Result<SearchResponseDto, OmniNewsError> {
+    let mute_filter = mute_service::load_mute_filter(pool, user_email).await?;
     let load_annoy = load_rss_annoy(embedding_service, value.search_value.unwrap()).await?;
     let page = value.search_page_size.unwrap_or_default();
 
-    let mut item_list = vec![];
-    let total = load_annoy.0.len() as i32;
+    // 뮤트된 아이템을 먼저 걸러낸 뒤 페이지를 나눈다. (페이지마다 개수가 줄어들지 않도록)
+    let mut item_list =
+        match rss_item_repository::select_rss_items_by_embedding_ids(pool, &load_annoy.0).await {
+            Ok(items) => items,
+            Err(e) => {
+                rss_error!("[Service] Failed to select items by embedding ids: {:?}", e);
+                return Err(OmniNewsError::Database(e));
+            }
+        };
+    item_list.retain(|item| !mute_filter.is_item_muted(item));
+
+    let total = item_list.len() as i32;
     // Provide 20 rss item each select request
     let offset = (page - 1) * 20;
     let has_next = total > offset + 20;
@@ -228,13 +235,14 @@ pub async fn get_rss_list(
         return Ok(SearchResponseDto::new(vec![], vec![], total, page, false));
     }
 
+    let mut item_list: Vec<RssItem> = item_list
+        .into_iter()
+        .skip(offset as usize)
+        .take(20)
+        .collect();
     match value.search_type.clone().unwrap() {
-        SearchType::Accuracy => {
-            push_rss_item(pool, &load_annoy, &mut item_list, total, offset).await;
-        }
+        SearchType::Accuracy => {}
         SearchType::Popularity => {
-            push_rss_item(pool, &load_annoy, &mut item_list, total, offset).await;
-
             item_list.sort_by(|a, b| {
                 b.rss_rank
                     .unwrap_or_default()
@@ -242,8 +250,6 @@ pub async fn get_rss_list(
             });
         }
         SearchType::Latest => {
-            push_rss_item(pool, &load_annoy, &mut item_list, total, offset).await;
-
             item_list.sort_by(|a, b| {
                 b.rss_pub_date
                     .unwrap_or_default()
@@ -260,35 +266,16 @@ pub async fn get_rss_list(
     ))
 }
 
-async fn push_rss_item(
-    pool: &MySqlPool,
-    load_annoy: &(Vec<i32>, Vec<f32>),
-    item_list: &mut Vec<RssItem>,
-    total: i32,
-    offset: i32,
-) {
-    for i in 0..20 {
-        if offset + i == total {
-            break;
-        }
-
-        if let Ok(item) = rss_item_repository::select_rss_item_by_embedding_id(
-            pool,
-            load_annoy.0[(i + offset) as usize],
-        )
-        .await
-        {
-            item_list.push(item);
-        }
-    }
-}
-
 // TODO 상위 100개 중 50개 랜덤 반환
 pub async fn get_recommend_item(
     pool: &MySqlPool,
+    user_email: String,
 ) -> Result<Vec<RssItemResponseDto>, OmniNewsError> {
+    let mute_filter = mute_service::load_mute_filter(pool, user_email).await?;
+
     match rss_item_repository::select_rss_items_order_by_rss_rank(pool).await {
-        Ok(res) => {
+        Ok(mut res) => {
+            res.retain(|item| !mute_filter.is_item_muted(item));
             //            let mut rng = rng();
             //            res.shuffle(&mut rng);
             //            Ok(res.into_iter().take(50).collect())
@@ -332,3 +319,43 @@ pub async fn update_rss_item_rank(
         Err(e) => Err(OmniNewsError::Database(e)),
     }
 }
+
+pub async fn delete_rss_item(pool: &MySqlPool, rss_id: i32) -> Result<(), OmniNewsError> {
+    match rss_item_repository::delete_rss_item_by_id(pool, rss_id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(OmniNewsError::NotFound(format!("rss item {}", rss_id))),
+        Err(e) => {
+            rss_error!("[Service] Failed to delete rss item: {:?}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn rebuild_item_embeddings_by_channel_id(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    channel_id: i32,
+) -> Result<usize, OmniNewsError> {
+    let items = rss_item_repository::select_rss_items_by_channel_id(pool, channel_id)
+        .await
+        .map_err(|e| {
+            rss_error!(
+                "[Service] Failed to select rss items by channel id: {:?}",
+                e
+            );
+            OmniNewsError::Database(e)
+        })?;
+
+    let mut rebuilt = 0;
+    for item in items {
+        let sentence = make_embedding_text(
+            &item.rss_title.unwrap_or_default(),
+            &item.rss_description.unwrap_or_default(),
+        );
+        let embedding = NewEmbedding::item(item.rss_id);
+        embedding_service::refresh_embedding(pool, embedding_service, sentence, embedding).await?;
+        rebuilt += 1;
+    }
+
+    Ok(rebuilt)
+}
@@ -1,9 +1,17 @@
+pub mod admin_service;
+pub mod channel_metadata_service;
 pub mod channel_service;
 pub mod embedding_service;
+pub mod feedback_service;
 pub mod folder_service;
+pub mod identity_service;
+pub mod image_proxy_service;
 pub mod item_service;
+pub mod mute_service;
 pub mod news_service;
 pub mod omninews_subscription_service;
+pub mod reembedding_job_service;
+pub mod smart_folder_service;
 pub mod subscription_service;
 pub mod user_service;
 
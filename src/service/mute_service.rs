@@ -0,0 +1,192 @@
+use std::future::Future;
+
+use chrono::Utc;
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::mute::{
+        request::{CreateMuteRuleRequestDto, DeleteMuteRuleRequestDto},
+        response::MuteRuleResponseDto,
+    },
+    model::{
+        error::OmniNewsError,
+        folder::ItemCursor,
+        mute::{MuteFilter, MuteRuleType, NewMuteRule},
+        rss::RssItem,
+    },
+    repository::mute_repository,
+    user_error, user_info,
+};
+
+use super::user_service;
+
+const MAX_MUTE_RULE_VALUE_LEN: usize = 200;
+const MAX_MUTE_RULE_DAYS: i32 = 365;
+// 뮤트된 아이템을 건너뛰며 한 페이지를 채울 때 최대 조회 횟수
+const MAX_PAGE_FETCH_ROUNDS: usize = 10;
+
+pub async fn create_mute_rule(
+    pool: &MySqlPool,
+    user_email: String,
+    rule: CreateMuteRuleRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let rule_type = validate_mute_rule(&rule)?;
+
+    let new_rule = NewMuteRule::new(user_id, rule_type, rule, Utc::now());
+    match mute_repository::insert_mute_rule(pool, new_rule).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            user_error!("[Service] Failed to create mute rule: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn find_mute_rules(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<Vec<MuteRuleResponseDto>, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match mute_repository::select_active_mute_rules(pool, user_id, Utc::now()).await {
+        Ok(res) => Ok(MuteRuleResponseDto::from_model_list(res)),
+        Err(e) => {
+            user_error!("[Service] Failed to fetch mute rules: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn delete_mute_rule(
+    pool: &MySqlPool,
+    user_email: String,
+    rule: DeleteMuteRuleRequestDto,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let mute_rule_id = rule.mute_rule_id.unwrap_or_default();
+
+    match mute_repository::delete_mute_rule(pool, user_id, mute_rule_id).await {
+        Ok(_) => Ok(()),
+        Err(sqlx::Error::RowNotFound) => {
+            Err(OmniNewsError::NotFound("Mute rule not found".to_string()))
+        }
+        Err(e) => {
+            user_error!("[Service] Failed to delete mute rule: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 타임라인, 검색, 추천 결과에 적용할 사용자의 뮤트 필터를 만듭니다.
+pub async fn load_mute_filter(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<MuteFilter, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match mute_repository::select_active_mute_rules(pool, user_id, Utc::now()).await {
+        Ok(rules) => Ok(MuteFilter::from_rules(rules)),
+        Err(e) => {
+            user_error!("[Service] Failed to load mute rules: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 커서 기반 목록에서 뮤트된 아이템을 건너뛰며 `limit`개를 채웁니다.
+///
+/// `fetch`는 커서 다음 아이템을 최신 순으로 최대 `limit`개 조회해야 합니다.
+/// 페이지를 다 채우지 못한 채 조회 횟수를 다 쓰면 마지막으로 확인한 위치를 다음 커서로 돌려줍니다.
+pub async fn fill_item_page<F, Fut>(
+    mute_filter: &MuteFilter,
+    mut cursor: ItemCursor,
+    limit: i64,
+    mut fetch: F,
+) -> Result<(Vec<RssItem>, Option<ItemCursor>), sqlx::Error>
+where
+    F: FnMut(ItemCursor, i64) -> Fut,
+    Fut: Future<Output = Result<Vec<RssItem>, sqlx::Error>>,
+{
+    let mut items = Vec::new();
+
+    for _ in 0..MAX_PAGE_FETCH_ROUNDS {
+        // 한 개 더 조회해서 다음 페이지가 있는지 확인한다.
+        let batch = fetch(cursor, limit + 1).await?;
+        let exhausted = batch.len() as i64 <= limit;
+
+        for item in batch {
+            if items.len() as i64 == limit {
+                return Ok((items, Some(cursor)));
+            }
+            cursor = ItemCursor::from_item(&item);
+            if !mute_filter.is_item_muted(&item) {
+                items.push(item);
+            }
+        }
+
+        if exhausted {
+            return Ok((items, None));
+        }
+    }
+
+    Ok((items, Some(cursor)))
+}
+
+pub async fn delete_expired_mute_rules(pool: &MySqlPool) {
+    match mute_repository::delete_mute_rules_expired_before(pool, Utc::now()).await {
+        Ok(0) => {}
+        Ok(deleted) => user_info!("[Service] Deleted {} expired mute rules", deleted),
+        Err(e) => user_error!("[Service] Failed to delete expired mute rules: {}", e),
+    }
+}
+
+fn validate_mute_rule(rule: &CreateMuteRuleRequestDto) -> Result<MuteRuleType, OmniNewsError> {
+    let rule_type = rule
+        .mute_rule_type
+        .as_deref()
+        .and_then(MuteRuleType::parse)
+        .ok_or_else(|| {
+            OmniNewsError::InvalidRequest(
+                "mute_rule_type must be one of keyword, regex, author, channel, domain".to_string(),
+            )
+        })?;
+
+    let value = rule.mute_rule_value.as_deref().unwrap_or_default().trim();
+    if value.is_empty() || value.chars().count() > MAX_MUTE_RULE_VALUE_LEN {
+        return Err(OmniNewsError::InvalidRequest(format!(
+            "mute_rule_value must be 1..={} characters",
+            MAX_MUTE_RULE_VALUE_LEN
+        )));
+    }
+
+    match rule_type {
+        MuteRuleType::Regex => {
+            MuteFilter::compile_regex(value)
+                .map_err(|e| OmniNewsError::InvalidRequest(format!("Invalid regex: {}", e)))?;
+        }
+        MuteRuleType::Channel => {
+            value.parse::<i32>().map_err(|_| {
+                OmniNewsError::InvalidRequest("Channel rule value must be a channel_id".to_string())
+            })?;
+        }
+        MuteRuleType::Domain if value.contains('/') => {
+            return Err(OmniNewsError::InvalidRequest(
+                "Domain rule value must be a host name (e.g. example.com)".to_string(),
+            ));
+        }
+        _ => {}
+    }
+
+    if rule
+        .days
+        .is_some_and(|days| !(1..=MAX_MUTE_RULE_DAYS).contains(&days))
+    {
+        return Err(OmniNewsError::InvalidRequest(format!(
+            "days must be between 1 and {}",
+            MAX_MUTE_RULE_DAYS
+        )));
+    }
+
+    Ok(rule_type)
+}
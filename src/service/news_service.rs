@@ -1,6 +1,7 @@
 use std::env;
 
 use crate::{
+    config::http_client::HttpClient,
     dto::news::{
         request::NewsRequestDto,
         response::{NewsApiResponseDto, NewsResponseDto},
@@ -9,7 +10,7 @@ use crate::{
     news_error,
     repository::news_repository,
 };
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Utc};
 use reqwest::{header::HeaderMap, Response};
 use sqlx::MySqlPool;
 
@@ -27,9 +28,10 @@ pub async fn get_news(
 }
 
 pub async fn get_news_by_api(
+    http_client: &HttpClient,
     params: NewsRequestDto,
 ) -> Result<Vec<NewsApiResponseDto>, OmniNewsError> {
-    let res = request_naver_news_api(params).await?;
+    let res = request_naver_news_api(http_client, params).await?;
 
     let xml_data = res.text().await.map_err(|e| {
         news_error!("[Service] Failed to fetch news items: {:?}", e);
@@ -39,9 +41,10 @@ pub async fn get_news_by_api(
     get_news_items_by_xml(xml_data)
 }
 
-async fn request_naver_news_api(params: NewsRequestDto) -> Result<Response, OmniNewsError> {
-    let client = reqwest::Client::new();
-
+async fn request_naver_news_api(
+    http_client: &HttpClient,
+    params: NewsRequestDto,
+) -> Result<Response, OmniNewsError> {
     let mut head = HeaderMap::new();
     head.append(
         "X-Naver-Client-Id",
@@ -65,10 +68,13 @@ async fn request_naver_news_api(params: NewsRequestDto) -> Result<Response, Omni
         params.sort.unwrap_or_default()
     );
 
-    client.get(url).headers(head).send().await.map_err(|e| {
-        news_error!("[Service] Failed to fetch news: {:?}", e);
-        OmniNewsError::FetchNews
-    })
+    http_client
+        .send(http_client.get(&url).headers(head))
+        .await
+        .map_err(|e| {
+            news_error!("[Service] Failed to fetch news: {:?}", e);
+            OmniNewsError::FetchNews
+        })
 }
 
 fn get_news_items_by_xml(xml_data: String) -> Result<Vec<NewsApiResponseDto>, OmniNewsError> {
@@ -117,7 +123,8 @@ fn get_news_items_by_xml(xml_data: String) -> Result<Vec<NewsApiResponseDto>, Om
             item.originallink,
             item.link,
             item.description,
-            NaiveDateTime::parse_from_str(&item.pubDate, "%a, %d %b %Y %H:%M:%S %z")
+            DateTime::parse_from_rfc2822(&item.pubDate)
+                .map(|pub_date| pub_date.with_timezone(&Utc))
                 .unwrap_or_default(),
         ));
     }
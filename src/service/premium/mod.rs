@@ -1,2 +1,3 @@
 pub mod premium_rss_service;
+pub mod rss_generate_job_service;
 pub mod site;
@@ -1,13 +1,15 @@
 use std::time::Duration;
 
-use chrono::{DateTime, Utc};
 use reqwest::Url;
 use rss::{Channel, ChannelBuilder, Image, ItemBuilder};
 use sqlx::MySqlPool;
 use thirtyfour::By;
 
 use crate::{
-    config::webdriver::{AcquireStrategy, DriverPool},
+    config::{
+        http_client::HttpClient,
+        webdriver::{AcquireStrategy, DriverPool},
+    },
     dto::{
         premium::rss::{
             request::{RssGenerateByCssReqeustDto, RssGenerateRequestDto},
@@ -17,7 +19,7 @@ use crate::{
     },
     model::{error::OmniNewsError, premium::rss_generate::SiteType},
     service::{channel_service, item_service},
-    utils::embedding_util::EmbeddingService,
+    utils::{date_util, embedding_util::EmbeddingService, url_policy_util},
 };
 
 use super::site::{default, instagram, medium, naver, tistory};
@@ -25,6 +27,7 @@ use super::site::{default, instagram, medium, naver, tistory};
 pub async fn generate_rss(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
+    http_client: &HttpClient,
     driver_pool: &DriverPool,
     data: RssGenerateRequestDto,
 ) -> Result<RssGenerateResponseDto, OmniNewsError> {
@@ -39,15 +42,19 @@ pub async fn generate_rss(
     };
 
     let channel_id = match data.kind {
-        SiteType::Naver => naver::generate_rss(pool, embedding_service, &link).await?,
-        SiteType::Tistory => tistory::generate_rss(pool, embedding_service, &link).await?,
+        SiteType::Naver => naver::generate_rss(pool, embedding_service, http_client, &link).await?,
+        SiteType::Tistory => {
+            tistory::generate_rss(pool, embedding_service, http_client, &link).await?
+        }
         //SiteType::Instagram => todo!(),
-        SiteType::Medium => medium::generate_rss(pool, embedding_service, &link).await?,
+        SiteType::Medium => {
+            medium::generate_rss(pool, embedding_service, http_client, &link).await?
+        }
         SiteType::Instagram => {
             instagram::generate_rss(pool, embedding_service, driver_pool, &link).await?
         }
         SiteType::Default => {
-            default::generate_rss(pool, embedding_service, driver_pool, &link).await?
+            default::generate_rss(pool, embedding_service, http_client, driver_pool, &link).await?
         }
     };
 
@@ -75,6 +82,8 @@ pub async fn generate_rss_by_css(
         });
     }
 
+    url_policy_util::validate_outbound_url(&data.channel_link)?;
+
     let strategy = AcquireStrategy::Wait(Some(Duration::from_secs(10)));
     let driver_handle = driver_pool
         .acquire(strategy)
@@ -82,10 +91,7 @@ pub async fn generate_rss_by_css(
         .map_err(OmniNewsError::WebDriverPool)?;
     let driver = driver_handle.driver();
 
-    driver
-        .goto(&data.channel_link)
-        .await
-        .map_err(OmniNewsError::WebDriverError)?;
+    url_policy_util::goto_checked(driver, &data.channel_link).await?;
 
     let (channel_id, mut rss_channel) = make_channel(pool, embedding_service, &data).await?;
     let items = make_items(data, driver).await?;
@@ -218,21 +224,22 @@ async fn make_items(
         };
 
         // --- pub_date ---
-        let item_pub_date_rfc2822 = if let Some(pub_dates) = &item_pub_date_raws {
-            let item_pub_date_raw = pub_dates.get(idx).unwrap().text().await.unwrap_or_default();
-
-            match DateTime::parse_from_rfc3339(&item_pub_date_raw) {
-                Ok(dt) => dt.to_rfc2822(),
-                Err(_) => {
-                    if let Ok(dt2) = DateTime::parse_from_rfc3339(&(item_pub_date_raw + "Z")) {
-                        dt2.to_rfc2822()
-                    } else {
-                        Utc::now().to_rfc2822()
-                    }
+        // 해석할 수 없는 날짜는 비워 두고, 저장할 때 처음 수집한 시각을 사용한다.
+        let item_pub_date = if let Some(pub_dates) = &item_pub_date_raws {
+            match pub_dates.get(idx) {
+                Some(pub_date) => {
+                    // <time datetime="..."> 처럼 속성에 기계가 읽을 수 있는 날짜가 있으면 우선 사용한다.
+                    let datetime_attr = pub_date.attr("datetime").await.ok().flatten();
+                    let text = pub_date.text().await.unwrap_or_default();
+                    datetime_attr
+                        .and_then(|raw| date_util::parse_date(&raw))
+                        .or_else(|| date_util::parse_date(&text))
+                        .map(|dt| dt.to_rfc2822())
                 }
+                None => None,
             }
         } else {
-            Utc::now().to_rfc2822()
+            None
         };
 
         let item = ItemBuilder::default()
@@ -240,7 +247,7 @@ async fn make_items(
             .description(item_description)
             .link(item_link)
             .author(item_author)
-            .pub_date(item_pub_date_rfc2822)
+            .pub_date(item_pub_date)
             .build();
         items.0.push(item);
         items.1.push(item_image_link);
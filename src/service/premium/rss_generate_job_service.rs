@@ -0,0 +1,276 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    env,
+    sync::Arc,
+};
+
+use chrono::{Duration, Utc};
+use sqlx::MySqlPool;
+use tokio::sync::{Mutex, Notify, RwLock};
+use uuid::Uuid;
+
+use crate::{
+    config::{http_client::HttpClient, webdriver::DriverPool},
+    dto::premium::rss::response::{RssGenerateJobResponseDto, RssGenerateResponseDto},
+    model::{
+        error::OmniNewsError,
+        premium::rss_generate_job::{RssGenerateJob, RssGenerateJobKind, RssGenerateJobStatus},
+    },
+    rss_error, rss_info, rss_warn,
+    service::omninews_subscription_service,
+    utils::embedding_util::EmbeddingService,
+};
+
+use super::premium_rss_service;
+
+// 완료/실패한 작업은 1시간 동안 조회할 수 있다.
+const JOB_RETENTION_HOURS: i64 = 1;
+// 작업 하나가 워커를 붙잡고 있을 수 있는 최대 시간 (기본 5분)
+const DEFAULT_JOB_TIMEOUT_SECS: u64 = 5 * 60;
+
+/// RSS 생성 작업 큐
+///
+/// 요청은 작업 ID만 받아 반환하고, 워커가 큐에서 작업을 꺼내 DriverPool로 생성합니다.
+#[derive(Clone)]
+pub struct RssGenerateJobQueue {
+    jobs: Arc<RwLock<HashMap<String, RssGenerateJob>>>,
+    pending: Arc<Mutex<VecDeque<String>>>,
+    notify: Arc<Notify>,
+    pool: MySqlPool,
+    max_pending: usize,
+    max_pending_per_user: usize,
+    job_timeout: std::time::Duration,
+}
+
+impl RssGenerateJobQueue {
+    pub fn new(
+        pool: MySqlPool,
+        embedding_service: EmbeddingService,
+        http_client: HttpClient,
+        driver_pool: DriverPool,
+    ) -> Self {
+        let workers = env::var("RSS_GENERATE_WORKERS")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(driver_pool.max_sessions());
+        let max_pending = env::var("RSS_GENERATE_QUEUE_MAX")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(100);
+        let max_pending_per_user = env::var("RSS_GENERATE_QUEUE_MAX_PER_USER")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(3);
+        let job_timeout = env::var("RSS_GENERATE_JOB_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(DEFAULT_JOB_TIMEOUT_SECS);
+
+        let queue = Self {
+            jobs: Arc::new(RwLock::new(HashMap::new())),
+            pending: Arc::new(Mutex::new(VecDeque::new())),
+            notify: Arc::new(Notify::new()),
+            pool: pool.clone(),
+            max_pending,
+            max_pending_per_user,
+            job_timeout: std::time::Duration::from_secs(job_timeout),
+        };
+
+        for worker_id in 0..workers {
+            let queue = queue.clone();
+            let pool = pool.clone();
+            let embedding_service = embedding_service.clone();
+            let http_client = http_client.clone();
+            let driver_pool = driver_pool.clone();
+            tokio::spawn(async move {
+                rss_info!("[Worker] Rss generate worker {} started", worker_id);
+                queue
+                    .run_worker(&pool, &embedding_service, &http_client, &driver_pool)
+                    .await;
+            });
+        }
+
+        queue
+    }
+
+    /// 프리미엄 구독자만 등록할 수 있고, 한 사용자가 대기/실행 중인 작업 수는 제한됩니다.
+    pub async fn enqueue(
+        &self,
+        user_email: String,
+        kind: RssGenerateJobKind,
+    ) -> Result<RssGenerateJobResponseDto, OmniNewsError> {
+        if !omninews_subscription_service::has_premium_entitlement(&self.pool, &user_email).await? {
+            rss_warn!("[Service] Rss generate requires premium: {}", user_email);
+            return Err(OmniNewsError::Forbidden(
+                "premium subscription required".into(),
+            ));
+        }
+
+        self.purge_finished_jobs().await;
+
+        let mut pending = self.pending.lock().await;
+        if pending.len() >= self.max_pending {
+            rss_warn!("[Service] Rss generate queue is full: {}", pending.len());
+            return Err(OmniNewsError::QueueFull);
+        }
+
+        // pending 잠금을 잡은 채로 세어 같은 사용자의 동시 요청이 제한을 넘지 않게 한다.
+        let user_jobs = self
+            .jobs
+            .read()
+            .await
+            .values()
+            .filter(|job| job.user_email == user_email && !job.is_finished())
+            .count();
+        if user_jobs >= self.max_pending_per_user {
+            rss_warn!(
+                "[Service] Too many rss generate jobs: {} ({})",
+                user_email,
+                user_jobs
+            );
+            return Err(OmniNewsError::TooManyJobs);
+        }
+
+        let job_id = Uuid::new_v4().to_string();
+        let job = RssGenerateJob::new(job_id.clone(), user_email, kind, Utc::now());
+        self.jobs.write().await.insert(job_id.clone(), job.clone());
+        pending.push_back(job_id.clone());
+        let queue_position = pending.len() - 1;
+        drop(pending);
+
+        self.notify.notify_one();
+        rss_info!("[Service] Enqueue rss generate job: {}", job_id);
+
+        Ok(RssGenerateJobResponseDto::from_model(
+            job,
+            Some(queue_position),
+        ))
+    }
+
+    /// 작업을 요청한 사용자만 조회할 수 있습니다.
+    pub async fn find_job(
+        &self,
+        user_email: &str,
+        job_id: &str,
+    ) -> Result<RssGenerateJobResponseDto, OmniNewsError> {
+        let job = match self.jobs.read().await.get(job_id) {
+            Some(job) if job.user_email == user_email => job.clone(),
+            _ => return Err(OmniNewsError::NotFound(format!("job {}", job_id))),
+        };
+
+        let queue_position = if job.status == RssGenerateJobStatus::Queued {
+            self.pending.lock().await.iter().position(|id| id == job_id)
+        } else {
+            None
+        };
+
+        Ok(RssGenerateJobResponseDto::from_model(job, queue_position))
+    }
+
+    async fn run_worker(
+        &self,
+        pool: &MySqlPool,
+        embedding_service: &EmbeddingService,
+        http_client: &HttpClient,
+        driver_pool: &DriverPool,
+    ) {
+        loop {
+            let next = self.pending.lock().await.pop_front();
+            let Some(job_id) = next else {
+                self.notify.notified().await;
+                continue;
+            };
+
+            let kind = {
+                let mut jobs = self.jobs.write().await;
+                match jobs.get_mut(&job_id) {
+                    Some(job) => {
+                        job.status = RssGenerateJobStatus::Running;
+                        job.updated_at = Utc::now();
+                        job.kind.clone()
+                    }
+                    None => continue,
+                }
+            };
+
+            rss_info!("[Worker] Start rss generate job: {}", job_id);
+            // 작업마다 별도 태스크로 실행해 패닉이나 멈춘 작업이 워커를 멈추지 않게 한다.
+            let mut handle = tokio::spawn(run_job(
+                pool.clone(),
+                embedding_service.clone(),
+                http_client.clone(),
+                driver_pool.clone(),
+                kind,
+            ));
+            let result = match tokio::time::timeout(self.job_timeout, &mut handle).await {
+                Ok(Ok(result)) => result.map_err(|e| e.to_string()),
+                Ok(Err(e)) => {
+                    rss_error!("[Worker] Rss generate job panicked: {} - {}", job_id, e);
+                    Err("job aborted unexpectedly".to_string())
+                }
+                Err(_) => {
+                    // 취소하면 작업이 잡고 있던 WebDriver 세션도 반환된다.
+                    handle.abort();
+                    rss_error!("[Worker] Rss generate job timed out: {}", job_id);
+                    Err(format!(
+                        "job timed out after {} seconds",
+                        self.job_timeout.as_secs()
+                    ))
+                }
+            };
+
+            let mut jobs = self.jobs.write().await;
+            if let Some(job) = jobs.get_mut(&job_id) {
+                job.updated_at = Utc::now();
+                match result {
+                    Ok(res) => {
+                        rss_info!("[Worker] Rss generate job completed: {}", job_id);
+                        job.status = RssGenerateJobStatus::Completed;
+                        job.result = Some(res);
+                    }
+                    Err(e) => {
+                        rss_error!("[Worker] Rss generate job failed: {} - {}", job_id, e);
+                        job.status = RssGenerateJobStatus::Failed;
+                        job.error = Some(e);
+                    }
+                }
+            }
+        }
+    }
+
+    async fn purge_finished_jobs(&self) {
+        let threshold = Utc::now() - Duration::hours(JOB_RETENTION_HOURS);
+        self.jobs
+            .write()
+            .await
+            .retain(|_, job| !(job.is_finished() && job.updated_at < threshold));
+    }
+}
+
+async fn run_job(
+    pool: MySqlPool,
+    embedding_service: EmbeddingService,
+    http_client: HttpClient,
+    driver_pool: DriverPool,
+    kind: RssGenerateJobKind,
+) -> Result<RssGenerateResponseDto, OmniNewsError> {
+    match kind {
+        RssGenerateJobKind::Site(data) => {
+            premium_rss_service::generate_rss(
+                &pool,
+                &embedding_service,
+                &http_client,
+                &driver_pool,
+                data,
+            )
+            .await
+        }
+        RssGenerateJobKind::Css(data) => {
+            premium_rss_service::generate_rss_by_css(&pool, &embedding_service, &driver_pool, data)
+                .await
+        }
+    }
+}
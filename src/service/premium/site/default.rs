@@ -8,12 +8,16 @@ use thirtyfour::{error::WebDriverResult, WebDriver};
 
 use crate::config::webdriver::{AcquireStrategy, DriverPool};
 use crate::{
-    model::error::OmniNewsError, service::channel_service, utils::embedding_util::EmbeddingService,
+    config::http_client::HttpClient,
+    model::error::OmniNewsError,
+    service::channel_service,
+    utils::{embedding_util::EmbeddingService, url_policy_util},
 };
 
 pub async fn generate_rss(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
+    http_client: &HttpClient,
     driver_pool: &DriverPool,
     link: &str,
 ) -> Result<i32, OmniNewsError> {
@@ -25,9 +29,7 @@ pub async fn generate_rss(
     })?;
     let driver = driver_handler.driver();
 
-    let feeds = extract_feed(driver, link)
-        .await
-        .map_err(OmniNewsError::WebDriverError)?;
+    let feeds = extract_feed(http_client, driver, link).await?;
 
     info!("Discovered feed URLs: {:?}", feeds);
 
@@ -39,11 +41,11 @@ pub async fn generate_rss(
             rss_link = feeds.first().unwrap_or(&"".to_string()).to_string();
         }
 
-        return channel_service::create_rss_and_embedding_with_web_driver(
+        return channel_service::create_generated_rss_and_embedding(
             pool,
             embedding_service,
+            http_client,
             rss_link,
-            driver,
         )
         .await;
     }
@@ -57,14 +59,23 @@ pub async fn generate_rss(
     ))
 }
 
-pub async fn extract_feed(driver: &WebDriver, start_url: &str) -> WebDriverResult<Vec<String>> {
-    driver.goto(start_url).await?;
+pub async fn extract_feed(
+    http_client: &HttpClient,
+    driver: &WebDriver,
+    start_url: &str,
+) -> Result<Vec<String>, OmniNewsError> {
+    url_policy_util::goto_checked(driver, start_url).await?;
     wait_for_dom_ready(driver, Duration::from_secs(5))
         .await
         .ok();
 
-    // link[rel="alternate"] 피드 링크 찾기
-    let mut feed_urls = detect_feed_links(driver).await.unwrap_or_default();
+    // link[rel="alternate"] 피드 링크 찾기 (페이지가 알려준 주소도 정책을 통과한 것만 사용)
+    let mut feed_urls: Vec<String> = detect_feed_links(driver)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|url| url_policy_util::validate_outbound_url(url).is_ok())
+        .collect();
 
     // 일반적인 RSS 링크 패턴 찾기
     if feed_urls.is_empty() {
@@ -75,7 +86,7 @@ pub async fn extract_feed(driver: &WebDriver, start_url: &str) -> WebDriverResul
 
         // 후보 URL 검증
         if !candidates.is_empty() {
-            let valid = verify_feed_candidates(driver, &candidates).await?;
+            let valid = verify_feed_candidates(http_client, &candidates).await;
             feed_urls.extend(valid);
         }
     }
@@ -249,63 +260,39 @@ fn build_feed_candidates(input_url: &str) -> Vec<String> {
     dedup(out)
 }
 
-/// 브라우저의 fetch를 사용해 피드 URL 후보들의 유효성을 검증
-async fn verify_feed_candidates(
-    driver: &WebDriver,
-    candidates: &[String],
-) -> WebDriverResult<Vec<String>> {
+/// 피드 URL 후보들의 유효성을 검증
+///
+/// 후보 주소는 외부 요청 정책이 적용된 클라이언트로 받아 본문이 피드인지 확인합니다.
+async fn verify_feed_candidates(http_client: &HttpClient, candidates: &[String]) -> Vec<String> {
     let mut valid_feeds = Vec::new();
 
     for candidate in candidates {
-        // 각 후보 URL에 대해 비동기 fetch 요청으로 검증
-        let js = format!(
-            r#"
-            return (async () => {{
-                try {{
-                    const response = await fetch("{}", {{
-                        method: 'GET',
-                        headers: {{
-                            'Accept': 'application/rss+xml, application/atom+xml, application/xml;q=0.9, text/xml;q=0.8, application/feed+json;q=0.9, */*;q=0.1'
-                        }},
-                        cache: 'no-store'
-                    }});
-                    
-                    if (!response.ok) return false;
-                    
-                    const contentType = response.headers.get('content-type') || '';
-                    const isFeedType = contentType.includes('xml') || 
-                                       contentType.includes('rss') || 
-                                       contentType.includes('atom') ||
-                                       contentType.includes('feed+json');
-                    
-                    if (isFeedType) return true;
-                    
-                    // 컨텐츠 타입이 명확하지 않으면 본문 확인
-                    const text = await response.text();
-                    const lowerText = text.toLowerCase();
-                    return lowerText.includes('<rss') || 
-                           lowerText.includes('<feed') || 
-                           (lowerText.includes('<?xml') && 
-                            (lowerText.includes('<channel') || lowerText.includes('<feed'))) ||
-                           lowerText.includes('jsonfeed');
-                }} catch (e) {{
-                    return false;
-                }}
-            }})();
-        "#,
-            candidate
-        );
-
-        let result = driver.execute(js, Vec::<Value>::new()).await?;
-
-        if let Some(is_valid) = result.json().as_bool() {
-            if is_valid {
-                valid_feeds.push(candidate.clone());
+        let body = match http_client.fetch_text(candidate).await {
+            Ok(body) => body,
+            Err(e) => {
+                info!(
+                    "[Service] Feed candidate is not available: {}, {}",
+                    candidate, e
+                );
+                continue;
             }
+        };
+
+        if looks_like_feed(&body) {
+            valid_feeds.push(candidate.clone());
         }
     }
 
-    Ok(valid_feeds)
+    valid_feeds
+}
+
+/// 본문이 RSS/Atom/JSON Feed인지 확인
+fn looks_like_feed(body: &str) -> bool {
+    let lower = body.to_lowercase();
+    lower.contains("<rss")
+        || lower.contains("<feed")
+        || (lower.contains("<?xml") && lower.contains("<channel"))
+        || lower.contains("jsonfeed")
 }
 
 /// 상대 URL을 절대 URL로 변환
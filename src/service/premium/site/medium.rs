@@ -1,19 +1,27 @@
 use sqlx::MySqlPool;
 
 use crate::{
-    model::error::OmniNewsError, service::channel_service, utils::embedding_util::EmbeddingService,
+    config::http_client::HttpClient, model::error::OmniNewsError, service::channel_service,
+    utils::embedding_util::EmbeddingService,
 };
 // channel : https://medium.com/@sonnyhallett
 // rss : https://medium.com/@sonnyhallett/feed
 pub async fn generate_rss(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
+    http_client: &HttpClient,
     link: &str,
 ) -> Result<i32, OmniNewsError> {
     let user = extract_user_by_link(link)?;
     let medium_rss_link = format!("https://medium.com/{}/feed", user);
 
-    match channel_service::create_rss_and_embedding(pool, embedding_service, medium_rss_link).await
+    match channel_service::create_rss_and_embedding(
+        pool,
+        embedding_service,
+        http_client,
+        medium_rss_link,
+    )
+    .await
     {
         Ok(channel_id) => Ok(channel_id),
         Err(e) => {
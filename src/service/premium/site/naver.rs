@@ -1,19 +1,28 @@
 use sqlx::MySqlPool;
 
 use crate::{
-    model::error::OmniNewsError, service::channel_service, utils::embedding_util::EmbeddingService,
+    config::http_client::HttpClient, model::error::OmniNewsError, service::channel_service,
+    utils::embedding_util::EmbeddingService,
 };
 // channel : https://blog.naver.com/editor_style
 // rss : https://blog.rss.naver.com/editor_style
 pub async fn generate_rss(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
+    http_client: &HttpClient,
     link: &str,
 ) -> Result<i32, OmniNewsError> {
     let user = extract_user_by_link(link)?;
     let naver_rss_link = format!("https://blog.rss.naver.com/{}", user);
 
-    match channel_service::create_rss_and_embedding(pool, embedding_service, naver_rss_link).await {
+    match channel_service::create_rss_and_embedding(
+        pool,
+        embedding_service,
+        http_client,
+        naver_rss_link,
+    )
+    .await
+    {
         Ok(channel_id) => Ok(channel_id),
         Err(e) => {
             error!(
@@ -1,7 +1,8 @@
 use sqlx::MySqlPool;
 
 use crate::{
-    model::error::OmniNewsError, service::channel_service, utils::embedding_util::EmbeddingService,
+    config::http_client::HttpClient, model::error::OmniNewsError, service::channel_service,
+    utils::embedding_util::EmbeddingService,
 };
 
 // channel : https://kang1027.tistory.com/
@@ -9,12 +10,19 @@ use crate::{
 pub async fn generate_rss(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
+    http_client: &HttpClient,
     link: &str,
 ) -> Result<i32, OmniNewsError> {
     let user = extract_user_by_link(link)?;
     let tistory_rss_link = format!("https://{}.tistory.com/rss", user);
 
-    match channel_service::create_rss_and_embedding(pool, embedding_service, tistory_rss_link).await
+    match channel_service::create_rss_and_embedding(
+        pool,
+        embedding_service,
+        http_client,
+        tistory_rss_link,
+    )
+    .await
     {
         Ok(channel_id) => Ok(channel_id),
         Err(e) => {
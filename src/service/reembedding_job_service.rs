@@ -0,0 +1,444 @@
+use std::{sync::Arc, time::Duration};
+
+use sqlx::MySqlPool;
+use tokio::sync::Mutex;
+
+use crate::{
+    dto::admin::response::ReembeddingJobResponseDto,
+    embedding_error, embedding_info, embedding_warn,
+    model::{
+        embedding::{
+            EmbeddingTarget, EmbeddingVersion, NewEmbedding, ReembeddingJob, ReembeddingJobPhase,
+            ReembeddingJobStatus,
+        },
+        error::OmniNewsError,
+    },
+    repository::{reembedding_job_repository, rss_channel_repository, rss_item_repository},
+    service::embedding_service,
+    utils::{
+        annoy_util,
+        embedding_util::{make_embedding_text, EmbeddingService},
+    },
+};
+
+// 한 번에 다시 임베딩하는 채널/아이템 수
+const REEMBEDDING_BATCH_SIZE: usize = 64;
+// 임베딩 큐가 가득 찼을 때 다시 시도하기까지 기다리는 시간
+const BUSY_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// 재임베딩 작업 관리자
+///
+/// 모든 채널과 아이템을 서버의 현재 임베딩 버전(모델, 문장 형식)으로 다시 임베딩합니다.
+/// 진행 위치는 reembedding_job 테이블에 배치마다 저장하므로, 일시 정지하거나 서버가 재시작되어도 이어서 진행합니다.
+///
+/// 재임베딩이 끝나면 검색 인덱스(`resources/*_embeddings.ann`)를 새 임베딩으로 다시 만듭니다.
+/// 그 전까지 검색은 기존 인덱스와 그 인덱스의 문장 형식 버전을 그대로 사용합니다.
+#[derive(Clone)]
+pub struct ReembeddingJobManager {
+    pool: MySqlPool,
+    embedding_service: EmbeddingService,
+    // 이 서버에서 실행 중인 작업 ID
+    running: Arc<Mutex<Option<i32>>>,
+}
+
+impl ReembeddingJobManager {
+    pub fn new(pool: MySqlPool, embedding_service: EmbeddingService) -> Self {
+        Self {
+            pool,
+            embedding_service,
+            running: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// 서버 시작 시 실행 중이던 작업을 이어서 진행합니다.
+    ///
+    /// 서버의 임베딩 버전이 바뀌었다면 이어서 진행할 수 없으므로 실패로 표시합니다.
+    pub async fn resume_running_jobs(&self) {
+        let jobs = match reembedding_job_repository::select_reembedding_jobs_by_status(
+            &self.pool,
+            ReembeddingJobStatus::Running,
+        )
+        .await
+        {
+            Ok(jobs) => jobs,
+            Err(e) => {
+                embedding_error!("[Service] Failed to select running reembedding jobs: {}", e);
+                return;
+            }
+        };
+
+        let version = self.embedding_service.version();
+        let mut running = self.running.lock().await;
+        for job in jobs {
+            let job_id = job.job_id.unwrap_or_default();
+            if job.version() == version && running.is_none() {
+                embedding_info!("[Service] Resume reembedding job: {}", job_id);
+                self.spawn(&mut running, job_id);
+            } else {
+                embedding_warn!(
+                    "[Service] Reembedding job {} can not be resumed with {:?}",
+                    job_id,
+                    version
+                );
+                self.mark_failed(job_id, "embedding version changed".to_string())
+                    .await;
+            }
+        }
+    }
+
+    /// 재임베딩을 시작합니다.
+    ///
+    /// 같은 버전의 끝나지 않은 작업이 있으면 그 작업을 이어서 진행하고, 없으면 새 작업을 만듭니다.
+    pub async fn start(&self) -> Result<ReembeddingJobResponseDto, OmniNewsError> {
+        let version = self.embedding_service.version();
+
+        // 작업을 실행할 때까지 잠가 두어 동시에 두 작업이 시작되지 않게 한다.
+        let mut running = self.running.lock().await;
+        if let Some(job_id) = *running {
+            // 일시 정지했지만 처리 중인 배치가 끝나지 않은 작업은 다시 실행 상태로 돌린다.
+            let resumed = reembedding_job_repository::update_reembedding_job_status(
+                &self.pool,
+                job_id,
+                &[ReembeddingJobStatus::Paused],
+                ReembeddingJobStatus::Running,
+                None,
+            )
+            .await
+            .map_err(|e| {
+                embedding_error!("[Service] Failed to resume reembedding job: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+            if resumed {
+                embedding_info!("[Service] Resume paused reembedding job: {}", job_id);
+            } else {
+                embedding_info!("[Service] Reembedding job is already running: {}", job_id);
+            }
+            return self.find_job(job_id).await;
+        }
+
+        let job_id = match self.find_latest_job().await? {
+            Some(job)
+                if job.version() == version
+                    && job.status() != Some(ReembeddingJobStatus::Completed) =>
+            {
+                let job_id = job.job_id.unwrap_or_default();
+                reembedding_job_repository::update_reembedding_job_status(
+                    &self.pool,
+                    job_id,
+                    &[
+                        ReembeddingJobStatus::Running,
+                        ReembeddingJobStatus::Paused,
+                        ReembeddingJobStatus::Failed,
+                    ],
+                    ReembeddingJobStatus::Running,
+                    None,
+                )
+                .await
+                .map_err(|e| {
+                    embedding_error!("[Service] Failed to resume reembedding job: {}", e);
+                    OmniNewsError::Database(e)
+                })?;
+                embedding_info!("[Service] Resume reembedding job: {}", job_id);
+                job_id
+            }
+            _ => {
+                let total = self.count_targets().await?;
+                let job_id =
+                    reembedding_job_repository::insert_reembedding_job(&self.pool, &version, total)
+                        .await
+                        .map_err(|e| {
+                            embedding_error!("[Service] Failed to insert reembedding job: {}", e);
+                            OmniNewsError::Database(e)
+                        })?;
+                embedding_info!(
+                    "[Service] Start reembedding job {} for {:?}: {} targets",
+                    job_id,
+                    version,
+                    total
+                );
+                job_id
+            }
+        };
+
+        self.spawn(&mut running, job_id);
+        self.find_job(job_id).await
+    }
+
+    /// 실행 중인 작업을 일시 정지합니다. 처리 중인 배치가 끝나면 멈춥니다.
+    pub async fn pause(&self) -> Result<ReembeddingJobResponseDto, OmniNewsError> {
+        let job = self
+            .find_latest_job()
+            .await?
+            .filter(|job| job.status() == Some(ReembeddingJobStatus::Running))
+            .ok_or_else(|| {
+                OmniNewsError::InvalidRequest("no running reembedding job".to_string())
+            })?;
+        let job_id = job.job_id.unwrap_or_default();
+
+        reembedding_job_repository::update_reembedding_job_status(
+            &self.pool,
+            job_id,
+            &[ReembeddingJobStatus::Running],
+            ReembeddingJobStatus::Paused,
+            None,
+        )
+        .await
+        .map_err(|e| {
+            embedding_error!("[Service] Failed to pause reembedding job: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+        embedding_info!("[Service] Pause reembedding job: {}", job_id);
+
+        self.find_job(job_id).await
+    }
+
+    /// 가장 최근 작업의 진행 상황을 반환합니다.
+    pub async fn status(&self) -> Result<ReembeddingJobResponseDto, OmniNewsError> {
+        match self.find_latest_job().await? {
+            Some(job) => Ok(ReembeddingJobResponseDto::from_model(job)),
+            None => Err(OmniNewsError::NotFound("reembedding job".to_string())),
+        }
+    }
+
+    async fn find_job(&self, job_id: i32) -> Result<ReembeddingJobResponseDto, OmniNewsError> {
+        reembedding_job_repository::select_reembedding_job_by_id(&self.pool, job_id)
+            .await
+            .map(ReembeddingJobResponseDto::from_model)
+            .map_err(|e| {
+                embedding_error!("[Service] Failed to select reembedding job: {}", e);
+                OmniNewsError::Database(e)
+            })
+    }
+
+    async fn find_latest_job(&self) -> Result<Option<ReembeddingJob>, OmniNewsError> {
+        match reembedding_job_repository::select_latest_reembedding_job(&self.pool).await {
+            Ok(job) => Ok(Some(job)),
+            Err(sqlx::Error::RowNotFound) => Ok(None),
+            Err(e) => {
+                embedding_error!("[Service] Failed to select latest reembedding job: {}", e);
+                Err(OmniNewsError::Database(e))
+            }
+        }
+    }
+
+    async fn count_targets(&self) -> Result<i32, OmniNewsError> {
+        let version = self.embedding_service.version();
+        let channels = rss_channel_repository::count_rss_channels_to_reembed(&self.pool, &version)
+            .await
+            .map_err(|e| {
+                embedding_error!("[Service] Failed to count channels to reembed: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+        let items = rss_item_repository::count_rss_items_to_reembed(&self.pool, &version)
+            .await
+            .map_err(|e| {
+                embedding_error!("[Service] Failed to count items to reembed: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+
+        Ok((channels + items) as i32)
+    }
+
+    fn spawn(&self, running: &mut Option<i32>, job_id: i32) {
+        *running = Some(job_id);
+
+        let manager = self.clone();
+        tokio::spawn(async move {
+            loop {
+                manager.run(job_id).await;
+
+                let mut running = manager.running.lock().await;
+                // 멈추는 사이에 start가 작업을 다시 실행 상태로 돌렸으면 이어서 진행한다.
+                if manager.job_status(job_id).await == Some(ReembeddingJobStatus::Running) {
+                    continue;
+                }
+                *running = None;
+                break;
+            }
+        });
+    }
+
+    async fn job_status(&self, job_id: i32) -> Option<ReembeddingJobStatus> {
+        reembedding_job_repository::select_reembedding_job_by_id(&self.pool, job_id)
+            .await
+            .ok()
+            .and_then(|job| job.status())
+    }
+
+    async fn run(&self, job_id: i32) {
+        let version = self.embedding_service.version();
+        // 큐 용량보다 큰 배치는 항상 거절되므로 용량에 맞춘다.
+        let batch_size = REEMBEDDING_BATCH_SIZE
+            .min(self.embedding_service.config().queue_capacity)
+            .max(1) as i64;
+
+        loop {
+            let job =
+                match reembedding_job_repository::select_reembedding_job_by_id(&self.pool, job_id)
+                    .await
+                {
+                    Ok(job) => job,
+                    Err(e) => {
+                        embedding_error!("[Worker] Failed to select reembedding job: {}", e);
+                        return;
+                    }
+                };
+            if job.status() != Some(ReembeddingJobStatus::Running) {
+                embedding_info!("[Worker] Reembedding job stopped: {}", job_id);
+                return;
+            }
+
+            let phase = job.phase();
+            let cursor = job.job_cursor.unwrap_or_default();
+            let batch = match phase {
+                ReembeddingJobPhase::Channel => {
+                    rss_channel_repository::select_rss_channels_to_reembed(
+                        &self.pool, &version, cursor, batch_size,
+                    )
+                    .await
+                    .map(|channels| {
+                        channels
+                            .into_iter()
+                            .map(|channel| {
+                                let channel_id = channel.channel_id.unwrap_or_default();
+                                let sentence = make_embedding_text(
+                                    &channel.channel_title.unwrap_or_default(),
+                                    &channel.channel_description.unwrap_or_default(),
+                                );
+                                (channel_id, sentence, NewEmbedding::channel(channel_id))
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                }
+                ReembeddingJobPhase::Item => rss_item_repository::select_rss_items_to_reembed(
+                    &self.pool, &version, cursor, batch_size,
+                )
+                .await
+                .map(|items| {
+                    items
+                        .into_iter()
+                        .map(|item| {
+                            let sentence = make_embedding_text(
+                                &item.rss_title.unwrap_or_default(),
+                                &item.rss_description.unwrap_or_default(),
+                            );
+                            (
+                                item.rss_id.unwrap_or_default(),
+                                sentence,
+                                NewEmbedding::item(item.rss_id),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                }),
+            };
+            let batch = match batch {
+                Ok(batch) => batch,
+                Err(e) => {
+                    embedding_error!("[Worker] Failed to select reembedding targets: {}", e);
+                    self.mark_failed(job_id, e.to_string()).await;
+                    return;
+                }
+            };
+
+            // 현재 단계의 대상을 모두 처리했다.
+            if batch.is_empty() {
+                match phase {
+                    ReembeddingJobPhase::Channel => {
+                        self.save_progress(job_id, ReembeddingJobPhase::Item, 0, 0)
+                            .await;
+                        continue;
+                    }
+                    ReembeddingJobPhase::Item => {
+                        self.mark_completed(job_id).await;
+                        self.rebuild_indexes(&version).await;
+                        return;
+                    }
+                }
+            }
+
+            let last_id = batch.last().map(|(id, _, _)| *id).unwrap_or(cursor);
+            let processed = batch.len() as i32;
+            let (sentences, embeddings): (Vec<String>, Vec<NewEmbedding>) = batch
+                .into_iter()
+                .map(|(_, sentence, embedding)| (sentence, embedding))
+                .unzip();
+
+            match embedding_service::refresh_embeddings(
+                &self.pool,
+                &self.embedding_service,
+                sentences,
+                embeddings,
+            )
+            .await
+            {
+                Ok(_) => {
+                    self.save_progress(job_id, phase, last_id, processed).await;
+                }
+                Err(OmniNewsError::EmbeddingBusy) => {
+                    // 사용자 요청에 큐를 양보하고 같은 배치를 다시 시도한다.
+                    tokio::time::sleep(BUSY_RETRY_DELAY).await;
+                }
+                Err(e) => {
+                    embedding_error!("[Worker] Reembedding job {} failed: {}", job_id, e);
+                    self.mark_failed(job_id, e.to_string()).await;
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn save_progress(
+        &self,
+        job_id: i32,
+        phase: ReembeddingJobPhase,
+        cursor: i32,
+        processed: i32,
+    ) {
+        if let Err(e) = reembedding_job_repository::update_reembedding_job_progress(
+            &self.pool, job_id, phase, cursor, processed,
+        )
+        .await
+        {
+            embedding_error!("[Worker] Failed to save reembedding progress: {}", e);
+        }
+    }
+
+    async fn mark_completed(&self, job_id: i32) {
+        embedding_info!("[Worker] Reembedding job completed: {}", job_id);
+        if let Err(e) = reembedding_job_repository::update_reembedding_job_status(
+            &self.pool,
+            job_id,
+            &[ReembeddingJobStatus::Running],
+            ReembeddingJobStatus::Completed,
+            None,
+        )
+        .await
+        {
+            embedding_error!("[Worker] Failed to complete reembedding job: {}", e);
+        }
+    }
+
+    // 모든 임베딩이 새 버전이 되었으므로 검색 인덱스도 새 버전으로 바꾼다.
+    async fn rebuild_indexes(&self, version: &EmbeddingVersion) {
+        for target in [EmbeddingTarget::Channel, EmbeddingTarget::Item] {
+            if let Err(e) = annoy_util::rebuild_index(&self.pool, target, &version.model_id).await {
+                embedding_error!("[Worker] Failed to rebuild {:?} index: {}", target, e);
+            }
+        }
+    }
+
+    async fn mark_failed(&self, job_id: i32, error: String) {
+        let error: String = error.chars().take(1000).collect();
+        if let Err(e) = reembedding_job_repository::update_reembedding_job_status(
+            &self.pool,
+            job_id,
+            &[ReembeddingJobStatus::Running],
+            ReembeddingJobStatus::Failed,
+            Some(error),
+        )
+        .await
+        {
+            embedding_error!("[Worker] Failed to mark reembedding job failed: {}", e);
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use chrono::{Duration, Utc};
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::folder::{
+        request::{
+            CreateSmartFolderRequestDto, DeleteSmartFolderRequestDto, FolderItemsRequestDto,
+            UpdateSmartFolderRequestDto,
+        },
+        response::FolderItemsResponseDto,
+    },
+    folder_error, folder_warn,
+    model::{
+        error::OmniNewsError,
+        folder::{ItemCursor, NewSmartFolder, SmartFolder, SmartFolderFilter},
+    },
+    repository::smart_folder_repository,
+    utils::{
+        annoy_util::{load_rss_annoy_ids, SMART_FOLDER_RESULT_LIMIT},
+        embedding_util::EmbeddingService,
+    },
+};
+
+use super::{mute_service, user_service};
+
+const DEFAULT_ITEMS_LIMIT: i64 = 20;
+const MAX_ITEMS_LIMIT: i64 = 100;
+const MAX_SMART_FOLDER_DAYS: i32 = 365;
+
+pub async fn create_smart_folder(
+    pool: &MySqlPool,
+    user_email: String,
+    folder: CreateSmartFolderRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    validate_smart_folder(&folder)?;
+
+    let new_folder = NewSmartFolder::new(user_id, folder, Utc::now());
+    match smart_folder_repository::insert_smart_folder(pool, new_folder).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            folder_error!("[Service] Failed to create smart folder: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn update_smart_folder(
+    pool: &MySqlPool,
+    user_email: String,
+    folder: UpdateSmartFolderRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let smart_folder_id = folder.smart_folder_id.unwrap_or_default();
+
+    find_owned_smart_folder(pool, user_id, smart_folder_id).await?;
+    validate_smart_folder(&folder.smart_folder)?;
+
+    let new_folder = NewSmartFolder::new(user_id, folder.smart_folder, Utc::now());
+    match smart_folder_repository::update_smart_folder(pool, smart_folder_id, new_folder).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            folder_error!("[Service] Failed to update smart folder: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn delete_smart_folder(
+    pool: &MySqlPool,
+    user_email: String,
+    folder: DeleteSmartFolderRequestDto,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let smart_folder_id = folder.smart_folder_id.unwrap_or_default();
+
+    find_owned_smart_folder(pool, user_id, smart_folder_id).await?;
+
+    match smart_folder_repository::delete_smart_folder(pool, user_id, smart_folder_id).await {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            folder_error!("[Service] Failed to delete smart folder: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn find_smart_folders(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<SmartFolder>, OmniNewsError> {
+    match smart_folder_repository::select_smart_folders(pool, user_id).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            folder_error!("[Service] Failed to fetch smart folders: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 스마트 폴더의 검색 조건으로 아이템을 최신 순으로 조회합니다.
+///
+/// 의미 검색어가 있으면 Annoy 검색 결과(가까운 순 최대 `SMART_FOLDER_RESULT_LIMIT`개) 안에서
+/// 나머지 조건을 적용합니다.
+pub async fn fetch_smart_folder_items(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    user_email: String,
+    smart_folder_id: i32,
+    request: FolderItemsRequestDto,
+) -> Result<FolderItemsResponseDto, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email.clone()).await?;
+    let folder = find_owned_smart_folder(pool, user_id, smart_folder_id).await?;
+    let mute_filter = mute_service::load_mute_filter(pool, user_email).await?;
+
+    let limit = request
+        .limit
+        .unwrap_or(DEFAULT_ITEMS_LIMIT)
+        .clamp(1, MAX_ITEMS_LIMIT);
+    let cursor = match request.cursor.as_deref() {
+        Some(cursor) => ItemCursor::parse(cursor)?,
+        None => ItemCursor::first(),
+    };
+
+    let embedding_ids = match folder
+        .smart_folder_query
+        .clone()
+        .filter(|query| !query.trim().is_empty())
+    {
+        Some(query) => {
+            let embedding_ids =
+                load_rss_annoy_ids(embedding_service, query, SMART_FOLDER_RESULT_LIMIT).await?;
+            if embedding_ids.is_empty() {
+                return Ok(FolderItemsResponseDto::from_page(vec![], limit as usize));
+            }
+            Some(embedding_ids)
+        }
+        None => None,
+    };
+
+    let filter = SmartFolderFilter {
+        embedding_ids,
+        keywords: folder.keywords(),
+        channel_ids: folder.channel_ids(),
+        language: folder
+            .smart_folder_language
+            .clone()
+            .filter(|language| !language.trim().is_empty()),
+        published_after: folder
+            .smart_folder_days
+            .map(|days| Utc::now() - Duration::days(days as i64)),
+    };
+
+    match mute_service::fill_item_page(&mute_filter, cursor, limit, |cursor, limit| {
+        smart_folder_repository::select_smart_folder_items(pool, filter.clone(), cursor, limit)
+    })
+    .await
+    {
+        Ok((items, next_cursor)) => Ok(FolderItemsResponseDto::new(items, next_cursor)),
+        Err(e) => {
+            folder_error!("[Service] Failed to fetch smart folder items: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+async fn find_owned_smart_folder(
+    pool: &MySqlPool,
+    user_id: i32,
+    smart_folder_id: i32,
+) -> Result<SmartFolder, OmniNewsError> {
+    match smart_folder_repository::select_smart_folder_by_id(pool, smart_folder_id).await {
+        Ok(folder) if folder.user_id == Some(user_id) => Ok(folder),
+        Ok(_) => {
+            folder_warn!(
+                "[Service] User {} tried to access smart folder {} of another user",
+                user_id,
+                smart_folder_id
+            );
+            Err(OmniNewsError::Forbidden(
+                "Smart folder belongs to another user".to_string(),
+            ))
+        }
+        Err(sqlx::Error::RowNotFound) => Err(OmniNewsError::NotFound(
+            "Smart folder not found".to_string(),
+        )),
+        Err(e) => {
+            folder_error!("[Service] Failed to find smart folder: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+fn validate_smart_folder(folder: &CreateSmartFolderRequestDto) -> Result<(), OmniNewsError> {
+    if folder
+        .smart_folder_name
+        .as_deref()
+        .unwrap_or_default()
+        .trim()
+        .is_empty()
+    {
+        return Err(OmniNewsError::InvalidRequest(
+            "smart_folder_name is required".to_string(),
+        ));
+    }
+
+    let has_filter = folder
+        .smart_folder_query
+        .as_deref()
+        .is_some_and(|query| !query.trim().is_empty())
+        || folder
+            .keywords
+            .as_ref()
+            .is_some_and(|keywords| keywords.iter().any(|k| !k.trim().is_empty()))
+        || folder
+            .channel_ids
+            .as_ref()
+            .is_some_and(|ids| !ids.is_empty())
+        || folder
+            .language
+            .as_deref()
+            .is_some_and(|language| !language.trim().is_empty())
+        || folder.days.is_some();
+    if !has_filter {
+        return Err(OmniNewsError::InvalidRequest(
+            "Smart folder needs at least one filter".to_string(),
+        ));
+    }
+
+    if folder
+        .days
+        .is_some_and(|days| !(1..=MAX_SMART_FOLDER_DAYS).contains(&days))
+    {
+        return Err(OmniNewsError::InvalidRequest(format!(
+            "days must be between 1 and {}",
+            MAX_SMART_FOLDER_DAYS
+        )));
+    }
+
+    Ok(())
+}
@@ -10,7 +10,7 @@ use crate::{
     subscription_error, subscription_info,
 };
 
-use super::{channel_service, user_service};
+use super::{channel_service, mute_service, user_service};
 
 pub async fn get_subscription_channels(
     pool: &MySqlPool,
@@ -86,10 +86,22 @@ pub async fn unsubscribe_channel(
 
 pub async fn get_subscription_items(
     pool: &MySqlPool,
-    channel_ids: Vec<i32>,
+    user_email: String,
+    mut channel_ids: Vec<i32>,
 ) -> Result<Vec<RssItemResponseDto>, OmniNewsError> {
+    let mute_filter = mute_service::load_mute_filter(pool, user_email).await?;
+
+    // 뮤트한 채널은 조회하지 않고, 나머지 규칙은 조회한 아이템에 적용한다.
+    channel_ids.retain(|channel_id| !mute_filter.is_channel_id_muted(*channel_id));
+    if channel_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
     match subscribe_repository::select_subscription_items(pool, channel_ids).await {
-        Ok(res) => Ok(RssItemResponseDto::from_model_list(res)),
+        Ok(mut res) => {
+            res.retain(|item| !mute_filter.is_item_muted(item));
+            Ok(RssItemResponseDto::from_model_list(res))
+        }
         Err(e) => {
             subscription_error!("Failed to select subscription items: {}", e);
             Err(OmniNewsError::Database(e))
@@ -1,144 +1,212 @@
-use std::env;
+use std::{
+    collections::HashSet,
+    env,
+    io::{Cursor, Write},
+};
 
-use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
-use jsonwebtoken::{encode, EncodingKey, Header};
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use sqlx::MySqlPool;
+use uuid::Uuid;
+use zip::{result::ZipError, write::FileOptions, ZipWriter};
 
 use crate::{
     auth_middleware::Claims,
+    config::http_client::HttpClient,
     dto::{
-        auth::{
-            request::VerifyRefreshTokenRequestDto,
-            response::{AccessTokenResponseDto, JwtTokenResponseDto},
-        },
+        auth::{request::VerifyRefreshTokenRequestDto, response::JwtTokenResponseDto},
+        feedback::response::FeedbackResponseDto,
+        mute::response::MuteRuleResponseDto,
         user::{
             request::{
                 AppleLoginRequestDto, LoginUserRequestDto, UserNotificationRequestDto,
-                UserThemeRequestDto,
+                UserThemeRequestDto, UserTimezoneRequestDto,
+            },
+            response::{
+                UserDeviceDto, UserExportProfileDto, UserExportResponseDto, UserReadingHistoryDto,
+                UserThemeResponseDto, UserTimezoneResponseDto,
             },
-            response::UserThemeResponseDto,
         },
     },
     model::{
-        auth::{AccessToken, JwtToken, TokenType},
+        auth::{JwtToken, TokenType},
         error::OmniNewsError,
-        user::NewUser,
+        session::NewUserSession,
+        user::{NewUser, User},
+    },
+    repository::{feedback_repository, mute_repository, user_repository, user_session_repository},
+    service::{
+        folder_service,
+        identity_service::{self, VerifiedIdentity},
+        subscription_service,
     },
-    repository::user_repository,
     user_error, user_info, user_warn,
 };
 
-/// 1. access token O refresh token O -> processed in auto login.
-/// 2. access token X refresh token O -> processed in auto login.
-/// 3. access token X refresh token X -> Reissue refresh token and access token or Create user +
-///    Update User Info
+/// 제공자의 ID 토큰을 검증한 뒤 로그인합니다.
+///
+/// 토큰의 subject가 `user_social_provider_id`와 다르면 거부합니다.
+/// 요청 본문의 이메일은 사용하지 않고, 계정은 검증된 토큰의 정보로만 찾습니다.
 pub async fn login_or_create_user(
     pool: &MySqlPool,
+    http_client: &HttpClient,
     user: LoginUserRequestDto,
 ) -> Result<JwtTokenResponseDto, OmniNewsError> {
-    let (is_access_available, is_refresh_available) = user_repository::validate_users_all_tokens(
-        pool,
-        user.user_email.clone().unwrap_or_default(),
+    let provider = user.user_social_login_provider.clone().unwrap_or_default();
+    let identity = identity_service::verify_identity(
+        http_client,
+        &provider,
+        user.id_token.as_deref().unwrap_or_default(),
     )
-    .await
-    .map_err(|e| {
-        user_error!(
-            "[Service] Failed to validate access and refresh token: {}",
-            e
-        );
-        OmniNewsError::TokenValidationError
-    })?;
+    .await?;
 
-    // 1 or 2 -> new login
-    if is_access_available || is_refresh_available {
-        user_warn!("[Service] Access or refresh token is already alived ailed to login");
+    if user.user_social_provider_id.as_deref() != Some(identity.provider_id.as_str()) {
+        user_warn!(
+            "[Service] Provider id does not match identity token: provider={}",
+            provider
+        );
+        return Err(OmniNewsError::IdentityToken("subject mismatch".into()));
     }
 
-    // 3. access token X refresh token X -> reissue both token or create user
-    if (user_repository::select_user_id_by_email(pool, user.user_email.clone().unwrap()).await)
-        .is_ok()
-    {
-        // 3-1. reissue both
-        user_info!(
-            "[Service] 3-1. Success login: {}",
-            user.user_email.clone().unwrap()
-        );
-        let jwt_token = issue_tokens(pool, user.user_email.clone().unwrap()).await?;
-        // update user info
+    login_with_verified_identity(pool, &provider, identity, user).await
+}
+
+/// 1. Exist user -> Update user info + Issue new session (access token, refresh token)
+/// 2. New user -> Create user + Issue new session
+///
+/// `user`에서는 표시 이름, 사진, 기기 ID만 사용합니다.
+/// 세션은 기기별로 발급되므로 다른 기기의 로그인 상태에는 영향을 주지 않습니다.
+async fn login_with_verified_identity(
+    pool: &MySqlPool,
+    provider: &str,
+    identity: VerifiedIdentity,
+    user: LoginUserRequestDto,
+) -> Result<JwtTokenResponseDto, OmniNewsError> {
+    let device_id = user.device_id.clone();
+
+    // 1. exist user -> update user info and issue tokens
+    if let Some(exist_user) = find_user_by_identity(pool, provider, &identity).await? {
+        let user_email = exist_user.user_email.clone().unwrap_or_default();
+        if exist_user.user_status.as_deref() == Some("suspended") {
+            user_warn!("[Service] Suspended user tried to login: {}", user_email);
+            return Err(OmniNewsError::Forbidden("suspended user".into()));
+        }
+        let user_id = exist_user.user_id.unwrap_or_default();
+
+        // 탈퇴 유예 기간 중 다시 로그인하면 탈퇴를 취소한다.
+        if exist_user.user_status.as_deref() == Some("deleted") {
+            user_info!("[Service] Restore deleted user: {}", user_email);
+            if let Err(e) = user_repository::restore_user(pool, user_id).await {
+                user_error!("[Service] Failed to restore user: {}", e);
+                return Err(OmniNewsError::Database(e));
+            }
+        }
+        user_info!("[Service] 1. Success login: {}", user_email);
         match user_repository::update_user_info(
             pool,
-            user.user_email.clone(),
+            Some(user_email.clone()),
             user.user_display_name,
             user.user_photo_url,
-            user.user_social_login_provider,
-            user.user_social_provider_id,
+            Some(provider.to_string()),
+            Some(identity.provider_id),
         )
         .await
         {
             Ok(_) => {
-                user_info!(
-                    "[Service] User info updated for email: {}",
-                    user.user_email.clone().unwrap()
-                );
+                user_info!("[Service] User info updated for email: {}", user_email);
             }
             Err(e) => {
                 user_error!("[Service] Failed to update user info: {}", e);
                 return Err(OmniNewsError::Database(e));
             }
         }
-        user_info!(
-            "[Service] User info updated for email: {}",
-            user.user_email.clone().unwrap()
-        );
 
+        let jwt_token = issue_tokens(pool, user_id, user_email, device_id).await?;
         return Ok(JwtTokenResponseDto::from_model(jwt_token));
     }
-    // 3-2.create user
-    user_info!(
-        "[Service] 3-2. Success login: {}",
-        user.user_email.clone().unwrap()
-    );
 
-    let jwt_token = create_user(pool, user).await?;
+    // 2. create user (검증된 이메일이 있을 때만)
+    let user_email = identity.email.clone().ok_or_else(|| {
+        user_warn!(
+            "[Service] Can not create user without verified email: provider={}",
+            provider
+        );
+        OmniNewsError::IdentityToken("verified email required".into())
+    })?;
+    user_info!("[Service] 2. Success login: {}", user_email);
+
+    let user_id = create_user(
+        pool,
+        LoginUserRequestDto {
+            user_email: Some(user_email.clone()),
+            user_social_login_provider: Some(provider.to_string()),
+            user_social_provider_id: Some(identity.provider_id),
+            ..user
+        },
+    )
+    .await?;
+    let jwt_token = issue_tokens(pool, user_id, user_email, device_id).await?;
     Ok(JwtTokenResponseDto::from_model(jwt_token))
 }
 
-async fn create_user(
+/// 검증된 제공자 정보로 계정을 찾습니다.
+///
+/// 1. 제공자와 제공자 사용자 ID가 같은 계정
+/// 2. 검증된 이메일이 같고 아직 다른 제공자 계정과 연결되지 않은 계정 (연결)
+///
+/// 이메일이 같더라도 다른 제공자 사용자 ID와 연결된 계정이면
+/// `AccountLinkedToOtherProvider`(409, `ACCOUNT_LINKED_TO_OTHER_PROVIDER`)로 거부합니다.
+/// 클라이언트는 에러 메시지의 제공자로 다시 로그인하도록 안내합니다.
+async fn find_user_by_identity(
     pool: &MySqlPool,
-    user: LoginUserRequestDto,
-) -> Result<JwtToken, OmniNewsError> {
-    let (access_token, access_token_expires_at) = make_token(
-        TokenType::Access,
-        user.user_email.clone().unwrap_or_default(),
-    )?;
-
-    let (refresh_token, refresh_token_expires_at) = make_token(
-        TokenType::Refresh,
-        user.user_email.clone().unwrap_or_default(),
-    )?;
-
-    let ktc_now = Utc::now()
-        .with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
-        .naive_utc();
-
-    let new_user = NewUser::new(
-        user,
-        JwtToken::new(
-            access_token.clone(),
-            refresh_token.clone(),
-            access_token_expires_at,
-            refresh_token_expires_at,
-        ),
-        ktc_now,
-    );
-    match user_repository::insert_user(pool, new_user).await {
-        Ok(_) => Ok(JwtToken::new(
-            access_token,
-            refresh_token,
-            access_token_expires_at,
-            refresh_token_expires_at,
-        )),
+    provider: &str,
+    identity: &VerifiedIdentity,
+) -> Result<Option<User>, OmniNewsError> {
+    match user_repository::select_user_by_social_provider_id(pool, provider, &identity.provider_id)
+        .await
+    {
+        Ok(user) => return Ok(Some(user)),
+        Err(sqlx::Error::RowNotFound) => {}
+        Err(e) => {
+            user_error!("[Service] Failed to select user by provider id: {}", e);
+            return Err(OmniNewsError::Database(e));
+        }
+    }
 
+    let Some(email) = identity.email.clone() else {
+        return Ok(None);
+    };
+    match user_repository::select_user_by_email(pool, email.clone()).await {
+        Ok(user) => {
+            let linked = user
+                .user_social_provider_id
+                .as_deref()
+                .is_some_and(|id| !id.is_empty());
+            if linked {
+                user_warn!(
+                    "[Service] Account is linked to another identity: {}, provider={}",
+                    email,
+                    provider
+                );
+                return Err(OmniNewsError::AccountLinkedToOtherProvider(
+                    user.user_social_login_provider.unwrap_or_default(),
+                ));
+            }
+            Ok(Some(user))
+        }
+        Err(sqlx::Error::RowNotFound) => Ok(None),
+        Err(e) => {
+            user_error!("[Service] Failed to select user by email: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+async fn create_user(pool: &MySqlPool, user: LoginUserRequestDto) -> Result<i32, OmniNewsError> {
+    let new_user = NewUser::new(user, Utc::now());
+    match user_repository::insert_user(pool, new_user).await {
+        Ok(user_id) => Ok(user_id),
         Err(e) => {
             user_error!("[Service] Failed to create user: {}", e);
             Err(OmniNewsError::Database(e))
@@ -146,13 +214,14 @@ async fn create_user(
     }
 }
 
+/// access token을 검증하고, 응답 시각을 변환할 사용자 시간대를 반환합니다.
 pub async fn vliadate_access_token(
     pool: &MySqlPool,
     token: String,
     email: String,
-) -> Result<bool, OmniNewsError> {
-    match user_repository::validate_access_token_by_user_email(pool, token, email).await {
-        Ok(_) => Ok(true),
+) -> Result<Tz, OmniNewsError> {
+    match user_session_repository::validate_access_token_by_user_email(pool, token, email).await {
+        Ok(user_timezone) => Ok(parse_user_timezone(user_timezone)),
         Err(_) => {
             user_error!("[Service] Token validation failed");
             Err(OmniNewsError::TokenValidationError)
@@ -160,88 +229,166 @@ pub async fn vliadate_access_token(
     }
 }
 
-pub async fn validate_refresh_token(
+/// refresh token을 검증하고 새 access token, refresh token을 발급합니다.
+///
+/// 이미 사용된 refresh token이 다시 들어오면 탈취된 것으로 보고 세션(token family)을 폐기합니다.
+pub async fn rotate_refresh_token(
     pool: &MySqlPool,
     refresh_token: VerifyRefreshTokenRequestDto,
-) -> Result<AccessTokenResponseDto, OmniNewsError> {
+) -> Result<JwtTokenResponseDto, OmniNewsError> {
     let user_email = refresh_token.email.clone().unwrap_or_default();
     let refresh_token = refresh_token.token.unwrap_or_default();
 
-    match user_repository::validate_refresh_token_by_user_email(
+    let claims = decode_token(&refresh_token)?;
+    if claims.sub != user_email {
+        user_error!(
+            "[Service] Refresh token subject does not match: {}",
+            user_email
+        );
+        return Err(OmniNewsError::TokenValidationError);
+    }
+    let session_id = claims.sid.ok_or_else(|| {
+        user_error!("[Service] Refresh token has no session id");
+        OmniNewsError::TokenValidationError
+    })?;
+
+    let session = user_session_repository::select_session_by_id(pool, &session_id)
+        .await
+        .map_err(|_| {
+            user_error!("[Service] Session not found: {}", session_id);
+            OmniNewsError::TokenValidationError
+        })?;
+
+    if session.session_revoked_at.is_some() {
+        user_warn!(
+            "[Service] Refresh token used on revoked session: {}",
+            session_id
+        );
+        return Err(OmniNewsError::TokenValidationError);
+    }
+
+    if session.session_refresh_token.as_deref() != Some(refresh_token.as_str()) {
+        revoke_reused_session(pool, &session_id, &user_email).await;
+        return Err(OmniNewsError::TokenValidationError);
+    }
+
+    let tokens = make_jwt_token(&user_email, &session_id)?;
+    match user_session_repository::rotate_session_tokens(
         pool,
-        refresh_token,
-        user_email.clone(),
+        &session_id,
+        &refresh_token,
+        tokens.clone(),
     )
     .await
     {
-        Ok(_) => {
-            user_info!("[Service] Reissue access token.");
-            let acces_token = reissue_access_token(pool, user_email).await?;
-
-            Ok(AccessTokenResponseDto::from_model(acces_token))
+        Ok(true) => {
+            user_info!("[Service] Rotate refresh token: {}", session_id);
+            Ok(JwtTokenResponseDto::from_model(tokens))
         }
-        Err(_) => {
-            user_error!("[Service] Refresh token validation failed");
+        // 동시에 같은 refresh token으로 교체가 일어난 경우
+        Ok(false) => {
+            revoke_reused_session(pool, &session_id, &user_email).await;
             Err(OmniNewsError::TokenValidationError)
         }
+        Err(e) => {
+            user_error!("[Service] Failed to rotate session tokens: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+async fn revoke_reused_session(pool: &MySqlPool, session_id: &str, user_email: &str) {
+    user_warn!(
+        "[Service] Refresh token reuse detected. Revoke session: {}, user: {}",
+        session_id,
+        user_email
+    );
+    if let Err(e) = user_session_repository::revoke_session(pool, session_id).await {
+        user_error!("[Service] Failed to revoke session: {}", e);
     }
 }
 
-async fn reissue_access_token(
+async fn issue_tokens(
     pool: &MySqlPool,
+    user_id: i32,
     user_email: String,
-) -> Result<AccessToken, OmniNewsError> {
-    let (access_token, access_token_expires_at) =
-        make_token(TokenType::Access, user_email.clone())?;
+    device_id: Option<String>,
+) -> Result<JwtToken, OmniNewsError> {
+    let session_id = Uuid::new_v4().to_string();
+    let tokens = make_jwt_token(&user_email, &session_id)?;
 
-    let _ = user_repository::update_user_access_token(
-        pool,
-        user_email,
-        access_token.clone(),
-        access_token_expires_at,
-    )
-    .await?;
+    if let Some(device_id) = &device_id {
+        if let Err(e) =
+            user_session_repository::delete_sessions_by_device(pool, user_id, device_id).await
+        {
+            user_error!("[Service] Failed to remove previous device session: {}", e);
+            return Err(OmniNewsError::Database(e));
+        }
+    }
 
-    Ok(AccessToken::new(access_token, access_token_expires_at))
+    let session = NewUserSession::new(session_id, user_id, device_id, tokens.clone(), Utc::now());
+    match user_session_repository::insert_session(pool, session).await {
+        Ok(_) => Ok(tokens),
+        Err(e) => {
+            user_error!("[Service] Failed to insert user session: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
 }
 
-async fn issue_tokens(pool: &MySqlPool, user_email: String) -> Result<JwtToken, OmniNewsError> {
+fn make_jwt_token(user_email: &str, session_id: &str) -> Result<JwtToken, OmniNewsError> {
     let (access_token, access_token_expires_at) =
-        make_token(TokenType::Access, user_email.clone())?;
+        make_token(TokenType::Access, user_email.to_string(), session_id)?;
 
     let (refresh_token, refresh_token_expires_at) =
-        make_token(TokenType::Refresh, user_email.clone())?;
+        make_token(TokenType::Refresh, user_email.to_string(), session_id)?;
 
-    let tokens = JwtToken::new(
+    Ok(JwtToken::new(
         access_token,
         refresh_token,
         access_token_expires_at,
         refresh_token_expires_at,
-    );
-    match user_repository::update_uesr_tokens(pool, user_email, tokens.clone()).await {
-        Ok(_) => Ok(tokens),
-        Err(e) => {
-            user_error!("[Service] Failed to update user tokens: {}", e);
-            Err(OmniNewsError::Database(e))
-        }
-    }
+    ))
+}
+
+fn decode_token(token: &str) -> Result<Claims, OmniNewsError> {
+    let key_string = env::var("JWT_SECRET_KEY").unwrap();
+
+    let mut validation = Validation::default();
+    validation.validate_aud = true;
+    validation.aud = Some(HashSet::from([String::from("omninews")]));
+
+    decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(key_string.as_bytes()),
+        &validation,
+    )
+    .map(|data| data.claims)
+    .map_err(|e| {
+        user_error!("[Service] Failed to decode JWT token: {}", e);
+        OmniNewsError::TokenValidationError
+    })
 }
 
 fn make_token(
     token_type: TokenType,
     sub: String,
-) -> Result<(String, NaiveDateTime), OmniNewsError> {
+    session_id: &str,
+) -> Result<(String, DateTime<Utc>), OmniNewsError> {
     let key_string = env::var("JWT_SECRET_KEY").unwrap();
     let key = key_string.as_bytes();
 
     match token_type {
         TokenType::Access => {
-            let access_token_exp = Utc::now().timestamp() as u64 + (60 * 60 * 24) + (9 * 3600); // 1 day and kst
+            // JWT exp는 UTC 기준 unix timestamp
+            let access_token_exp = Utc::now().timestamp() as u64 + (60 * 60 * 24); // 1 day
             let access_token_claim = Claims {
                 aud: "omninews".to_owned(),
                 sub: sub.clone(),
                 company: "kdh".to_owned(),
                 exp: access_token_exp,
+                sid: Some(session_id.to_string()),
+                jti: Some(Uuid::new_v4().to_string()),
             };
 
             let access_token = match encode(
@@ -260,19 +407,19 @@ fn make_token(
 
             Ok((
                 access_token.unwrap_or_default(),
-                access_token_expires_at.unwrap_or_default().naive_utc(),
+                access_token_expires_at.unwrap_or_default(),
             ))
         }
         TokenType::Refresh => {
-            let refresh_token_exp =
-                Utc::now().timestamp() as u64 + (60 * 60 * 24 * 31) + (9 * 3600); // 1 month and
-                                                                                  // kst
+            let refresh_token_exp = Utc::now().timestamp() as u64 + (60 * 60 * 24 * 31); // 1 month
 
             let refresh_token_claim = Claims {
                 aud: "omninews".to_owned(),
                 sub,
                 company: "kdh".to_owned(),
                 exp: refresh_token_exp,
+                sid: Some(session_id.to_string()),
+                jti: Some(Uuid::new_v4().to_string()),
             };
 
             let refresh_token = match encode(
@@ -291,7 +438,7 @@ fn make_token(
 
             Ok((
                 refresh_token.unwrap_or_default(),
-                refresh_token_expires_at.unwrap_or_default().naive_utc(),
+                refresh_token_expires_at.unwrap_or_default(),
             ))
         }
     }
@@ -299,31 +446,48 @@ fn make_token(
 
 pub async fn apple_login(
     pool: &MySqlPool,
+    http_client: &HttpClient,
     apple_login: AppleLoginRequestDto,
 ) -> Result<JwtTokenResponseDto, OmniNewsError> {
-    let user_email = user_repository::select_user_email_by_social_provider_id(
-        pool,
-        apple_login
-            .user_social_provider_id
-            .clone()
-            .unwrap_or_default(),
+    let identity = identity_service::verify_identity(
+        http_client,
+        "apple",
+        apple_login.id_token.as_deref().unwrap_or_default(),
     )
     .await?;
 
-    // Apple login is not a new user, so we can issue tokens directly
-    login_or_create_user(
+    if apple_login.user_social_provider_id.as_deref() != Some(identity.provider_id.as_str()) {
+        user_warn!("[Service] Apple provider id does not match identity token");
+        return Err(OmniNewsError::IdentityToken("subject mismatch".into()));
+    }
+
+    login_with_verified_identity(
         pool,
+        "apple",
+        identity,
         LoginUserRequestDto {
-            user_email: Some(user_email),
+            user_email: None,
             user_display_name: None,
             user_photo_url: None,
-            user_social_login_provider: Some("apple".to_string()),
-            user_social_provider_id: apple_login.user_social_provider_id,
+            user_social_login_provider: None,
+            user_social_provider_id: None,
+            device_id: apple_login.device_id,
+            id_token: None,
         },
     )
     .await
 }
 
+pub async fn find_user_role(pool: &MySqlPool, user_email: String) -> Result<String, OmniNewsError> {
+    match user_repository::select_user_by_email(pool, user_email).await {
+        Ok(user) => Ok(user.user_role.unwrap_or_default()),
+        Err(e) => {
+            user_error!("[Service] Failed to find user role: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
 pub async fn find_user_id_by_email(
     pool: &MySqlPool,
     user_email: String,
@@ -337,8 +501,18 @@ pub async fn find_user_id_by_email(
     }
 }
 
-pub async fn delete_user_token(pool: &MySqlPool, user_email: String) -> Result<(), OmniNewsError> {
-    match user_repository::delete_user_token_by_email(pool, user_email).await {
+/// 현재 기기의 세션만 폐기합니다. 세션 ID가 없으면(이전 토큰) 모든 세션을 폐기합니다.
+pub async fn delete_user_token(
+    pool: &MySqlPool,
+    user_email: String,
+    session_id: Option<String>,
+) -> Result<(), OmniNewsError> {
+    let result = match session_id {
+        Some(session_id) => user_session_repository::revoke_session(pool, &session_id).await,
+        None => user_session_repository::revoke_sessions_by_user_email(pool, user_email).await,
+    };
+
+    match result {
         Ok(_) => Ok(()),
         Err(e) => {
             user_error!("[Service] Failed to remove user token: {}", e);
@@ -346,6 +520,152 @@ pub async fn delete_user_token(pool: &MySqlPool, user_email: String) -> Result<(
         }
     }
 }
+/// 회원 탈퇴 요청
+///
+/// 모든 세션을 폐기하고 탈퇴 상태로 표시합니다. 데이터는 유예 기간이 지난 뒤
+/// `purge_deleted_users`에서 완전히 삭제됩니다.
+pub async fn delete_user(pool: &MySqlPool, user_email: String) -> Result<(), OmniNewsError> {
+    let user_id = find_user_id_by_email(pool, user_email.clone()).await?;
+
+    if let Err(e) = user_repository::soft_delete_user(pool, user_id, Utc::now()).await {
+        user_error!("[Service] Failed to soft delete user: {}", e);
+        return Err(OmniNewsError::Database(e));
+    }
+    if let Err(e) =
+        user_session_repository::revoke_sessions_by_user_email(pool, user_email.clone()).await
+    {
+        user_error!("[Service] Failed to revoke sessions of deleted user: {}", e);
+        return Err(OmniNewsError::Database(e));
+    }
+
+    user_info!("[Service] User requested deletion: {}", user_email);
+    Ok(())
+}
+
+/// 유예 기간이 지난 탈퇴 사용자를 완전히 삭제합니다.
+pub async fn purge_deleted_users(pool: &MySqlPool, grace_period: Duration) -> usize {
+    let threshold = Utc::now() - grace_period;
+    let user_ids = match user_repository::select_user_ids_deleted_before(pool, threshold).await {
+        Ok(user_ids) => user_ids,
+        Err(e) => {
+            user_error!("[Scheduler] Failed to select deleted users: {}", e);
+            return 0;
+        }
+    };
+
+    let mut purged = 0;
+    for user_id in user_ids {
+        match user_repository::purge_user(pool, user_id).await {
+            Ok(true) => purged += 1,
+            Ok(false) => {}
+            Err(e) => user_error!("[Scheduler] Failed to purge user {}: {}", user_id, e),
+        }
+    }
+    purged
+}
+
+/// 프로필, 구독, 폴더, 스마트 폴더, 뮤트 규칙, 읽기 기록, 기기, 피드백을 한 번에 내보냅니다.
+pub async fn export_user_data(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<UserExportResponseDto, OmniNewsError> {
+    let user = user_repository::select_user_by_email(pool, user_email.clone())
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select user for export: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let user_id = user.user_id.unwrap_or_default();
+
+    let subscriptions =
+        subscription_service::get_subscription_channels(pool, user_email.clone()).await?;
+    // 폴더 목록에는 스마트 폴더도 포함되어 있으므로 나눠서 내보낸다.
+    let (smart_folders, folders): (Vec<_>, Vec<_>) =
+        folder_service::fetch_folders(pool, user_email.clone())
+            .await?
+            .into_iter()
+            .partition(|folder| folder.folder_type.as_deref() == Some("smart"));
+    let mute_rules = mute_repository::select_mute_rules_by_user_id(pool, user_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select mute rules for export: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let devices = user_session_repository::select_sessions_by_user_id(pool, user_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select sessions for export: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let feedback = feedback_repository::select_feedbacks_by_email(pool, user_email)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select feedback for export: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    Ok(UserExportResponseDto {
+        exported_at: Utc::now().fixed_offset(),
+        profile: UserExportProfileDto::from_model(&user),
+        subscriptions,
+        folders,
+        smart_folders,
+        mute_rules: MuteRuleResponseDto::from_model_list(mute_rules),
+        reading_history: UserReadingHistoryDto::from_model(&user),
+        devices: devices.into_iter().map(UserDeviceDto::from_model).collect(),
+        feedback: FeedbackResponseDto::from_model_list(feedback),
+    })
+}
+
+/// 내보내기 묶음을 항목별 JSON 파일로 나눈 zip으로 만듭니다.
+pub fn make_export_zip(export: &UserExportResponseDto) -> Result<Vec<u8>, OmniNewsError> {
+    let files = [
+        ("profile.json", serde_json::to_vec_pretty(&export.profile)),
+        (
+            "subscriptions.json",
+            serde_json::to_vec_pretty(&export.subscriptions),
+        ),
+        ("folders.json", serde_json::to_vec_pretty(&export.folders)),
+        (
+            "smart_folders.json",
+            serde_json::to_vec_pretty(&export.smart_folders),
+        ),
+        (
+            "mute_rules.json",
+            serde_json::to_vec_pretty(&export.mute_rules),
+        ),
+        (
+            "reading_history.json",
+            serde_json::to_vec_pretty(&export.reading_history),
+        ),
+        ("devices.json", serde_json::to_vec_pretty(&export.devices)),
+        ("feedback.json", serde_json::to_vec_pretty(&export.feedback)),
+    ];
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, content) in files {
+        let content =
+            content.map_err(|e| OmniNewsError::JsonParseError(format!("{}: {}", name, e)))?;
+        zip.start_file(name, FileOptions::default())
+            .and_then(|_| zip.write_all(&content).map_err(ZipError::Io))
+            .map_err(|e| {
+                user_error!("[Service] Failed to write export zip: {}", e);
+                OmniNewsError::Config(format!("failed to write export zip: {}", e))
+            })?;
+    }
+
+    match zip.finish() {
+        Ok(cursor) => Ok(cursor.into_inner()),
+        Err(e) => {
+            user_error!("[Service] Failed to finish export zip: {}", e);
+            Err(OmniNewsError::Config(format!(
+                "failed to finish export zip: {}",
+                e
+            )))
+        }
+    }
+}
+
 pub async fn update_user_notification_setting(
     pool: &MySqlPool,
     user_email: String,
@@ -398,3 +718,55 @@ pub async fn update_user_theme(
         }
     }
 }
+
+// 저장된 값이 없으면 기존 동작과 같은 한국 시간대를 돌려준다.
+const DEFAULT_USER_TIMEZONE: Tz = chrono_tz::Asia::Seoul;
+
+pub async fn get_user_timezone(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<UserTimezoneResponseDto, OmniNewsError> {
+    let tz = find_user_timezone(pool, user_email).await?;
+    Ok(UserTimezoneResponseDto::new(tz.name().to_string()))
+}
+
+/// 사용자 시간대를 찾습니다. 설정하지 않았으면 한국 시간대입니다.
+async fn find_user_timezone(pool: &MySqlPool, user_email: String) -> Result<Tz, OmniNewsError> {
+    match user_repository::get_user_timezone(pool, user_email).await {
+        Ok(user_timezone) => Ok(parse_user_timezone(user_timezone)),
+        Err(e) => {
+            user_error!("[Service] Failed to get user timezone: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+fn parse_user_timezone(user_timezone: Option<String>) -> Tz {
+    user_timezone
+        .and_then(|user_timezone| user_timezone.parse::<Tz>().ok())
+        .unwrap_or(DEFAULT_USER_TIMEZONE)
+}
+
+pub async fn update_user_timezone(
+    pool: &MySqlPool,
+    user_email: String,
+    timezone: UserTimezoneRequestDto,
+) -> Result<(), OmniNewsError> {
+    let user_timezone = timezone.user_timezone.unwrap_or_default();
+    // IANA 시간대 이름(예: Asia/Seoul)만 허용한다.
+    let Ok(tz) = user_timezone.trim().parse::<Tz>() else {
+        user_warn!("[Service] Invalid user timezone: {}", user_timezone);
+        return Err(OmniNewsError::InvalidRequest(format!(
+            "unknown timezone: {}",
+            user_timezone
+        )));
+    };
+
+    match user_repository::update_user_timezone(pool, user_email, tz.name().to_string()).await {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            user_error!("[Service] Failed to update user timezone: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
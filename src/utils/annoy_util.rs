@@ -1,34 +1,87 @@
-use std::{collections::HashSet, path::PathBuf};
+use std::{collections::HashSet, fs, io, path::PathBuf};
 
 use rocket::State;
+use serde::{Deserialize, Serialize};
+use sqlx::MySqlPool;
 
-use crate::model::error::OmniNewsError;
+use crate::{
+    embedding_error, embedding_info, embedding_warn,
+    model::{
+        embedding::{EmbeddingTarget, EmbeddingVersion},
+        error::OmniNewsError,
+    },
+    repository::embedding_repository,
+};
 
-use super::embedding_util::{embedding_sentence, EmbeddingService};
+use super::embedding_util::{
+    decode_embedding, embedding_sentence, make_embedding_text, make_search_text, EmbeddingService,
+};
 
 // 거리 임계값 상수 추가
 const DISTANCE_THRESHOLD: f32 = 0.6;
+// 인덱스를 만들 때 한 번에 읽는 임베딩 수
+const INDEX_BUILD_BATCH_SIZE: i64 = 1000;
+// 인덱스 트리 수. 많을수록 정확하지만 파일이 커진다.
+const INDEX_TREES: i32 = 10;
+// 검색 API가 가져오는 최대 결과 수
+const SEARCH_RESULT_LIMIT: i32 = 200;
+// 최소 탐색 노드 수 (결과 수 × 트리 수보다 작으면 이 값을 쓴다)
+const MIN_SEARCH_K: i32 = 10000;
+/// 스마트 폴더가 의미 검색어로 가져오는 최대 결과 수
+///
+/// 다른 조건(키워드, 채널, 기간)으로 다시 거르므로 검색 API보다 넓게 가져온다.
+pub const SMART_FOLDER_RESULT_LIMIT: i32 = 2000;
+
+/// `.ann` 파일 옆(`*.ann.meta.json`)에 저장하는 인덱스 정보
+///
+/// 인덱스의 벡터는 모두 이 모델, 문장 형식 버전, 차원으로 만든 임베딩입니다.
+/// 검색어도 같은 버전으로 임베딩해야 하므로 정보가 없거나 서버 설정과 다르면 인덱스를 사용하지 않습니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnnoyIndexMeta {
+    pub model_id: String,
+    pub text_recipe_version: i32,
+    pub dim: usize,
+    pub item_count: usize,
+}
+
+fn index_path(target: EmbeddingTarget) -> PathBuf {
+    match target {
+        EmbeddingTarget::Channel => PathBuf::from("../resources/channel_embeddings.ann"),
+        EmbeddingTarget::Item => PathBuf::from("../resources/rss_embeddings.ann"),
+    }
+}
+
+fn meta_path(target: EmbeddingTarget) -> PathBuf {
+    index_path(target).with_extension("ann.meta.json")
+}
+
+fn read_index_meta(target: EmbeddingTarget) -> Option<AnnoyIndexMeta> {
+    let path = meta_path(target);
+    let bytes = fs::read(&path).ok()?;
+    match serde_json::from_slice(&bytes) {
+        Ok(meta) => Some(meta),
+        Err(e) => {
+            embedding_warn!(
+                "[Annoy] Failed to parse index meta {}: {}",
+                path.display(),
+                e
+            );
+            None
+        }
+    }
+}
 
 pub async fn load_channel_annoy(
     service: &EmbeddingService,
     search_value: String,
 ) -> Result<(Vec<i32>, Vec<f32>), OmniNewsError> {
-    let annoy = rannoy::Rannoy::new(384);
-    annoy.load(PathBuf::from("../resources/channel_embeddings.ann"));
-
-    // 검색어 형식화
-    let search_query = format!("제목: {}. 내용: {}", search_value, search_value);
-    let embedding_search_text = embedding_sentence(service, search_query).await?;
-
-    // search_k 값 추가 (10000)
-    let (result_ids, distances) = annoy.get_nns_by_vector(embedding_search_text, 200, 10000);
-
-    // 거리 기반 필터링 적용
-    let filtered_results: Vec<(i32, f32)> = result_ids
-        .into_iter()
-        .zip(distances.into_iter())
-        .filter(|&(_, distance)| distance < DISTANCE_THRESHOLD)
-        .collect();
+    let filtered_results = search_index(
+        service,
+        EmbeddingTarget::Channel,
+        &search_value,
+        SEARCH_RESULT_LIMIT,
+    )
+    .await?;
 
     // 필터링된 결과 사용
     let filtered_ids: Vec<i32> = filtered_results.iter().map(|(id, _)| *id).collect();
@@ -48,28 +101,216 @@ pub async fn load_rss_annoy(
     service: &EmbeddingService,
     search_value: String,
 ) -> Result<(Vec<i32>, Vec<f32>), OmniNewsError> {
-    let annoy = rannoy::Rannoy::new(384);
-    annoy.load(PathBuf::from("../resources/rss_embeddings.ann"));
+    let filtered_results = search_index(
+        service,
+        EmbeddingTarget::Item,
+        &search_value,
+        SEARCH_RESULT_LIMIT,
+    )
+    .await?;
 
-    // 검색어 형식화
-    let search_query = format!("제목: {}. 내용: {}", search_value, search_value);
+    // 필터링된 결과 사용
+    let filtered_ids: Vec<i32> = filtered_results.iter().map(|(id, _)| *id).collect();
+    let filtered_distances: Vec<f32> = filtered_results.iter().map(|(_, dist)| *dist).collect();
+
+    Ok((filtered_ids, filtered_distances))
+}
+
+/// 검색어와 가까운 아이템 임베딩 ID를 최대 `limit`개까지 반환합니다.
+pub async fn load_rss_annoy_ids(
+    service: &EmbeddingService,
+    search_value: String,
+    limit: i32,
+) -> Result<Vec<i32>, OmniNewsError> {
+    let results = search_index(service, EmbeddingTarget::Item, &search_value, limit).await?;
+    Ok(results.into_iter().map(|(id, _)| id).collect())
+}
+
+/// 인덱스를 만든 임베딩과 같은 버전으로 검색어를 임베딩해 가까운 임베딩 ID와 거리를 반환합니다.
+///
+/// 재임베딩 중에는 인덱스를 다시 만들기 전까지 이전 문장 형식 버전으로 검색합니다.
+/// 인덱스 정보가 없거나 모델/차원이 맞지 않으면 버전이 섞이지 않도록 검색을 거절합니다.
+async fn search_index(
+    service: &EmbeddingService,
+    target: EmbeddingTarget,
+    search_value: &str,
+    limit: i32,
+) -> Result<Vec<(i32, f32)>, OmniNewsError> {
+    let meta = read_index_meta(target).ok_or_else(|| {
+        embedding_error!("[Annoy] {:?} index has no meta. Rebuild the index", target);
+        OmniNewsError::SearchIndexUnavailable
+    })?;
+    let model_id = &service.config().model_id;
+    if &meta.model_id != model_id {
+        embedding_error!(
+            "[Annoy] {:?} index was built with {}, but server uses {}",
+            target,
+            meta.model_id,
+            model_id
+        );
+        return Err(OmniNewsError::SearchIndexUnavailable);
+    }
+
+    let search_query =
+        make_search_text(meta.text_recipe_version, search_value).ok_or_else(|| {
+            embedding_error!(
+                "[Annoy] Unknown text recipe version of {:?} index: {}",
+                target,
+                meta.text_recipe_version
+            );
+            OmniNewsError::SearchIndexUnavailable
+        })?;
     let embedding_search_text = embedding_sentence(service, search_query).await?;
+    if embedding_search_text.len() != meta.dim {
+        embedding_error!(
+            "[Annoy] {:?} index dimension is {}, but query dimension is {}",
+            target,
+            meta.dim,
+            embedding_search_text.len()
+        );
+        return Err(OmniNewsError::SearchIndexUnavailable);
+    }
 
-    // search_k 값 추가
-    let (result_ids, distances) = annoy.get_nns_by_vector(embedding_search_text, 200, 10000);
+    let annoy = rannoy::Rannoy::new(meta.dim);
+    annoy.load(index_path(target));
+
+    let search_k = (limit * INDEX_TREES).max(MIN_SEARCH_K);
+    let (result_ids, distances) = annoy.get_nns_by_vector(embedding_search_text, limit, search_k);
 
     // 거리 기반 필터링 적용
-    let filtered_results: Vec<(i32, f32)> = result_ids
+    Ok(result_ids
         .into_iter()
         .zip(distances.into_iter())
         .filter(|&(_, distance)| distance < DISTANCE_THRESHOLD)
-        .collect();
+        .collect())
+}
 
-    // 필터링된 결과 사용
-    let filtered_ids: Vec<i32> = filtered_results.iter().map(|(id, _)| *id).collect();
-    let filtered_distances: Vec<f32> = filtered_results.iter().map(|(_, dist)| *dist).collect();
+/// 서버 시작 시 인덱스 정보가 없거나 서버와 다른 모델로 만든 인덱스를 다시 만듭니다.
+///
+/// 문장 형식 버전만 다른 인덱스는 재임베딩 작업이 끝날 때 다시 만들므로 그대로 둡니다.
+pub async fn rebuild_stale_indexes(pool: &MySqlPool, service: &EmbeddingService) {
+    let model_id = &service.config().model_id;
+    for target in [EmbeddingTarget::Channel, EmbeddingTarget::Item] {
+        match read_index_meta(target) {
+            Some(meta) if &meta.model_id == model_id => {
+                embedding_info!("[Annoy] {:?} index is up to date: {:?}", target, meta);
+            }
+            _ => {
+                if let Err(e) = rebuild_index(pool, target, model_id).await {
+                    embedding_error!("[Annoy] Failed to rebuild {:?} index: {}", target, e);
+                }
+            }
+        }
+    }
+}
 
-    Ok((filtered_ids, filtered_distances))
+/// 모델의 임베딩 중 가장 많은 문장 형식 버전으로 인덱스를 다시 만듭니다.
+///
+/// 해당 모델의 임베딩이 없으면 인덱스를 그대로 두고 None을 반환합니다. (검색은 계속 거절된다)
+pub async fn rebuild_index(
+    pool: &MySqlPool,
+    target: EmbeddingTarget,
+    model_id: &str,
+) -> Result<Option<AnnoyIndexMeta>, OmniNewsError> {
+    let (text_recipe_version, dim) =
+        match embedding_repository::select_dominant_embedding_version(pool, target, model_id)
+            .await
+            .map_err(|e| {
+                embedding_error!("[Annoy] Failed to select dominant embedding version: {}", e);
+                OmniNewsError::Database(e)
+            })? {
+            Some(version) => version,
+            None => {
+                embedding_warn!(
+                    "[Annoy] No {:?} embeddings for {}. Skip building index",
+                    target,
+                    model_id
+                );
+                return Ok(None);
+            }
+        };
+    let version = EmbeddingVersion {
+        model_id: model_id.to_string(),
+        text_recipe_version,
+    };
+
+    let mut items = Vec::new();
+    let mut cursor = 0;
+    loop {
+        let batch = embedding_repository::select_embeddings_by_version(
+            pool,
+            target,
+            &version,
+            dim,
+            cursor,
+            INDEX_BUILD_BATCH_SIZE,
+        )
+        .await
+        .map_err(|e| {
+            embedding_error!("[Annoy] Failed to select embeddings for index: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+        let Some((last_id, _)) = batch.last() else {
+            break;
+        };
+        cursor = *last_id;
+        items.extend(
+            batch
+                .into_iter()
+                .map(|(embedding_id, value)| (embedding_id, decode_embedding(&value))),
+        );
+    }
+
+    let meta = AnnoyIndexMeta {
+        model_id: version.model_id,
+        text_recipe_version,
+        dim: dim as usize,
+        item_count: items.len(),
+    };
+    let written = meta.clone();
+    tokio::task::spawn_blocking(move || write_index(target, &written, items))
+        .await
+        .map_err(|e| {
+            embedding_error!("[Annoy] Index build task failed: {}", e);
+            OmniNewsError::Embedding
+        })?
+        .map_err(|e| {
+            embedding_error!("[Annoy] Failed to write {:?} index: {}", target, e);
+            OmniNewsError::Embedding
+        })?;
+
+    embedding_info!("[Annoy] Rebuilt {:?} index: {:?}", target, meta);
+    Ok(Some(meta))
+}
+
+// 인덱스를 임시 파일에 만든 뒤 교체한다.
+// 교체하는 동안 정보 파일을 먼저 지워, 검색이 새 인덱스를 이전 정보로 읽지 않게 한다.
+fn write_index(
+    target: EmbeddingTarget,
+    meta: &AnnoyIndexMeta,
+    items: Vec<(i32, Vec<f32>)>,
+) -> io::Result<()> {
+    let path = index_path(target);
+    let tmp_path = path.with_extension("ann.tmp");
+
+    let annoy = rannoy::Rannoy::new(meta.dim);
+    for (embedding_id, embedding) in &items {
+        annoy.add_item(*embedding_id, embedding);
+    }
+    annoy.build(INDEX_TREES);
+    annoy.save(tmp_path.clone());
+
+    let meta_path = meta_path(target);
+    match fs::remove_file(&meta_path) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    fs::rename(&tmp_path, &path)?;
+
+    let meta_tmp_path = meta_path.with_extension("json.tmp");
+    fs::write(&meta_tmp_path, serde_json::to_vec_pretty(meta)?)?;
+    fs::rename(&meta_tmp_path, &meta_path)
 }
 
 #[allow(dead_code)]
@@ -77,13 +318,13 @@ pub async fn load_news_annoy(
     service: &State<EmbeddingService>,
     search_value: String,
 ) -> Result<(Vec<i32>, Vec<f32>), OmniNewsError> {
-    let annoy = rannoy::Rannoy::new(384);
-    annoy.load(PathBuf::from("../resources/news_embeddings.ann"));
-
     // 검색어 형식화
-    let search_query = format!("제목: {}. 내용: {}", search_value, search_value);
+    let search_query = make_embedding_text(&search_value, &search_value);
     let embedding_search_text = embedding_sentence(service, search_query).await?;
 
+    let annoy = rannoy::Rannoy::new(embedding_search_text.len());
+    annoy.load(PathBuf::from("../resources/news_embeddings.ann"));
+
     // search_k 값 추가
     let (result_ids, distances) = annoy.get_nns_by_vector(embedding_search_text, 10, 10000);
 
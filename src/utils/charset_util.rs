@@ -0,0 +1,192 @@
+use encoding_rs::{Encoding, EUC_KR, UTF_8};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+// XML 선언을 찾을 때 확인하는 본문 앞부분 크기
+const PROLOG_SCAN_BYTES: usize = 1024;
+
+lazy_static! {
+    static ref XML_DECL_ENCODING: Regex =
+        Regex::new(r#"^\s*<\?xml[^>]*?\bencoding\s*=\s*["']([A-Za-z0-9._:-]+)["']"#).unwrap();
+    static ref CONTENT_TYPE_CHARSET: Regex =
+        Regex::new(r#"(?i)charset\s*=\s*["']?([A-Za-z0-9._:-]+)"#).unwrap();
+}
+
+/// 응답 본문의 문자 인코딩을 판별해 UTF-8 문자열로 바꿉니다.
+///
+/// BOM, HTTP `Content-Type`의 charset, XML 선언, 휴리스틱(UTF-8 → EUC-KR/CP949) 순서로 인코딩을 정합니다.
+/// XML 선언에 적힌 인코딩은 변환 후 `UTF-8`로 바꿔 파서가 다시 해석하지 않게 합니다.
+pub fn decode_to_utf8(body: &[u8], content_type: Option<&str>) -> String {
+    let encoding = detect_encoding(body, content_type);
+    // BOM이 있으면 BOM의 인코딩이 우선하고 BOM은 결과에서 제거된다.
+    let (text, _, _) = encoding.decode(body);
+    rewrite_xml_declaration(&text)
+}
+
+/// 본문에 사용된 인코딩을 판별합니다.
+pub fn detect_encoding(body: &[u8], content_type: Option<&str>) -> &'static Encoding {
+    if let Some((encoding, _)) = Encoding::for_bom(body) {
+        return encoding;
+    }
+    if let Some(encoding) = content_type.and_then(charset_from_content_type) {
+        return encoding;
+    }
+    if let Some(encoding) = charset_from_xml_declaration(body) {
+        return encoding;
+    }
+    guess_encoding(body)
+}
+
+fn charset_from_content_type(content_type: &str) -> Option<&'static Encoding> {
+    let label = CONTENT_TYPE_CHARSET
+        .captures(content_type)?
+        .get(1)?
+        .as_str();
+    ascii_compatible(Encoding::for_label(label.as_bytes())?)
+}
+
+fn charset_from_xml_declaration(body: &[u8]) -> Option<&'static Encoding> {
+    let prolog = &body[..body.len().min(PROLOG_SCAN_BYTES)];
+    // 선언은 ASCII 범위이므로 나머지 바이트가 깨져도 상관없다.
+    let prolog = String::from_utf8_lossy(prolog);
+    let label = XML_DECL_ENCODING.captures(&prolog)?.get(1)?.as_str();
+    ascii_compatible(Encoding::for_label(label.as_bytes())?)
+}
+
+/// 선언된 인코딩이 없을 때는 UTF-8로 읽히는지 먼저 보고, 아니면 한국어 사이트에서 흔한 CP949로 읽는다.
+fn guess_encoding(body: &[u8]) -> &'static Encoding {
+    if std::str::from_utf8(body).is_ok() {
+        return UTF_8;
+    }
+    // encoding_rs의 EUC-KR은 CP949(windows-949) 확장 문자까지 포함한다.
+    let (_, had_errors) = EUC_KR.decode_without_bom_handling(body);
+    if !had_errors {
+        return EUC_KR;
+    }
+    UTF_8
+}
+
+/// BOM 없이 UTF-16으로 선언된 경우처럼 ASCII로 선언을 읽을 수 없는 인코딩은 믿지 않는다.
+fn ascii_compatible(encoding: &'static Encoding) -> Option<&'static Encoding> {
+    if encoding.is_ascii_compatible() {
+        Some(encoding)
+    } else {
+        None
+    }
+}
+
+fn rewrite_xml_declaration(text: &str) -> String {
+    match XML_DECL_ENCODING.captures(text).and_then(|c| c.get(1)) {
+        Some(label) if !label.as_str().eq_ignore_ascii_case("utf-8") => {
+            format!("{}UTF-8{}", &text[..label.start()], &text[label.end()..])
+        }
+        _ => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rss::Channel;
+
+    const EXPECTED_TITLE: &str = "한국 뉴스";
+    const EXPECTED_ITEM: &str = "오늘의 주요 소식";
+
+    fn fixture(name: &str) -> Vec<u8> {
+        let path = format!(
+            "{}/tests/fixtures/feeds/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        );
+        std::fs::read(&path).unwrap_or_else(|e| panic!("failed to read {}: {}", path, e))
+    }
+
+    fn parse(body: &[u8], content_type: Option<&str>) -> Channel {
+        let text = decode_to_utf8(body, content_type);
+        Channel::read_from(text.as_bytes()).expect("failed to parse decoded feed")
+    }
+
+    fn assert_korean_feed(channel: &Channel) {
+        assert_eq!(channel.title(), EXPECTED_TITLE);
+        assert_eq!(channel.items()[0].title(), Some(EXPECTED_ITEM));
+    }
+
+    #[test]
+    fn decodes_utf8_feed() {
+        let channel = parse(&fixture("utf8.xml"), Some("application/rss+xml"));
+        assert_korean_feed(&channel);
+    }
+
+    #[test]
+    fn decodes_utf8_bom_feed_even_if_header_disagrees() {
+        let body = fixture("utf8_bom.xml");
+        assert_eq!(
+            detect_encoding(&body, Some("text/xml; charset=euc-kr")),
+            UTF_8
+        );
+        assert_korean_feed(&parse(&body, Some("text/xml; charset=euc-kr")));
+    }
+
+    #[test]
+    fn decodes_utf16le_bom_feed() {
+        let body = fixture("utf16le_bom.xml");
+        assert_korean_feed(&parse(&body, None));
+    }
+
+    #[test]
+    fn decodes_euc_kr_feed_from_http_charset() {
+        let body = fixture("euc_kr_undeclared.xml");
+        assert_eq!(
+            detect_encoding(&body, Some("application/rss+xml; charset=\"EUC-KR\"")),
+            EUC_KR
+        );
+        assert_korean_feed(&parse(
+            &body,
+            Some("application/rss+xml; charset=\"EUC-KR\""),
+        ));
+    }
+
+    #[test]
+    fn decodes_euc_kr_feed_from_xml_declaration() {
+        let body = fixture("euc_kr.xml");
+        assert_eq!(detect_encoding(&body, Some("text/xml")), EUC_KR);
+
+        let text = decode_to_utf8(&body, Some("text/xml"));
+        assert!(text.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+        assert_korean_feed(&parse(&body, Some("text/xml")));
+    }
+
+    #[test]
+    fn decodes_undeclared_euc_kr_feed_by_heuristics() {
+        let body = fixture("euc_kr_undeclared.xml");
+        assert_eq!(detect_encoding(&body, None), EUC_KR);
+        assert_korean_feed(&parse(&body, None));
+    }
+
+    #[test]
+    fn decodes_cp949_extended_characters() {
+        let body = fixture("cp949.xml");
+        assert_eq!(detect_encoding(&body, None), EUC_KR);
+
+        let channel = parse(&body, None);
+        assert_korean_feed(&channel);
+        assert_eq!(channel.items()[1].title(), Some("똠양꿍 맛집 뷁"));
+    }
+
+    #[test]
+    fn http_charset_takes_priority_over_xml_declaration() {
+        let body = fixture("euc_kr.xml");
+        // 헤더가 잘못된 인코딩을 알려주면 그대로 따르므로 제목이 달라진다.
+        let text = decode_to_utf8(&body, Some("text/xml; charset=iso-8859-1"));
+        assert!(!text.contains(EXPECTED_TITLE));
+    }
+
+    #[test]
+    fn ignores_unknown_charset_label() {
+        let body = fixture("euc_kr.xml");
+        assert_eq!(
+            detect_encoding(&body, Some("text/xml; charset=unknown")),
+            EUC_KR
+        );
+    }
+}
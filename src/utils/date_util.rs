@@ -0,0 +1,356 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+// 시간대가 없는 날짜는 대부분 국내 사이트에서 온 값이므로 한국 시간으로 해석한다.
+const DEFAULT_OFFSET_SECS: i32 = 9 * 3600;
+
+// 시간대 없는 ISO 8601 형식
+const NAIVE_ISO_FORMATS: [&str; 4] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y%m%dT%H%M%S",
+];
+
+// 시간대가 있는 ISO 8601 형식 (+0900, +09:00)
+const ISO_FORMATS: [&str; 4] = [
+    "%Y-%m-%dT%H:%M:%S%.f%z",
+    "%Y-%m-%dT%H:%M%z",
+    "%Y-%m-%d %H:%M:%S%.f%z",
+    "%Y-%m-%d %H:%M:%S%.f %z",
+];
+
+// 요일을 떼어낸 RFC 822 변형 (%b는 월 전체 이름도 받는다)
+const RFC822_FORMATS: [&str; 6] = [
+    "%d %b %Y %H:%M:%S %z",
+    "%d %b %Y %H:%M %z",
+    "%d %b %y %H:%M:%S %z",
+    "%d %b %y %H:%M %z",
+    "%b %d %Y %H:%M:%S %z",
+    "%b %d %Y %H:%M %z",
+];
+
+lazy_static! {
+    static ref WHITESPACE: Regex = Regex::new(r"\s+").unwrap();
+    // 앞쪽 요일 (Wed, / Wednesday,)
+    static ref LEADING_WEEKDAY: Regex =
+        Regex::new(r"(?i)^(?:mon|tue|wed|thu|fri|sat|sun)[a-z]*\.?,?\s*").unwrap();
+    // 뒤쪽 주석 (+0900 (KST))
+    static ref TRAILING_COMMENT: Regex = Regex::new(r"\s*\([^)]*\)$").unwrap();
+    static ref TRAILING_ZONE_NAME: Regex = Regex::new(r"\s([A-Za-z]{1,5})$").unwrap();
+    // 2024.05.01 오후 3:20, 2024-05-01 15:20:30, 2024년 5월 1일 (수) 오후 3시 20분
+    static ref KOREAN_DATE: Regex = Regex::new(
+        r"(?i)^(\d{4})\s*(?:[./-]|년)\s*(\d{1,2})\s*(?:[./-]|월)\s*(\d{1,2})\s*(?:\.|일)?\s*(?:\([^)]*\)|[월화수목금토일]요일)?\s*(오전|오후|am|pm)?\s*(?:(\d{1,2})\s*(?::|시)\s*(?:(\d{1,2})\s*분?)?\s*(?::\s*(\d{1,2})|(\d{1,2})\s*초)?)?\s*(오전|오후|am|pm)?$"
+    )
+    .unwrap();
+    // 3시간 전, 5 minutes ago
+    static ref RELATIVE_DATE: Regex = Regex::new(
+        r"(?i)^(\d+)\s*(초|분|시간|일|주|개월|달|년|seconds?|secs?|minutes?|mins?|hours?|hrs?|days?|weeks?|months?|years?)\s*(?:전|ago)$"
+    )
+    .unwrap();
+    // 오늘 15:20, 어제 오후 3:20
+    static ref RELATIVE_DAY: Regex = Regex::new(
+        r"(?i)^(오늘|어제|그저께|그제|today|yesterday)\s*(오전|오후|am|pm)?\s*(?:(\d{1,2})\s*(?::|시)\s*(?:(\d{1,2})\s*분?)?)?\s*(오전|오후|am|pm)?$"
+    )
+    .unwrap();
+}
+
+/// 피드와 웹 페이지에서 흔히 쓰이는 날짜 문자열을 해석합니다.
+///
+/// RFC 2822/822 변형, RFC 3339/ISO 8601, `2024.05.01 오후 3:20` 같은 한국어 형식과
+/// `3시간 전` 같은 상대 시간을 지원합니다. 시간대가 없으면 한국 시간으로 해석합니다.
+pub fn parse_date(raw: &str) -> Option<DateTime<FixedOffset>> {
+    parse_date_at(raw, Utc::now())
+}
+
+/// `now`를 기준으로 상대 시간을 계산하는 `parse_date`
+pub fn parse_date_at(raw: &str, now: DateTime<Utc>) -> Option<DateTime<FixedOffset>> {
+    let value = WHITESPACE.replace_all(raw.trim(), " ").to_string();
+    if value.is_empty() {
+        return None;
+    }
+
+    parse_standard(&value)
+        .or_else(|| parse_iso(&value))
+        .or_else(|| parse_korean(&value))
+        .or_else(|| parse_relative(&value, now))
+        .or_else(|| parse_rfc822_variant(&value))
+}
+
+pub fn default_offset() -> FixedOffset {
+    FixedOffset::east_opt(DEFAULT_OFFSET_SECS).unwrap()
+}
+
+fn parse_standard(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc2822(value)
+        .or_else(|_| DateTime::parse_from_rfc3339(value))
+        .ok()
+}
+
+fn parse_iso(value: &str) -> Option<DateTime<FixedOffset>> {
+    // 소문자 t, z와 Z 표기를 정규화한다.
+    let value = value.replace('t', "T");
+    let value = match value.strip_suffix(['Z', 'z']) {
+        Some(rest) => format!("{}+0000", rest),
+        None => value,
+    };
+
+    if let Some(dt) = ISO_FORMATS
+        .iter()
+        .find_map(|format| DateTime::parse_from_str(&value, format).ok())
+    {
+        return Some(dt);
+    }
+
+    NAIVE_ISO_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(&value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(&value, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_time(NaiveTime::MIN))
+        })
+        .and_then(with_default_offset)
+}
+
+fn parse_korean(value: &str) -> Option<DateTime<FixedOffset>> {
+    let caps = KOREAN_DATE.captures(value)?;
+    let number = |idx: usize| caps.get(idx).and_then(|m| m.as_str().parse::<u32>().ok());
+
+    let date = NaiveDate::from_ymd_opt(number(1)? as i32, number(2)?, number(3)?)?;
+    let meridiem = caps.get(4).or_else(|| caps.get(9)).map(|m| m.as_str());
+    let hour = to_24_hour(number(5).unwrap_or(0), meridiem)?;
+    let time = NaiveTime::from_hms_opt(
+        hour,
+        number(6).unwrap_or(0),
+        number(7).or_else(|| number(8)).unwrap_or(0),
+    )?;
+
+    with_default_offset(date.and_time(time))
+}
+
+fn parse_relative(value: &str, now: DateTime<Utc>) -> Option<DateTime<FixedOffset>> {
+    let now = now.with_timezone(&default_offset());
+    let lower = value.to_lowercase();
+    if matches!(lower.as_str(), "방금" | "방금 전" | "just now" | "now") {
+        return Some(now);
+    }
+
+    if let Some(caps) = RELATIVE_DATE.captures(value) {
+        let amount = caps[1].parse::<i64>().ok()?;
+        let unit = caps[2].to_lowercase();
+        let duration = match unit.as_str() {
+            "초" => Duration::try_seconds(amount),
+            "분" => Duration::try_minutes(amount),
+            "시간" => Duration::try_hours(amount),
+            "일" => Duration::try_days(amount),
+            "주" => Duration::try_weeks(amount),
+            "개월" | "달" => Duration::try_days(amount.checked_mul(30)?),
+            "년" => Duration::try_days(amount.checked_mul(365)?),
+            unit if unit.starts_with("sec") => Duration::try_seconds(amount),
+            unit if unit.starts_with("min") => Duration::try_minutes(amount),
+            unit if unit.starts_with('h') => Duration::try_hours(amount),
+            unit if unit.starts_with('d') => Duration::try_days(amount),
+            unit if unit.starts_with('w') => Duration::try_weeks(amount),
+            unit if unit.starts_with("mon") => Duration::try_days(amount.checked_mul(30)?),
+            unit if unit.starts_with('y') => Duration::try_days(amount.checked_mul(365)?),
+            _ => None,
+        }?;
+        return now.checked_sub_signed(duration);
+    }
+
+    let caps = RELATIVE_DAY.captures(value)?;
+    let days_ago = match caps[1].to_lowercase().as_str() {
+        "오늘" | "today" => 0,
+        "어제" | "yesterday" => 1,
+        _ => 2,
+    };
+    let date = now.date_naive() - Duration::days(days_ago);
+    let Some(hour) = caps.get(3).and_then(|m| m.as_str().parse::<u32>().ok()) else {
+        // 시각이 없으면 오늘은 지금, 지난 날은 그날 자정으로 본다.
+        return if days_ago == 0 {
+            Some(now)
+        } else {
+            with_default_offset(date.and_time(NaiveTime::MIN))
+        };
+    };
+    let meridiem = caps.get(2).or_else(|| caps.get(5)).map(|m| m.as_str());
+    let minute = caps
+        .get(4)
+        .and_then(|m| m.as_str().parse::<u32>().ok())
+        .unwrap_or(0);
+    let time = NaiveTime::from_hms_opt(to_24_hour(hour, meridiem)?, minute, 0)?;
+
+    with_default_offset(date.and_time(time))
+}
+
+/// 요일이 틀리거나, 시간대가 약어이거나, 초가 없는 RFC 822 날짜를 해석한다.
+fn parse_rfc822_variant(value: &str) -> Option<DateTime<FixedOffset>> {
+    let value = TRAILING_COMMENT.replace(value, "");
+    // 요일은 검증하지 않고 버린다. (잘못된 요일을 쓰는 피드가 많다)
+    let value = LEADING_WEEKDAY.replace(&value, "").replace(',', "");
+
+    // KST처럼 chrono가 모르는 시간대 약어는 숫자 오프셋으로 바꾼다.
+    let zone = TRAILING_ZONE_NAME
+        .captures(&value)
+        .and_then(|caps| Some((caps.get(0)?.start(), zone_offset(&caps[1])?)));
+    let value = match zone {
+        Some((start, offset)) => format!("{} {}", &value[..start], offset),
+        None => value,
+    };
+
+    if let Some(dt) = RFC822_FORMATS
+        .iter()
+        .find_map(|format| DateTime::parse_from_str(&value, format).ok())
+    {
+        return Some(dt);
+    }
+
+    RFC822_FORMATS
+        .iter()
+        .map(|format| format.trim_end_matches(" %z"))
+        .find_map(|format| NaiveDateTime::parse_from_str(&value, format).ok())
+        .and_then(with_default_offset)
+}
+
+fn zone_offset(name: &str) -> Option<&'static str> {
+    let offset = match name.to_uppercase().as_str() {
+        "Z" | "UT" | "UTC" | "GMT" => "+0000",
+        "KST" | "JST" => "+0900",
+        "CST" => "-0600",
+        "CDT" => "-0500",
+        "EST" => "-0500",
+        "EDT" => "-0400",
+        "MST" => "-0700",
+        "MDT" => "-0600",
+        "PST" => "-0800",
+        "PDT" => "-0700",
+        "BST" | "CET" => "+0100",
+        "CEST" => "+0200",
+        "AEST" => "+1000",
+        "AEDT" => "+1100",
+        _ => return None,
+    };
+    Some(offset)
+}
+
+fn to_24_hour(hour: u32, meridiem: Option<&str>) -> Option<u32> {
+    let hour = match meridiem.map(|m| m.to_lowercase()).as_deref() {
+        Some("오후") | Some("pm") if hour < 12 => hour + 12,
+        Some("오전") | Some("am") if hour == 12 => 0,
+        _ => hour,
+    };
+    (hour < 24).then_some(hour)
+}
+
+fn with_default_offset(naive: NaiveDateTime) -> Option<DateTime<FixedOffset>> {
+    default_offset().from_local_datetime(&naive).single()
+}
+
+/// 응답 DTO의 시각 필드를 사용자 시간대로 바꿉니다.
+///
+/// DTO는 UTC 시각으로 만들고, 핸들러가 인증된 사용자의 시간대로 변환해 내려줍니다.
+pub trait LocalizeTimestamps {
+    fn localize(&mut self, tz: &Tz);
+
+    fn localized(mut self, tz: &Tz) -> Self
+    where
+        Self: Sized,
+    {
+        self.localize(tz);
+        self
+    }
+}
+
+impl LocalizeTimestamps for DateTime<FixedOffset> {
+    fn localize(&mut self, tz: &Tz) {
+        *self = self.with_timezone(tz).fixed_offset();
+    }
+}
+
+impl<T: LocalizeTimestamps> LocalizeTimestamps for Option<T> {
+    fn localize(&mut self, tz: &Tz) {
+        if let Some(value) = self {
+            value.localize(tz);
+        }
+    }
+}
+
+impl<T: LocalizeTimestamps> LocalizeTimestamps for Vec<T> {
+    fn localize(&mut self, tz: &Tz) {
+        self.iter_mut().for_each(|value| value.localize(tz));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kst(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> DateTime<FixedOffset> {
+        default_offset()
+            .with_ymd_and_hms(y, m, d, h, min, s)
+            .unwrap()
+    }
+
+    #[test]
+    fn parses_korean_date_with_meridiem() {
+        assert_eq!(
+            parse_date("2024.05.01 오후 3:20"),
+            Some(kst(2024, 5, 1, 15, 20, 0))
+        );
+    }
+
+    #[test]
+    fn parses_relative_date_from_now() {
+        let now = Utc.with_ymd_and_hms(2024, 5, 1, 6, 20, 0).unwrap();
+        assert_eq!(
+            parse_date_at("3시간 전", now),
+            Some(kst(2024, 5, 1, 12, 20, 0))
+        );
+    }
+
+    #[test]
+    fn parses_rfc822_with_zone_name_and_without_seconds() {
+        assert_eq!(
+            parse_date("Wed, 01 May 2024 15:20 KST"),
+            Some(kst(2024, 5, 1, 15, 20, 0))
+        );
+    }
+
+    #[test]
+    fn ignores_wrong_weekday() {
+        // 2024-05-01은 수요일이다.
+        assert_eq!(
+            parse_date("Mon, 01 May 2024 15:20:00 +0900"),
+            Some(kst(2024, 5, 1, 15, 20, 0))
+        );
+    }
+
+    #[test]
+    fn parses_date_only_iso_as_midnight() {
+        assert_eq!(parse_date("2024-05-01"), Some(kst(2024, 5, 1, 0, 0, 0)));
+    }
+
+    #[test]
+    fn returns_none_for_unparseable_value() {
+        assert_eq!(parse_date("not a date"), None);
+        assert_eq!(parse_date("   "), None);
+    }
+
+    #[test]
+    fn localizes_timestamps_to_timezone() {
+        let utc = Utc
+            .with_ymd_and_hms(2024, 5, 1, 6, 20, 0)
+            .unwrap()
+            .fixed_offset();
+        let mut values = vec![Some(utc), None];
+
+        values.localize(&chrono_tz::Asia::Seoul);
+
+        assert_eq!(values[0], Some(kst(2024, 5, 1, 15, 20, 0)));
+        assert_eq!(values[0].unwrap().to_rfc3339(), "2024-05-01T15:20:00+09:00");
+        assert_eq!(values[1], None);
+    }
+}
@@ -1,17 +1,31 @@
+use sqlx::mysql::MySqlConnectOptions;
 use sqlx::pool::PoolOptions;
 use sqlx::{mysql::MySql, pool::PoolConnection, MySqlPool};
-use std::env;
+use std::{env, str::FromStr};
 
-use crate::server_error;
+use crate::{server_error, server_info};
 
 pub async fn create_pool() -> MySqlPool {
     let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    PoolOptions::new()
+    // DATETIME 컬럼은 모두 UTC로 저장하므로 세션 시간대도 UTC로 고정한다. (NOW() 등)
+    let options = MySqlConnectOptions::from_str(&database_url)
+        .expect("DATABASE_URL is invalid")
+        .timezone(Some(String::from("+00:00")));
+    let pool = PoolOptions::new()
         .max_connections(10)
         .min_connections(5)
-        .connect(&database_url)
+        .connect_with(options)
         .await
-        .expect("Failed to create MySQL pool")
+        .expect("Failed to create MySQL pool");
+
+    // migrations/ 디렉터리의 번호순 마이그레이션 중 아직 적용되지 않은 것을 실행한다.
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("Failed to run database migrations");
+    server_info!("[DB] Database migrations are up to date");
+
+    pool
 }
 
 pub async fn get_db(pool: &MySqlPool) -> Result<PoolConnection<MySql>, sqlx::Error> {
@@ -20,3 +34,8 @@ pub async fn get_db(pool: &MySqlPool) -> Result<PoolConnection<MySql>, sqlx::Err
         e
     })
 }
+
+/// `IN (...)` 절에 넣을 `?` 자리표시자를 만듭니다.
+pub fn placeholders(len: usize) -> String {
+    vec!["?"; len].join(",")
+}
@@ -0,0 +1,349 @@
+use std::{env, path::PathBuf};
+
+use rust_bert::pipelines::sentence_embeddings::{
+    SentenceEmbeddingsBuilder, SentenceEmbeddingsModel, SentenceEmbeddingsModelType,
+};
+use serde::Deserialize;
+use serde_json::json;
+use tokio::runtime::Handle;
+
+use crate::{config::http_client::HttpClient, embedding_info};
+
+// DistiluseBaseMultilingualCased와 같은 차원
+const DEFAULT_HASHING_DIMENSION: usize = 512;
+
+/// 문장을 벡터로 바꾸는 임베딩 백엔드
+///
+/// 워커 스레드마다 하나씩 만들어 쓰므로 `Sync`일 필요는 없습니다.
+pub trait Embedder {
+    /// 입력 순서대로 임베딩을 돌려줍니다.
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, String>;
+}
+
+/// rust-bert 모델을 불러올 위치
+#[derive(Clone, Debug)]
+pub enum RustBertModelSource {
+    /// 변환된 모델(rust_model.ot, config.json 등)이 있는 디렉터리. 디렉터리 이름과 관계없이 `model_id`로 임베딩 버전을 기록한다.
+    Local {
+        model_dir: PathBuf,
+        model_id: String,
+    },
+    /// DistiluseBaseMultilingualCased를 원격에서 내려받는다. (`EMBEDDING_MODEL_DOWNLOAD=true`일 때만)
+    Download,
+}
+
+/// 사용할 임베딩 백엔드 설정
+#[derive(Clone, Debug)]
+pub enum EmbeddingBackend {
+    /// rust-bert 문장 임베딩 모델
+    RustBert { source: RustBertModelSource },
+    /// OpenAI 호환 `/embeddings` API
+    OpenAi {
+        url: String,
+        api_key: Option<String>,
+        model: String,
+        dimensions: Option<usize>,
+    },
+    /// 토큰 해시로 만드는 결정적 임베딩 (테스트, 오프라인 개발용)
+    Hashing { dimension: usize },
+}
+
+impl EmbeddingBackend {
+    /// `EMBEDDING_BACKEND`로 백엔드를 고릅니다. (기본값 `rust-bert`)
+    ///
+    /// - `rust-bert` : `EMBEDDING_MODEL_DIR`(변환된 모델 디렉터리)와 `EMBEDDING_MODEL_ID`(필수).
+    ///   디렉터리 없이 원격에서 내려받으려면 `EMBEDDING_MODEL_DOWNLOAD=true`를 명시해야 한다.
+    /// - `openai` : `EMBEDDING_API_URL`(필수), `EMBEDDING_API_KEY`, `EMBEDDING_API_MODEL`, `EMBEDDING_API_DIMENSIONS`
+    /// - `hashing` : `EMBEDDING_HASHING_DIMENSION` (기본값 512)
+    pub fn from_env() -> Self {
+        let backend = env::var("EMBEDDING_BACKEND").unwrap_or_else(|_| "rust-bert".to_string());
+        match backend.to_lowercase().as_str() {
+            "rust-bert" | "rust_bert" | "local" => EmbeddingBackend::RustBert {
+                source: rust_bert_source_from_env(),
+            },
+            "openai" => EmbeddingBackend::OpenAi {
+                url: env_string("EMBEDDING_API_URL")
+                    .expect("EMBEDDING_API_URL must be set for openai embedding backend"),
+                api_key: env_string("EMBEDDING_API_KEY"),
+                model: env_string("EMBEDDING_API_MODEL")
+                    .unwrap_or_else(|| "text-embedding-3-small".to_string()),
+                dimensions: env_string("EMBEDDING_API_DIMENSIONS")
+                    .and_then(|v| v.parse::<usize>().ok()),
+            },
+            "hashing" => EmbeddingBackend::Hashing {
+                dimension: env_string("EMBEDDING_HASHING_DIMENSION")
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .filter(|v| *v > 0)
+                    .unwrap_or(DEFAULT_HASHING_DIMENSION),
+            },
+            other => panic!("Unknown EMBEDDING_BACKEND: {}", other),
+        }
+    }
+
+    /// 워커 스레드에서 호출해 백엔드를 만듭니다.
+    ///
+    /// HTTP 백엔드는 `runtime`에서 요청을 보내고 워커 스레드는 응답을 기다린다.
+    pub fn create(
+        &self,
+        http_client: &HttpClient,
+        runtime: &Handle,
+    ) -> Result<Box<dyn Embedder>, String> {
+        match self {
+            EmbeddingBackend::RustBert { source } => {
+                RustBertEmbedder::new(source).map(|e| Box::new(e) as Box<dyn Embedder>)
+            }
+            EmbeddingBackend::OpenAi {
+                url,
+                api_key,
+                model,
+                dimensions,
+            } => Ok(Box::new(OpenAiEmbedder {
+                http_client: http_client.clone(),
+                runtime: runtime.clone(),
+                url: url.clone(),
+                api_key: api_key.clone(),
+                model: model.clone(),
+                dimensions: *dimensions,
+            })),
+            EmbeddingBackend::Hashing { dimension } => {
+                Ok(Box::new(HashingEmbedder::new(*dimension)))
+            }
+        }
+    }
+}
+
+impl EmbeddingBackend {
+    /// 임베딩 버전에 기록하는 모델 이름
+    pub fn model_id(&self) -> String {
+        match self {
+            EmbeddingBackend::RustBert {
+                source: RustBertModelSource::Local { model_id, .. },
+            } => model_id.clone(),
+            EmbeddingBackend::RustBert {
+                source: RustBertModelSource::Download,
+            } => "distiluse-base-multilingual-cased".to_string(),
+            EmbeddingBackend::OpenAi {
+                model, dimensions, ..
+            } => match dimensions {
+                Some(dimensions) => format!("{}@{}", model, dimensions),
+                None => model.clone(),
+            },
+            EmbeddingBackend::Hashing { dimension } => format!("hashing-{}", dimension),
+        }
+    }
+}
+
+fn env_string(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|v| !v.is_empty())
+}
+
+fn rust_bert_source_from_env() -> RustBertModelSource {
+    if let Some(model_dir) = env_string("EMBEDDING_MODEL_DIR") {
+        // 디렉터리 이름은 모델 버전을 나타내지 않으므로 임베딩 버전에 기록할 이름을 따로 받는다.
+        return RustBertModelSource::Local {
+            model_dir: PathBuf::from(model_dir),
+            model_id: env_string("EMBEDDING_MODEL_ID")
+                .expect("EMBEDDING_MODEL_ID must be set when EMBEDDING_MODEL_DIR is used"),
+        };
+    }
+
+    let download = env_string("EMBEDDING_MODEL_DOWNLOAD")
+        .and_then(|v| v.parse::<bool>().ok())
+        .unwrap_or(false);
+    if !download {
+        panic!(
+            "EMBEDDING_MODEL_DIR must be set for rust-bert embedding backend \
+            (set EMBEDDING_MODEL_DOWNLOAD=true to download the model instead)"
+        );
+    }
+    RustBertModelSource::Download
+}
+
+pub struct RustBertEmbedder {
+    model: SentenceEmbeddingsModel,
+}
+
+impl RustBertEmbedder {
+    pub fn new(source: &RustBertModelSource) -> Result<Self, String> {
+        let model = match source {
+            RustBertModelSource::Local { model_dir, .. } => {
+                embedding_info!("[Embedder] Loading local model: {}", model_dir.display());
+                SentenceEmbeddingsBuilder::local(model_dir.clone()).create_model()
+            }
+            RustBertModelSource::Download => {
+                // TOO 다국어 지원 모델로 변경
+                embedding_info!("[Embedder] EMBEDDING_MODEL_DOWNLOAD is set. Downloading model");
+                SentenceEmbeddingsBuilder::remote(
+                    SentenceEmbeddingsModelType::DistiluseBaseMultilingualCased,
+                )
+                .create_model()
+            }
+        };
+        model.map(|model| Self { model }).map_err(|e| e.to_string())
+    }
+}
+
+impl Embedder for RustBertEmbedder {
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, String> {
+        self.model.encode(texts).map_err(|e| e.to_string())
+    }
+}
+
+pub struct OpenAiEmbedder {
+    http_client: HttpClient,
+    runtime: Handle,
+    url: String,
+    api_key: Option<String>,
+    model: String,
+    dimensions: Option<usize>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiEmbeddingResponse {
+    data: Vec<OpenAiEmbeddingData>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiEmbeddingData {
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+impl OpenAiEmbedder {
+    async fn request(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, String> {
+        let mut body = json!({ "model": self.model, "input": texts });
+        if let Some(dimensions) = self.dimensions {
+            body["dimensions"] = json!(dimensions);
+        }
+
+        let mut request = self.http_client.post(&self.url).json(&body);
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = self
+            .http_client
+            .send(request)
+            .await
+            .map_err(|e| e.to_string())?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let message = response.text().await.unwrap_or_default();
+            return Err(format!("Embedding API returned {}: {}", status, message));
+        }
+
+        let mut response = response
+            .json::<OpenAiEmbeddingResponse>()
+            .await
+            .map_err(|e| e.to_string())?;
+        // 응답 순서는 보장되지 않으므로 index로 정렬한다.
+        response.data.sort_by_key(|data| data.index);
+        Ok(response
+            .data
+            .into_iter()
+            .map(|data| data.embedding)
+            .collect())
+    }
+}
+
+impl Embedder for OpenAiEmbedder {
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, String> {
+        self.runtime.block_on(self.request(texts))
+    }
+}
+
+/// 토큰마다 해시한 위치에 ±1을 더하는 결정적 임베딩
+///
+/// 모델 없이도 같은 문장은 같은 벡터, 토큰이 겹치는 문장은 가까운 벡터가 됩니다.
+pub struct HashingEmbedder {
+    dimension: usize,
+}
+
+impl HashingEmbedder {
+    pub fn new(dimension: usize) -> Self {
+        Self { dimension }
+    }
+
+    fn embed_one(&self, text: &str) -> Vec<f32> {
+        let mut embedding = vec![0.0; self.dimension];
+        for token in text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|token| !token.is_empty())
+        {
+            let hash = fnv1a(token.to_lowercase().as_bytes());
+            let index = (hash % self.dimension as u64) as usize;
+            let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+            embedding[index] += sign;
+        }
+        embedding
+    }
+}
+
+impl Embedder for HashingEmbedder {
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, String> {
+        Ok(texts.iter().map(|text| self.embed_one(text)).collect())
+    }
+}
+
+// 러스트 버전과 관계없이 같은 값을 내는 해시 (FNV-1a 64bit)
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cosine(a: &[f32], b: &[f32]) -> f32 {
+        let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        dot / (norm(a) * norm(b))
+    }
+
+    #[test]
+    fn hashing_embedder_is_deterministic() {
+        let embedder = HashingEmbedder::new(64);
+        let first = embedder
+            .embed(&["러스트 비동기 런타임", "Rust async"])
+            .unwrap();
+        let second = embedder
+            .embed(&["러스트 비동기 런타임", "Rust async"])
+            .unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 2);
+        assert!(first.iter().all(|embedding| embedding.len() == 64));
+    }
+
+    #[test]
+    fn hashing_embedder_ignores_case_and_punctuation() {
+        let embedder = HashingEmbedder::new(64);
+        let embeddings = embedder.embed(&["Rust, Async!", "rust async"]).unwrap();
+
+        assert_eq!(embeddings[0], embeddings[1]);
+    }
+
+    #[test]
+    fn hashing_embedder_places_overlapping_texts_closer() {
+        let embedder = HashingEmbedder::new(DEFAULT_HASHING_DIMENSION);
+        let embeddings = embedder
+            .embed(&[
+                "rust async runtime tokio",
+                "rust async runtime",
+                "kimchi stew recipe",
+            ])
+            .unwrap();
+
+        assert!(cosine(&embeddings[0], &embeddings[1]) > cosine(&embeddings[0], &embeddings[2]));
+    }
+
+    #[test]
+    fn hashing_embedder_returns_zero_vector_for_empty_text() {
+        let embedder = HashingEmbedder::new(8);
+        let embeddings = embedder.embed(&[""]).unwrap();
+
+        assert_eq!(embeddings[0], vec![0.0; 8]);
+    }
+}
@@ -1,108 +1,419 @@
 use std::{
-    sync::{mpsc, Arc, Mutex},
+    env,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
     thread,
+    time::Duration,
 };
 
-use rust_bert::pipelines::sentence_embeddings::{
-    SentenceEmbeddingsBuilder, SentenceEmbeddingsModelType,
+use lazy_static::lazy_static;
+use regex::Regex;
+use tokio::{
+    runtime::Handle,
+    sync::{
+        mpsc::{self, error::TrySendError},
+        oneshot,
+    },
+    time::Instant,
 };
 
-use crate::{embedding_error, embedding_info, model::error::OmniNewsError};
+use crate::{
+    config::http_client::HttpClient,
+    embedding_error, embedding_info, embedding_warn,
+    model::{embedding::EmbeddingVersion, error::OmniNewsError},
+};
+
+use super::embedder_util::EmbeddingBackend;
+
+lazy_static! {
+    static ref HTML_TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+    static ref WHITESPACE: Regex = Regex::new(r"\s+").unwrap();
+}
+
+type EmbeddingResult = Result<Vec<Vec<f32>>, String>;
+
+/// 새로 만드는 임베딩의 문장 형식 버전. `make_embedding_text`를 바꾸면 올리고 재임베딩 작업을 돌려야 한다.
+///
+/// 1: 채널은 `제목: .. 내용: ..`, 아이템은 `제목\n설명\n작성자` 형식
+/// 2: 채널, 아이템, 검색어 모두 `make_embedding_text` 형식
+///
+/// 검색은 이 값이 아니라 인덱스를 만든 임베딩의 버전을 따른다. (`make_search_text` 참고)
+/// 재임베딩 작업이 끝나 인덱스를 다시 만들기 전까지는 이전 버전으로 검색한다.
+pub const TEXT_RECIPE_VERSION: i32 = 2;
+
+// 임베딩 모델의 최대 입력 길이를 고려한 문장 길이 제한 (글자 수)
+const MAX_EMBEDDING_TEXT_CHARS: usize = 512;
+
+const DEFAULT_EMBEDDING_WORKERS: usize = 1;
+const DEFAULT_EMBEDDING_QUEUE_CAPACITY: usize = 1024;
+const DEFAULT_EMBEDDING_MAX_BATCH_SIZE: usize = 32;
+const DEFAULT_EMBEDDING_MAX_WAIT_MS: u64 = 10;
+
+#[derive(Clone, Debug)]
+pub struct EmbeddingConfig {
+    pub backend: EmbeddingBackend,
+    pub model_id: String,
+    pub workers: usize,
+    pub queue_capacity: usize,
+    pub max_batch_size: usize,
+    pub max_wait: Duration,
+}
+
+impl EmbeddingConfig {
+    /// 환경 변수로 설정을 읽습니다.
+    ///
+    /// - `EMBEDDING_BACKEND` 외 백엔드 설정 : `EmbeddingBackend::from_env` 참고
+    /// - `EMBEDDING_MODEL_ID` : 임베딩 버전에 기록할 모델 이름 (기본값은 백엔드 설정에서 정한다. rust-bert 로컬 모델은 필수)
+    /// - `EMBEDDING_WORKERS` : 모델 워커 스레드 수 (워커마다 모델을 따로 불러오므로 메모리도 그만큼 쓴다)
+    /// - `EMBEDDING_QUEUE_CAPACITY` : 대기할 수 있는 최대 문장 수. 넘치면 기다리지 않고 Busy 에러를 돌려준다.
+    /// - `EMBEDDING_MAX_BATCH_SIZE` : 모델에 한 번에 넘기는 최대 문장 수
+    /// - `EMBEDDING_MAX_WAIT_MS` : 배치를 채우려고 다음 요청을 기다리는 최대 시간 (밀리초)
+    pub fn from_env() -> Self {
+        let queue_capacity =
+            env_usize("EMBEDDING_QUEUE_CAPACITY").unwrap_or(DEFAULT_EMBEDDING_QUEUE_CAPACITY);
+        let backend = EmbeddingBackend::from_env();
+        Self {
+            model_id: env::var("EMBEDDING_MODEL_ID")
+                .ok()
+                .filter(|v| !v.is_empty())
+                .unwrap_or_else(|| backend.model_id()),
+            backend,
+            workers: env_usize("EMBEDDING_WORKERS").unwrap_or(DEFAULT_EMBEDDING_WORKERS),
+            queue_capacity,
+            // 배치 하나가 큐 용량보다 크면 영원히 들어갈 수 없다.
+            max_batch_size: env_usize("EMBEDDING_MAX_BATCH_SIZE")
+                .unwrap_or(DEFAULT_EMBEDDING_MAX_BATCH_SIZE)
+                .min(queue_capacity),
+            max_wait: Duration::from_millis(
+                env::var("EMBEDDING_MAX_WAIT_MS")
+                    .ok()
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .unwrap_or(DEFAULT_EMBEDDING_MAX_WAIT_MS),
+            ),
+        }
+    }
+}
+
+fn env_usize(key: &str) -> Option<usize> {
+    env::var(key)
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|v| *v > 0)
+}
 
 struct EmbeddingRequest {
-    text: String,
-    response_tx: mpsc::Sender<Vec<f32>>,
+    texts: Vec<String>,
+    response_tx: oneshot::Sender<EmbeddingResult>,
 }
 
+/// 임베딩 요청 큐
+///
+/// 동시에 들어온 요청을 디스패처가 최대 `max_batch_size`개, 최대 `max_wait`만큼 모아 한 배치로 만들고,
+/// 모델 워커 스레드 중 쉬고 있는 워커가 배치를 한 번의 `encode`로 처리합니다.
+/// 큐가 가득 차면 메모리를 늘리지 않고 `OmniNewsError::EmbeddingBusy`를 돌려줍니다.
 #[derive(Clone)]
 pub struct EmbeddingService {
-    request_tx: Arc<Mutex<mpsc::Sender<EmbeddingRequest>>>,
+    request_tx: mpsc::Sender<EmbeddingRequest>,
+    // 워커가 아직 가져가지 않은 문장 수
+    queued: Arc<AtomicUsize>,
+    config: EmbeddingConfig,
 }
 
 impl EmbeddingService {
-    pub fn new() -> Self {
-        // 요청 채널 생성
-        let (request_tx, request_rx) = mpsc::channel::<EmbeddingRequest>();
-
-        thread::spawn(move || {
-            embedding_info!("[Worker Thread] Initializing worker thread module");
-
-            // TOO 다국어 지원 모델로 변경
-            let model = SentenceEmbeddingsBuilder::remote(
-                SentenceEmbeddingsModelType::DistiluseBaseMultilingualCased,
-            )
-            .create_model()
-            .expect("[Worker Thread] Error while initalizing model");
-
-            embedding_info!("[Worker Thread] Worker thread initialized");
-
-            // 요청 대기
-            while let Ok(request) = request_rx.recv() {
-                // 임베딩 생성
-                match model.encode(&[request.text]) {
-                    Ok(embeddings) if !embeddings.is_empty() => {
-                        let _ = request.response_tx.send(embeddings[0].clone());
-                    }
-                    _ => {
-                        let _ = request.response_tx.send(Vec::new());
+    pub fn new(config: EmbeddingConfig, http_client: HttpClient) -> Self {
+        // 요청마다 문장이 하나 이상이므로 문장 수로 용량을 지키면 요청 채널도 넘치지 않는다.
+        let (request_tx, request_rx) = mpsc::channel::<EmbeddingRequest>(config.queue_capacity);
+        // 워커 수만큼만 배치를 미리 받아 두고, 나머지는 요청 큐에 남아 다음 배치로 합쳐진다.
+        let (batch_tx, batch_rx) = mpsc::channel::<Vec<EmbeddingRequest>>(config.workers);
+        let batch_rx = Arc::new(Mutex::new(batch_rx));
+        let queued = Arc::new(AtomicUsize::new(0));
+
+        let runtime = Handle::current();
+        for worker_id in 0..config.workers {
+            let backend = config.backend.clone();
+            let http_client = http_client.clone();
+            let runtime = runtime.clone();
+            let batch_rx = batch_rx.clone();
+            let queued = queued.clone();
+            thread::spawn(move || {
+                run_worker(worker_id, backend, http_client, runtime, batch_rx, queued)
+            });
+        }
+        tokio::spawn(run_dispatcher(
+            request_rx,
+            batch_tx,
+            config.max_batch_size,
+            config.max_wait,
+        ));
+
+        Self {
+            request_tx,
+            queued,
+            config,
+        }
+    }
+
+    pub fn config(&self) -> &EmbeddingConfig {
+        &self.config
+    }
+
+    /// 이 서버가 만드는 임베딩의 버전
+    pub fn version(&self) -> EmbeddingVersion {
+        EmbeddingVersion {
+            model_id: self.config.model_id.clone(),
+            text_recipe_version: TEXT_RECIPE_VERSION,
+        }
+    }
+
+    /// 워커가 아직 가져가지 않은 문장 수
+    pub fn queue_depth(&self) -> usize {
+        self.queued.load(Ordering::Acquire)
+    }
+
+    // 임베딩 생성 요청 메서드 (응답은 입력 순서와 같다)
+    fn submit(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<oneshot::Receiver<EmbeddingResult>, OmniNewsError> {
+        let len = texts.len();
+        let capacity = self.config.queue_capacity;
+        self.queued
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |queued| {
+                (queued + len <= capacity).then_some(queued + len)
+            })
+            .map_err(|queued| {
+                embedding_warn!(
+                    "[Embedding Service] Queue is full: queued={}, capacity={}",
+                    queued,
+                    capacity
+                );
+                OmniNewsError::EmbeddingBusy
+            })?;
+
+        let (response_tx, response_rx) = oneshot::channel();
+        match self
+            .request_tx
+            .try_send(EmbeddingRequest { texts, response_tx })
+        {
+            Ok(()) => Ok(response_rx),
+            Err(e) => {
+                self.queued.fetch_sub(len, Ordering::AcqRel);
+                match e {
+                    TrySendError::Full(_) => Err(OmniNewsError::EmbeddingBusy),
+                    TrySendError::Closed(_) => {
+                        embedding_error!("[Embedding Service] Dispatcher is not running");
+                        Err(OmniNewsError::Embedding)
                     }
                 }
             }
+        }
+    }
+}
 
-            embedding_info!("[Worker Thread] worker thread terminated");
-        });
+async fn run_dispatcher(
+    mut request_rx: mpsc::Receiver<EmbeddingRequest>,
+    batch_tx: mpsc::Sender<Vec<EmbeddingRequest>>,
+    max_batch_size: usize,
+    max_wait: Duration,
+) {
+    // 배치에 넣으면 최대 크기를 넘는 요청은 다음 배치의 첫 요청이 된다.
+    let mut carry = None;
+    loop {
+        let first = match carry.take() {
+            Some(request) => request,
+            None => match request_rx.recv().await {
+                Some(request) => request,
+                None => break,
+            },
+        };
 
-        Self {
-            request_tx: Arc::new(Mutex::new(request_tx)),
+        let mut size = first.texts.len();
+        let mut batch = vec![first];
+        let deadline = Instant::now() + max_wait;
+        while size < max_batch_size {
+            match tokio::time::timeout_at(deadline, request_rx.recv()).await {
+                Ok(Some(request)) if size + request.texts.len() > max_batch_size => {
+                    carry = Some(request);
+                    break;
+                }
+                Ok(Some(request)) => {
+                    size += request.texts.len();
+                    batch.push(request);
+                }
+                Ok(None) | Err(_) => break,
+            }
+        }
+
+        // 모든 워커가 바쁘면 여기서 기다리고, 그동안 들어온 요청은 다음 배치로 모인다.
+        if batch_tx.send(batch).await.is_err() {
+            break;
         }
     }
+    embedding_info!("[Embedding Service] Dispatcher terminated");
+}
+
+fn run_worker(
+    worker_id: usize,
+    backend: EmbeddingBackend,
+    http_client: HttpClient,
+    runtime: Handle,
+    batch_rx: Arc<Mutex<mpsc::Receiver<Vec<EmbeddingRequest>>>>,
+    queued: Arc<AtomicUsize>,
+) {
+    embedding_info!(
+        "[Worker Thread] Initializing worker thread module: {}",
+        worker_id
+    );
 
-    // 임베딩 생성 요청 메서드
-    fn embed_text(&self, text: String) -> Result<Vec<f32>, String> {
-        // 응답용 채널 생성
-        let (response_tx, response_rx) = mpsc::channel();
+    let embedder = backend
+        .create(&http_client, &runtime)
+        .expect("[Worker Thread] Error while initalizing model");
 
-        // 요청 생성 및 전송
-        let request = EmbeddingRequest { text, response_tx };
+    embedding_info!("[Worker Thread] Worker thread initialized: {}", worker_id);
 
-        // 요청 전송
-        self.request_tx
-            .lock()
-            .map_err(|_| "Failed to acquire lock".to_string())?
-            .send(request)
-            .map_err(|_| "Failed to request to worker thread ".to_string())?;
+    // 배치 대기 (잠금은 배치를 받을 때까지만 잡는다)
+    while let Some(requests) = batch_rx.lock().ok().and_then(|mut rx| rx.blocking_recv()) {
+        let texts: Vec<&str> = requests
+            .iter()
+            .flat_map(|request| request.texts.iter().map(String::as_str))
+            .collect();
+        queued.fetch_sub(texts.len(), Ordering::AcqRel);
 
-        // 응답 대기
-        response_rx
-            .recv()
-            .map_err(|_| "Failed to recieved from worker thread ".to_string())
+        // 임베딩 생성
+        let result = match embedder.embed(&texts) {
+            Ok(embeddings) if embeddings.len() == texts.len() => Ok(embeddings),
+            Ok(embeddings) => Err(format!(
+                "Expected {} embeddings but got {}",
+                texts.len(),
+                embeddings.len()
+            )),
+            Err(e) => Err(e.to_string()),
+        };
+
+        match result {
+            Ok(embeddings) => {
+                let mut embeddings = embeddings.into_iter();
+                for request in requests {
+                    let response = embeddings.by_ref().take(request.texts.len()).collect();
+                    let _ = request.response_tx.send(Ok(response));
+                }
+            }
+            Err(e) => {
+                embedding_error!("[Worker Thread] Failed to encode batch: {}", e);
+                for request in requests {
+                    let _ = request.response_tx.send(Err(e.clone()));
+                }
+            }
+        }
     }
+
+    embedding_info!("[Worker Thread] worker thread terminated: {}", worker_id);
 }
 
 pub async fn embedding_sentence(
     embedding_service: &EmbeddingService,
     sentence: String,
 ) -> Result<Vec<f32>, OmniNewsError> {
-    let service = embedding_service.clone();
+    embedding_sentences(embedding_service, vec![sentence])
+        .await?
+        .pop()
+        .ok_or(OmniNewsError::Embedding)
+}
+
+/// 여러 문장을 임베딩합니다. 결과는 입력 순서와 같습니다.
+///
+/// 배치 크기보다 많으면 나눠서 요청하므로 여러 워커가 함께 처리합니다.
+pub async fn embedding_sentences(
+    embedding_service: &EmbeddingService,
+    sentences: Vec<String>,
+) -> Result<Vec<Vec<f32>>, OmniNewsError> {
+    if sentences.is_empty() {
+        return Ok(Vec::new());
+    }
+
     // Generate Embeddings
-    let embedding = tokio::task::spawn_blocking(move || service.embed_text(sentence))
-        .await
-        .map_err(|_| OmniNewsError::Embedding)?;
-
-    match embedding {
-        Ok(mut res) => {
-            // 벡터 정규화
-            let norm: f32 = res.iter().map(|x| x * x).sum::<f32>().sqrt();
-            if norm > 0.0 {
-                for x in &mut res {
-                    *x /= norm;
-                }
+    let receivers = sentences
+        .chunks(embedding_service.config().max_batch_size)
+        .map(|chunk| embedding_service.submit(chunk.to_vec()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut embeddings = Vec::with_capacity(sentences.len());
+    for response_rx in receivers {
+        match response_rx.await {
+            Ok(Ok(res)) => embeddings.extend(res),
+            Ok(Err(e)) => {
+                embedding_error!("[Embedding Service] Failed to generate embedding: {}", e);
+                return Err(OmniNewsError::Embedding);
+            }
+            Err(_) => {
+                embedding_error!("[Embedding Service] Worker dropped the request");
+                return Err(OmniNewsError::Embedding);
             }
-            Ok(res)
         }
-        Err(e) => {
-            embedding_error!("[Embedding Service] Failed to generate embedding: {}", e);
-            Err(OmniNewsError::Embedding)
+    }
+
+    embeddings
+        .iter_mut()
+        .for_each(|embedding| normalize(embedding));
+    Ok(embeddings)
+}
+
+/// 채널, 아이템, 검색어를 임베딩할 문장으로 만듭니다. (`TEXT_RECIPE_VERSION` 참고)
+pub fn make_embedding_text(title: &str, description: &str) -> String {
+    // 1. HTML 태그 제거
+    let clean_description = HTML_TAG.replace_all(description, "");
+
+    // 2. 구조화된 형식으로 정보 표현
+    let text = format!("제목: {}. 내용: {}", title, clean_description);
+
+    // 3. 특수문자 정리 및 중복 공백 제거 - 한글 보존
+    let text = text.replace(
+        |c: char| {
+            !c.is_alphanumeric()
+                && !c.is_whitespace()
+                && !is_hangul(c)
+                && c != '.'
+                && c != ','
+                && c != ':'
+        },
+        " ",
+    );
+    let text = WHITESPACE.replace_all(text.trim(), " ");
+
+    // 4. 텍스트 길이 제한 (바이트가 아닌 글자 단위로 자른다)
+    let text: String = text.chars().take(MAX_EMBEDDING_TEXT_CHARS).collect();
+
+    // 5. 제목 반복으로 중요성 강조
+    format!("{}. {}", text, title)
+}
+
+/// 검색어를 인덱스의 문장 형식 버전에 맞는 문장으로 만듭니다. 알 수 없는 버전이면 None을 반환합니다.
+pub fn make_search_text(text_recipe_version: i32, search_value: &str) -> Option<String> {
+    match text_recipe_version {
+        1 => Some(format!("제목: {}. 내용: {}", search_value, search_value)),
+        2 => Some(make_embedding_text(search_value, search_value)),
+        _ => None,
+    }
+}
+
+// 한글 문자 판별
+fn is_hangul(c: char) -> bool {
+    let cp = c as u32;
+    // 한글 유니코드 범위 (가~힣)
+    (0xAC00..=0xD7A3).contains(&cp) ||
+    // 한글 자음/모음
+    (0x1100..=0x11FF).contains(&cp) ||
+    (0x3130..=0x318F).contains(&cp)
+}
+
+// 벡터 정규화
+fn normalize(embedding: &mut [f32]) {
+    let norm: f32 = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for x in embedding {
+            *x /= norm;
         }
     }
 }
@@ -114,3 +425,122 @@ pub fn encode_embedding(embedding: &[f32]) -> Vec<u8> {
     }
     bytes
 }
+
+pub fn decode_embedding(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::http_client::HttpClientConfig;
+
+    fn hashing_service(queue_capacity: usize, max_batch_size: usize) -> EmbeddingService {
+        EmbeddingService::new(
+            EmbeddingConfig {
+                backend: EmbeddingBackend::Hashing { dimension: 32 },
+                model_id: "hashing-32".to_string(),
+                workers: 2,
+                queue_capacity,
+                max_batch_size,
+                max_wait: Duration::from_millis(5),
+            },
+            HttpClient::new(HttpClientConfig::default()),
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn embeds_sentences_in_input_order() {
+        let service = hashing_service(64, 4);
+        let sentences: Vec<String> = (0..10).map(|i| format!("item {} title", i)).collect();
+
+        let embeddings = embedding_sentences(&service, sentences.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(embeddings.len(), sentences.len());
+        for (sentence, embedding) in sentences.into_iter().zip(&embeddings) {
+            let single = embedding_sentence(&service, sentence).await.unwrap();
+            assert_eq!(&single, embedding);
+
+            let norm: f32 = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+            assert!((norm - 1.0).abs() < 1e-5);
+        }
+        assert_eq!(service.queue_depth(), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn handles_concurrent_requests() {
+        let service = hashing_service(64, 8);
+
+        let handles: Vec<_> = (0..16)
+            .map(|i| {
+                let service = service.clone();
+                tokio::spawn(async move {
+                    embedding_sentence(&service, format!("concurrent {}", i)).await
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap().unwrap().len(), 32);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn rejects_request_when_queue_is_full() {
+        let service = hashing_service(4, 4);
+
+        let result = service.submit(vec!["too many".to_string(); 5]);
+
+        assert!(matches!(result, Err(OmniNewsError::EmbeddingBusy)));
+        assert_eq!(service.queue_depth(), 0);
+    }
+
+    #[test]
+    fn makes_embedding_text_without_splitting_korean() {
+        let description = format!("<p>{}</p>", "가".repeat(600));
+
+        let text = make_embedding_text("제목", &description);
+
+        assert!(text.starts_with("제목: 제목. 내용: 가"));
+        assert!(text.ends_with(". 제목"));
+        assert!(!text.contains('<'));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn reports_configured_version() {
+        let service = hashing_service(4, 4);
+
+        assert_eq!(
+            service.version(),
+            EmbeddingVersion {
+                model_id: "hashing-32".to_string(),
+                text_recipe_version: TEXT_RECIPE_VERSION,
+            }
+        );
+    }
+
+    #[test]
+    fn makes_search_text_for_index_recipe_version() {
+        assert_eq!(
+            make_search_text(1, "러스트").as_deref(),
+            Some("제목: 러스트. 내용: 러스트")
+        );
+        assert_eq!(
+            make_search_text(2, "러스트"),
+            Some(make_embedding_text("러스트", "러스트"))
+        );
+        assert_eq!(make_search_text(0, "러스트"), None);
+    }
+
+    #[test]
+    fn decodes_encoded_embedding() {
+        let embedding = vec![0.5, -1.25, 3.0];
+
+        assert_eq!(decode_embedding(&encode_embedding(&embedding)), embedding);
+    }
+}
@@ -0,0 +1,108 @@
+use std::env;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use hmac::{Hmac, Mac};
+use lazy_static::lazy_static;
+use sha2::Sha256;
+
+use crate::CURRENT_VERSION;
+
+type HmacSha256 = Hmac<Sha256>;
+
+lazy_static! {
+    // 비어 있으면 프록시를 사용하지 않고 원본 주소를 그대로 내려준다.
+    static ref IMAGE_PROXY_SECRET: Option<String> = env::var("IMAGE_PROXY_SECRET")
+        .ok()
+        .filter(|secret| !secret.is_empty());
+    static ref IMAGE_PROXY_BASE_URL: String = env::var("IMAGE_PROXY_BASE_URL")
+        .unwrap_or_else(|_| format!("/{}/api/img", CURRENT_VERSION))
+        .trim_end_matches('/')
+        .to_string();
+}
+
+/// 원본 이미지 주소를 `<서명>.<base64 주소>` 형태의 경로 세그먼트로 만든다.
+pub fn sign_image_url(url: &str) -> Option<String> {
+    sign_with_secret(IMAGE_PROXY_SECRET.as_ref()?, url)
+}
+
+/// 서명된 경로 세그먼트를 검증하고 원본 이미지 주소를 반환한다.
+pub fn verify_signed_image_url(signed_url: &str) -> Option<String> {
+    verify_with_secret(IMAGE_PROXY_SECRET.as_ref()?, signed_url)
+}
+
+/// 응답 DTO에 내려줄 이미지 주소를 프록시 주소로 바꾼다.
+///
+/// 프록시가 꺼져 있거나 http(s) 주소가 아니면 원본 주소를 그대로 반환한다.
+pub fn proxied_image_url(url: Option<String>) -> Option<String> {
+    let url = url?;
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return Some(url);
+    }
+
+    match sign_image_url(&url) {
+        Some(signed_url) => Some(format!("{}/{}", *IMAGE_PROXY_BASE_URL, signed_url)),
+        None => Some(url),
+    }
+}
+
+fn sign_with_secret(secret: &str, url: &str) -> Option<String> {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).ok()?;
+    mac.update(url.as_bytes());
+    let signature = mac.finalize().into_bytes();
+    Some(format!(
+        "{}.{}",
+        URL_SAFE_NO_PAD.encode(signature),
+        URL_SAFE_NO_PAD.encode(url)
+    ))
+}
+
+fn verify_with_secret(secret: &str, signed_url: &str) -> Option<String> {
+    let (signature, encoded_url) = signed_url.split_once('.')?;
+
+    let signature = URL_SAFE_NO_PAD.decode(signature).ok()?;
+    let url = String::from_utf8(URL_SAFE_NO_PAD.decode(encoded_url).ok()?).ok()?;
+
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).ok()?;
+    mac.update(url.as_bytes());
+    mac.verify_slice(&signature).ok()?;
+
+    Some(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "test-secret";
+    const URL: &str = "https://example.com/images/a.png?size=large";
+
+    #[test]
+    fn verifies_signed_url() {
+        let signed_url = sign_with_secret(SECRET, URL).unwrap();
+
+        assert_eq!(
+            verify_with_secret(SECRET, &signed_url).as_deref(),
+            Some(URL)
+        );
+    }
+
+    #[test]
+    fn rejects_tampered_signature() {
+        let signed_url = sign_with_secret(SECRET, URL).unwrap();
+        let (signature, encoded_url) = signed_url.split_once('.').unwrap();
+
+        let mut tampered = URL_SAFE_NO_PAD.decode(signature).unwrap();
+        tampered[0] ^= 0x01;
+        let tampered_signature = format!("{}.{}", URL_SAFE_NO_PAD.encode(tampered), encoded_url);
+        assert_eq!(verify_with_secret(SECRET, &tampered_signature), None);
+
+        let other_url = format!(
+            "{}.{}",
+            signature,
+            URL_SAFE_NO_PAD.encode("https://evil.com/")
+        );
+        assert_eq!(verify_with_secret(SECRET, &other_url), None);
+
+        assert_eq!(verify_with_secret("other-secret", &signed_url), None);
+    }
+}
@@ -1,3 +1,10 @@
 pub mod annoy_util;
+pub mod charset_util;
+pub mod date_util;
 pub mod db_util;
+pub mod embedder_util;
 pub mod embedding_util;
+pub mod image_proxy_util;
+pub mod url_policy_util;
+#[cfg(test)]
+pub mod test_http_server;
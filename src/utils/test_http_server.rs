@@ -0,0 +1,111 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+/// 테스트에서 외부 API(JWKS, App Store 등) 대신 사용하는 로컬 HTTP 서버
+///
+/// 경로마다 고정된 상태 코드와 JSON 본문을 돌려주고, 경로별 요청 수를 기록합니다.
+pub struct TestHttpServer {
+    pub base_url: String,
+    routes: Arc<Mutex<HashMap<String, (u16, String)>>>,
+    hits: Arc<Mutex<HashMap<String, usize>>>,
+}
+
+impl TestHttpServer {
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let routes: Arc<Mutex<HashMap<String, (u16, String)>>> = Arc::default();
+        let hits: Arc<Mutex<HashMap<String, usize>>> = Arc::default();
+
+        let (server_routes, server_hits) = (routes.clone(), hits.clone());
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let routes = server_routes.clone();
+                let hits = server_hits.clone();
+                tokio::spawn(async move {
+                    let Some(path) = read_request_path(&mut stream).await else {
+                        return;
+                    };
+                    *hits.lock().unwrap().entry(path.clone()).or_default() += 1;
+                    let (status, body) = routes
+                        .lock()
+                        .unwrap()
+                        .get(&path)
+                        .cloned()
+                        .unwrap_or((404, "{}".to_string()));
+                    let response = format!(
+                        "HTTP/1.1 {} Test\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                    let _ = stream.shutdown().await;
+                });
+            }
+        });
+
+        Self {
+            base_url,
+            routes,
+            hits,
+        }
+    }
+
+    /// `path`(쿼리 제외) 요청에 돌려줄 응답을 정합니다.
+    pub fn route(&self, path: &str, status: u16, body: impl Into<String>) {
+        self.routes
+            .lock()
+            .unwrap()
+            .insert(path.to_string(), (status, body.into()));
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    pub fn hits(&self, path: &str) -> usize {
+        self.hits.lock().unwrap().get(path).copied().unwrap_or(0)
+    }
+}
+
+// 요청 헤더와 본문을 읽고 요청 경로를 돌려준다.
+async fn read_request_path(stream: &mut tokio::net::TcpStream) -> Option<String> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let content_length = head
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+    while buf.len() < header_end + content_length {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    let target = head.lines().next()?.split_whitespace().nth(1)?;
+    Some(target.split('?').next().unwrap_or(target).to_string())
+}
@@ -0,0 +1,316 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Arc,
+};
+
+use reqwest::{
+    dns::{Addrs, Name, Resolve, Resolving},
+    redirect, Client, Response, Url,
+};
+use thirtyfour::WebDriver;
+
+use crate::{model::error::OmniNewsError, server_warn};
+
+// 사용자 입력 주소로 받는 응답의 최대 크기 (10MB)
+pub const MAX_RESPONSE_BYTES: u64 = 10 * 1024 * 1024;
+const MAX_REDIRECTS: usize = 5;
+const ALLOWED_SCHEMES: [&str; 2] = ["http", "https"];
+
+/// 외부 요청 정책(내부망 차단, 리다이렉트 재검증)이 적용된 빌더
+///
+/// 연결 시점에 DNS 결과를 검사하고, 리다이렉트마다 주소를 다시 검증한다.
+/// 프록시를 거치면 대상 주소의 DNS 검사를 건너뛰게 되므로 환경 변수의 프록시도 사용하지 않는다.
+pub fn outbound_client_builder() -> reqwest::ClientBuilder {
+    Client::builder()
+        .no_proxy()
+        .dns_resolver(Arc::new(PublicDnsResolver))
+        .redirect(redirect::Policy::custom(|attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                return attempt.error("too many redirects");
+            }
+            match check_url(attempt.url()) {
+                Ok(_) => attempt.follow(),
+                Err(reason) => attempt.error(reason),
+            }
+        }))
+}
+
+/// 외부로 요청해도 되는 주소인지 확인합니다.
+///
+/// 호스트 이름의 DNS 결과는 연결 시점에 `PublicDnsResolver`가 다시 검사합니다.
+pub fn validate_outbound_url(url: &str) -> Result<Url, OmniNewsError> {
+    let parsed =
+        Url::parse(url).map_err(|_| OmniNewsError::BlockedUrl(format!("invalid url: {}", url)))?;
+    check_url(&parsed).map_err(|reason| {
+        server_warn!("[Outbound] Blocked url {}: {}", url, reason);
+        OmniNewsError::BlockedUrl(reason)
+    })?;
+    Ok(parsed)
+}
+
+/// 주소를 검증하고 DNS 결과까지 모두 공인 IP인지 확인합니다.
+///
+/// WebDriver처럼 연결을 직접 제어할 수 없는 경로에서 요청 전에 사용합니다.
+pub async fn validate_outbound_url_with_dns(url: &str) -> Result<Url, OmniNewsError> {
+    let parsed = validate_outbound_url(url)?;
+    let host = parsed.host_str().unwrap_or_default().to_string();
+    let port = parsed.port_or_known_default().unwrap_or(80);
+
+    let addrs = resolve_public_addrs(&host, port).await.map_err(|reason| {
+        server_warn!("[Outbound] Blocked url {}: {}", url, reason);
+        OmniNewsError::BlockedUrl(reason)
+    })?;
+    if addrs.is_empty() {
+        return Err(OmniNewsError::BlockedUrl(format!(
+            "no address for host: {}",
+            host
+        )));
+    }
+    Ok(parsed)
+}
+
+/// 브라우저를 주소로 이동시키고, 이동을 마친 주소(리다이렉트 포함)도 다시 검증합니다.
+///
+/// 이동한 주소가 차단 대상이면 빈 페이지로 돌아가 그 내용이 이후 작업에 쓰이지 않게 합니다.
+pub async fn goto_checked(driver: &WebDriver, url: &str) -> Result<(), OmniNewsError> {
+    validate_outbound_url_with_dns(url).await?;
+    driver.goto(url).await?;
+
+    let current_url = driver.current_url().await?;
+    if let Err(e) = validate_outbound_url_with_dns(current_url.as_str()).await {
+        server_warn!(
+            "[Outbound] Browser navigated to blocked url: {} -> {}",
+            url,
+            current_url
+        );
+        let _ = driver.goto("about:blank").await;
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// 응답 본문을 최대 크기까지만 읽습니다.
+pub async fn read_body_limited(
+    mut response: Response,
+    max_bytes: u64,
+) -> Result<Vec<u8>, OmniNewsError> {
+    if response.content_length().is_some_and(|len| len > max_bytes) {
+        return Err(OmniNewsError::ResponseTooLarge);
+    }
+
+    let mut body = vec![];
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() as u64 + chunk.len() as u64 > max_bytes {
+            return Err(OmniNewsError::ResponseTooLarge);
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+fn check_url(url: &Url) -> Result<(), String> {
+    if !ALLOWED_SCHEMES.contains(&url.scheme()) {
+        return Err(format!("scheme not allowed: {}", url.scheme()));
+    }
+
+    let host = url
+        .host_str()
+        .ok_or_else(|| "host is missing".to_string())?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .trim_end_matches('.')
+        .to_lowercase();
+
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return check_ip(ip);
+    }
+    if host == "localhost" || host.ends_with(".localhost") {
+        return Err(format!("host not allowed: {}", host));
+    }
+    Ok(())
+}
+
+fn check_ip(ip: IpAddr) -> Result<(), String> {
+    if is_public_ip(ip) {
+        Ok(())
+    } else {
+        Err(format!("address not allowed: {}", ip))
+    }
+}
+
+async fn resolve_public_addrs(host: &str, port: u16) -> Result<Vec<SocketAddr>, String> {
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|e| format!("failed to resolve {}: {}", host, e))?
+        .collect();
+
+    // 하나라도 내부 주소가 섞여 있으면 차단한다. (DNS 리바인딩 방지)
+    if let Some(addr) = addrs.iter().find(|addr| !is_public_ip(addr.ip())) {
+        return Err(format!("{} resolves to {}", host, addr.ip()));
+    }
+    Ok(addrs)
+}
+
+/// 연결할 IP를 고르는 시점에 내부 주소를 걸러내는 DNS 리졸버
+struct PublicDnsResolver;
+
+impl Resolve for PublicDnsResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs = resolve_public_addrs(name.as_str(), 0).await?;
+            let addrs: Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => is_public_ipv6(ip),
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // 0.0.0.0/8
+        || a == 0
+        // 100.64.0.0/10 (CGNAT)
+        || (a == 100 && (64..=127).contains(&b))
+        // 192.0.0.0/24 (IETF protocol assignments)
+        || (a == 192 && b == 0 && c == 0)
+        // 198.18.0.0/15 (benchmarking)
+        || (a == 198 && (18..=19).contains(&b))
+        // 240.0.0.0/4 (reserved)
+        || a >= 240)
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    if let Some(ipv4) = ip.to_ipv4_mapped() {
+        return is_public_ipv4(ipv4);
+    }
+
+    let segments = ip.segments();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // ::/96 (IPv4-compatible, ::a.b.c.d)
+        || segments[..6].iter().all(|&s| s == 0)
+        // 2002::/16 (6to4)
+        || segments[0] == 0x2002
+        // fc00::/7 (unique local)
+        || (segments[0] & 0xfe00) == 0xfc00
+        // fe80::/10 (link local)
+        || (segments[0] & 0xffc0) == 0xfe80
+        // 2001:db8::/32 (documentation)
+        || (segments[0] == 0x2001 && segments[1] == 0x0db8)
+        // 64:ff9b::/96 (NAT64)
+        || (segments[0] == 0x0064 && segments[1] == 0xff9b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_http_server::TestHttpServer;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    fn check(url: &str) -> Result<(), String> {
+        check_url(&Url::parse(url).unwrap())
+    }
+
+    #[test]
+    fn blocks_internal_ipv4() {
+        for value in [
+            "127.0.0.1",
+            "10.0.0.1",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "0.0.0.0",
+            "100.64.0.1",
+        ] {
+            assert!(!is_public_ip(ip(value)), "{} should be blocked", value);
+        }
+        assert!(is_public_ip(ip("93.184.216.34")));
+    }
+
+    #[test]
+    fn blocks_internal_ipv6() {
+        for value in [
+            "::1",
+            "::",
+            "fc00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "::ffff:169.254.169.254",
+            "::ffff:10.0.0.1",
+            "::127.0.0.1",
+            "::169.254.169.254",
+            "2002:7f00:1::",
+            "2002:a9fe:a9fe::1",
+        ] {
+            assert!(!is_public_ip(ip(value)), "{} should be blocked", value);
+        }
+        assert!(is_public_ip(ip("2606:4700::1111")));
+        assert!(is_public_ip(ip("::ffff:93.184.216.34")));
+    }
+
+    #[test]
+    fn checks_url_scheme_and_host() {
+        assert!(check("https://example.com/feed").is_ok());
+        assert!(check("http://93.184.216.34/rss").is_ok());
+
+        assert!(check("file:///etc/passwd").is_err());
+        assert!(check("ftp://example.com/feed").is_err());
+        assert!(check("gopher://example.com").is_err());
+        assert!(check("http://localhost:8080").is_err());
+        assert!(check("http://api.localhost").is_err());
+        assert!(check("http://127.0.0.1/").is_err());
+        assert!(check("http://169.254.169.254/latest/meta-data").is_err());
+        assert!(check("http://[::1]/").is_err());
+        assert!(check("http://[::ffff:127.0.0.1]/").is_err());
+        assert!(check("http://[2002:7f00:1::]/").is_err());
+    }
+
+    #[test]
+    fn validates_outbound_url() {
+        assert!(validate_outbound_url("https://example.com").is_ok());
+        assert!(matches!(
+            validate_outbound_url("not a url"),
+            Err(OmniNewsError::BlockedUrl(_))
+        ));
+        assert!(matches!(
+            validate_outbound_url("http://10.0.0.1/"),
+            Err(OmniNewsError::BlockedUrl(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn limits_response_body_size() {
+        let server = TestHttpServer::start().await;
+        server.route("/body", 200, "0123456789");
+        let client = Client::new();
+
+        let response = client.get(server.url("/body")).send().await.unwrap();
+        assert!(matches!(
+            read_body_limited(response, 5).await,
+            Err(OmniNewsError::ResponseTooLarge)
+        ));
+
+        let response = client.get(server.url("/body")).send().await.unwrap();
+        assert_eq!(
+            read_body_limited(response, 10).await.unwrap(),
+            b"0123456789"
+        );
+    }
+}
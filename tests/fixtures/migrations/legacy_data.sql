@@ -0,0 +1,33 @@
+-- 23d2fd4 스키마로 운영되던 DB에 있을 법한 데이터
+INSERT INTO `user` (`user_id`, `user_email`, `user_social_login_provider`, `user_social_provider_id`, `user_access_token`, `user_status`)
+VALUES
+	(1, 'active@example.com', 'google', 'g-1', 'old-token', 'active'),
+	(2, 'suspended@example.com', 'kakao', 'k-2', NULL, 'suspended, deleted');
+
+INSERT INTO `rss_channel` (`channel_id`, `channel_title`, `channel_language`, `channel_rss_link`)
+VALUES
+	(1, '국내 채널', 'ko', 'https://example.com/ko.xml'),
+	(2, 'Global', 'en', 'https://example.com/en.xml');
+
+INSERT INTO `rss_item` (`rss_id`, `channel_id`, `rss_title`, `rss_link`, `rss_pub_date`)
+VALUES
+	(1, 1, '첫 기사', 'https://example.com/ko/1', '2024-05-01 15:20:00'),
+	(2, 1, '중복 기사', 'https://example.com/ko/1', '2024-05-01 15:21:00'),
+	(3, 2, 'First', 'https://example.com/en/1', '2024-05-01 06:20:00'),
+	(4, 99, 'Orphan', 'https://example.com/gone/1', '2024-05-01 06:20:00');
+
+INSERT INTO `News` (`news_id`, `news_title`, `news_category`) VALUES (1, '뉴스', '정치');
+
+INSERT INTO `embedding` (`embedding_id`, `embedding_value`, `channel_id`, `rss_id`, `news_id`, `embedding_source_rank`)
+VALUES
+	(1, X'0000803F0000803F', 1, NULL, NULL, 0),
+	(2, X'0000803F0000803F', NULL, 2, NULL, 0),
+	(3, X'0000803F0000803F', NULL, NULL, 1, 0);
+
+INSERT INTO `feedback` (`feedback_email`, `feedback_content`) VALUES ('active@example.com', '좋아요');
+
+INSERT INTO `user_subscription_channel` (`user_id`, `channel_id`) VALUES (1, 1), (1, 2), (0, 1);
+
+INSERT INTO `rss_folder` (`folder_id`, `folder_name`, `user_id`) VALUES (1, '뉴스', 1), (2, '주인 없음', 0);
+
+INSERT INTO `channels_in_folder` (`folder_id`, `channel_id`) VALUES (1, 1), (1, 1), (1, 2), (2, 1);
@@ -0,0 +1,140 @@
+//! 마이그레이션 도입 전(23d2fd4)의 src/schema.sql로 만든 DB가 최신 스키마로 올라가는지 확인한다.
+//!
+//! MySQL이 필요하므로 기본으로는 실행하지 않는다.
+//! `DATABASE_URL=mysql://... cargo test --test migration_upgrade -- --ignored`
+
+use sqlx::{migrate::Migrator, MySqlPool, Row};
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn column_names(pool: &MySqlPool, table: &str) -> Vec<String> {
+    sqlx::query(
+        "SELECT column_name FROM information_schema.columns
+        WHERE table_schema = DATABASE() AND table_name = ?
+        ORDER BY ordinal_position",
+    )
+    .bind(table)
+    .fetch_all(pool)
+    .await
+    .unwrap()
+    .iter()
+    .map(|row| row.get::<String, _>(0))
+    .collect()
+}
+
+#[ignore = "requires DATABASE_URL (MySQL)"]
+#[sqlx::test(migrations = false)]
+async fn upgrades_database_created_from_original_schema(pool: MySqlPool) {
+    sqlx::raw_sql(include_str!("fixtures/migrations/schema_23d2fd4.sql"))
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::raw_sql(include_str!("fixtures/migrations/legacy_data.sql"))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    MIGRATOR.run(&pool).await.unwrap();
+
+    // 토큰 컬럼은 세션 테이블로 옮겨지고 탈퇴/시간대 컬럼이 추가된다.
+    let user_columns = column_names(&pool, "user").await;
+    assert!(!user_columns.contains(&"user_access_token".to_string()));
+    assert!(user_columns.contains(&"user_deleted_at".to_string()));
+    assert!(user_columns.contains(&"user_timezone".to_string()));
+    assert!(!column_names(&pool, "user_session").await.is_empty());
+
+    let status: String = sqlx::query_scalar("SELECT user_status FROM user WHERE user_id = 2")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(status, "suspended");
+
+    let feedback_status: String = sqlx::query_scalar("SELECT feedback_status FROM feedback")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(feedback_status, "open");
+
+    // 한국어 채널 아이템만 KST에서 UTC로 바뀐다.
+    let pub_dates: Vec<(i32, chrono::NaiveDateTime)> =
+        sqlx::query_as("SELECT rss_id, rss_pub_date FROM rss_item ORDER BY rss_id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+    let pub_dates: Vec<(i32, String)> = pub_dates
+        .into_iter()
+        .map(|(id, date)| (id, date.to_string()))
+        .collect();
+    // 중복 링크(2)와 채널이 없는 아이템(4)은 지워진다.
+    assert_eq!(
+        pub_dates,
+        vec![
+            (1, "2024-05-01 06:20:00".to_string()),
+            (3, "2024-05-01 06:20:00".to_string()),
+        ]
+    );
+
+    // 지워진 아이템의 임베딩도 지워지고, 남은 임베딩은 기존 모델/문장 형식으로 기록된다.
+    let embeddings: Vec<(i32, Option<String>, Option<i32>)> = sqlx::query_as(
+        "SELECT embedding_id, embedding_model_id, embedding_dim FROM embedding ORDER BY embedding_id",
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(
+        embeddings,
+        vec![
+            (
+                1,
+                Some("distiluse-base-multilingual-cased".to_string()),
+                Some(2)
+            ),
+            (
+                3,
+                Some("distiluse-base-multilingual-cased".to_string()),
+                Some(2)
+            ),
+        ]
+    );
+
+    let news_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM news")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(news_count, 1);
+
+    // 주인 없는 폴더와 구독, 폴더 안 중복 채널은 정리되고 순서 컬럼이 추가된다.
+    let folders: Vec<(i32, Option<i32>, Option<i32>)> =
+        sqlx::query_as("SELECT folder_id, parent_folder_id, folder_order FROM rss_folder")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+    assert_eq!(folders, vec![(1, None, Some(0))]);
+
+    let channels_in_folder: Vec<(i32, Option<i32>)> = sqlx::query_as(
+        "SELECT channel_id, channel_order FROM channels_in_folder ORDER BY channel_id",
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(channels_in_folder, vec![(1, Some(0)), (2, Some(0))]);
+
+    let subscription_count: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM user_subscription_channel")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(subscription_count, 2);
+
+    for table in [
+        "smart_folder",
+        "mute_rule",
+        "rate_limit_bucket",
+        "reembedding_job",
+    ] {
+        assert!(
+            !column_names(&pool, table).await.is_empty(),
+            "{table} should exist"
+        );
+    }
+}